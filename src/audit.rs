@@ -0,0 +1,220 @@
+//! Append-only JSONL audit log of deletions.
+
+use crate::{DeleteReason, ReaperConfig, get_selected_node, get_storage_class};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use kube::ResourceExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+/// One line of the audit log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditRecord {
+    pub timestamp: DateTime<Utc>,
+    pub namespace: String,
+    pub name: String,
+    pub uid: Option<String>,
+    pub storage_class: Option<String>,
+    pub selected_node: Option<String>,
+    /// `DeleteReason` variant, e.g. `MissingNode`
+    pub reason: String,
+    pub description: String,
+    pub dry_run: bool,
+}
+
+impl AuditRecord {
+    pub(crate) fn new(
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        config: &ReaperConfig,
+    ) -> Self {
+        Self {
+            timestamp: Utc::now(),
+            namespace: pvc.namespace().unwrap_or_default(),
+            name: pvc.name_any(),
+            uid: pvc.uid(),
+            storage_class: get_storage_class(pvc).map(str::to_string),
+            selected_node: get_selected_node(pvc, config).map(str::to_string),
+            reason: reason.variant().to_string(),
+            description: reason.describe(),
+            dry_run: config.dry_run,
+        }
+    }
+}
+
+/// Buffered writer appending [`AuditRecord`]s as JSON lines
+pub struct AuditLog {
+    writer: BufWriter<File>,
+}
+
+impl AuditLog {
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            writer: BufWriter::new(file),
+        })
+    }
+
+    pub fn append(&mut self, record: &AuditRecord) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+/// Which audit records `history` prints; unset fields match everything
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub namespace: Option<String>,
+    pub storage_class: Option<String>,
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, record: &AuditRecord) -> bool {
+        self.since.is_none_or(|since| record.timestamp >= since)
+            && self.until.is_none_or(|until| record.timestamp <= until)
+            && self
+                .namespace
+                .as_ref()
+                .is_none_or(|namespace| &record.namespace == namespace)
+            && self
+                .storage_class
+                .as_ref()
+                .is_none_or(|class| record.storage_class.as_ref() == Some(class))
+    }
+}
+
+/// Read the audit log at `path`, oldest first, keeping the records `filter` matches
+pub fn read_history(path: &Path, filter: &HistoryFilter) -> Result<Vec<AuditRecord>> {
+    let file =
+        File::open(path).with_context(|| format!("Failed to open audit log {}", path.display()))?;
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.with_context(|| format!("Failed to read audit log {}", path.display()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: AuditRecord = serde_json::from_str(&line).with_context(|| {
+            format!(
+                "Invalid audit record on line {} of {}",
+                index + 1,
+                path.display()
+            )
+        })?;
+        if filter.matches(&record) {
+            records.push(record);
+        }
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn record() -> AuditRecord {
+        AuditRecord {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+            namespace: "default".to_string(),
+            name: "data-db-0".to_string(),
+            uid: Some("uid-1".to_string()),
+            storage_class: Some("openebs-lvm".to_string()),
+            selected_node: Some("node-1".to_string()),
+            reason: "MissingNode".to_string(),
+            description: "pod 'db-0' references missing node 'node-1'".to_string(),
+            dry_run: false,
+        }
+    }
+
+    #[test]
+    fn test_audit_record_format() {
+        assert_eq!(
+            serde_json::to_string(&record()).unwrap(),
+            r#"{"timestamp":"2025-01-02T03:04:05Z","namespace":"default","name":"data-db-0","uid":"uid-1","storage_class":"openebs-lvm","selected_node":"node-1","reason":"MissingNode","description":"pod 'db-0' references missing node 'node-1'","dry_run":false}"#
+        );
+    }
+
+    #[test]
+    fn test_audit_log_appends_lines() {
+        let path =
+            std::env::temp_dir().join(format!("pvc-reaper-audit-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        for _ in 0..2 {
+            let mut log = AuditLog::open(&path).unwrap();
+            log.append(&record()).unwrap();
+            log.flush().unwrap();
+        }
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<AuditRecord> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines, vec![record(), record()]);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_read_history_filters_records() {
+        let path =
+            std::env::temp_dir().join(format!("pvc-reaper-history-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let old = record();
+        let other_namespace = AuditRecord {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap(),
+            namespace: "team-a".to_string(),
+            ..record()
+        };
+        let other_class = AuditRecord {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 4, 0, 0, 0).unwrap(),
+            storage_class: None,
+            dry_run: true,
+            ..record()
+        };
+        let mut log = AuditLog::open(&path).unwrap();
+        for record in [&old, &other_namespace, &other_class] {
+            log.append(record).unwrap();
+        }
+        log.flush().unwrap();
+
+        let all = read_history(&path, &HistoryFilter::default()).unwrap();
+        assert_eq!(
+            all,
+            vec![old.clone(), other_namespace.clone(), other_class.clone()]
+        );
+
+        let since = HistoryFilter {
+            since: Some(Utc.with_ymd_and_hms(2025, 1, 3, 0, 0, 0).unwrap()),
+            until: Some(Utc.with_ymd_and_hms(2025, 1, 3, 12, 0, 0).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(read_history(&path, &since).unwrap(), vec![other_namespace]);
+
+        let scoped = HistoryFilter {
+            namespace: Some("default".to_string()),
+            storage_class: Some("openebs-lvm".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(read_history(&path, &scoped).unwrap(), vec![old]);
+
+        std::fs::write(&path, "not json\n").unwrap();
+        let error = read_history(&path, &HistoryFilter::default()).unwrap_err();
+        assert!(format!("{:#}", error).contains("line 1"));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
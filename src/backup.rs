@@ -0,0 +1,314 @@
+//! Copies of PVC manifests taken right before deletion, to recover from a wrongful one.
+
+use crate::{BackupFailurePolicy, ReaperConfig, telemetry};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::{
+    api::core::v1::{ConfigMap, PersistentVolumeClaim},
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+};
+use kube::{Api, Client, ResourceExt, api::PostParams};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use tracing::{info, warn};
+
+/// Size the backup ConfigMap's data is kept under, leaving headroom below the API server's
+/// 1MiB object limit
+const CONFIGMAP_MAX_BYTES: usize = 900 * 1024;
+
+/// The PVC as YAML without its managed fields, headed by a comment naming the PV it was bound
+/// to, so the pairing can be rebuilt, and when the backup was taken
+pub fn manifest(pvc: &PersistentVolumeClaim, at: DateTime<Utc>) -> Result<String> {
+    let mut pvc = pvc.clone();
+    pvc.metadata.managed_fields = None;
+    let volume = pvc
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.volume_name.as_deref())
+        .unwrap_or("<none>");
+    let yaml = serde_yaml::to_string(&pvc).context("Failed to serialize PVC")?;
+    Ok(format!(
+        "# pvc-reaper backup of {}/{}, bound to PV {}, taken {}\n{}",
+        pvc.namespace().unwrap_or_default(),
+        pvc.name_any(),
+        volume,
+        at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+        yaml
+    ))
+}
+
+/// When a manifest was taken according to its header, `None` if it has no readable time
+fn taken_at(manifest: &str) -> Option<DateTime<Utc>> {
+    let header = manifest.lines().next()?;
+    let (_, at) = header.rsplit_once(", taken ")?;
+    DateTime::parse_from_rfc3339(at)
+        .ok()
+        .map(|at| at.with_timezone(&Utc))
+}
+
+/// Drop the oldest backups other than `keep` until at most `max_entries` remain and they fit in
+/// [`CONFIGMAP_MAX_BYTES`], returning how many were dropped
+fn prune(data: &mut BTreeMap<String, String>, keep: &str, max_entries: usize) -> Result<usize> {
+    let mut size: usize = data
+        .iter()
+        .map(|(key, value)| key.len() + value.len())
+        .sum();
+    // Entries without a readable time sort first, as the oldest
+    let mut oldest_first: Vec<_> = data
+        .iter()
+        .filter(|(key, _)| *key != keep)
+        .map(|(key, value)| (taken_at(value), key.clone()))
+        .collect();
+    oldest_first.sort();
+
+    let mut dropped = 0;
+    for (_, key) in oldest_first {
+        if data.len() <= max_entries && size <= CONFIGMAP_MAX_BYTES {
+            break;
+        }
+        if let Some(value) = data.remove(&key) {
+            size -= key.len() + value.len();
+            dropped += 1;
+        }
+    }
+    if size > CONFIGMAP_MAX_BYTES {
+        anyhow::bail!(
+            "Backup of {} doesn't fit in a ConfigMap ({} bytes)",
+            keep,
+            size
+        );
+    }
+    Ok(dropped)
+}
+
+/// Write the manifest to `<dir>/<namespace>-<name>-<timestamp>.yaml`
+pub fn write_file(dir: &Path, pvc: &PersistentVolumeClaim, at: DateTime<Utc>) -> Result<PathBuf> {
+    let path = dir.join(format!(
+        "{}-{}-{}.yaml",
+        pvc.namespace().unwrap_or_default(),
+        pvc.name_any(),
+        at.format("%Y%m%dT%H%M%SZ")
+    ));
+    std::fs::write(&path, manifest(pvc, at)?)
+        .with_context(|| format!("Failed to write backup {}", path.display()))?;
+    Ok(path)
+}
+
+/// ConfigMap key holding a claim's latest backup
+fn configmap_key(pvc: &PersistentVolumeClaim) -> String {
+    format!(
+        "{}.{}.yaml",
+        pvc.namespace().unwrap_or_default(),
+        pvc.name_any()
+    )
+}
+
+/// Store the manifest under the claim's key in the backup ConfigMap, creating it if needed.
+/// The oldest backups are dropped to keep it within `max_entries` and the 1MiB object limit.
+pub async fn write_configmap(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    pvc: &PersistentVolumeClaim,
+    at: DateTime<Utc>,
+    max_entries: usize,
+) -> Result<()> {
+    let api = Api::<ConfigMap>::namespaced(client.clone(), namespace);
+    let (key, manifest) = (configmap_key(pvc), manifest(pvc, at)?);
+    let existing = api
+        .get_opt(name)
+        .await
+        .inspect_err(|_| telemetry::api_error("configmaps", "get"))
+        .with_context(|| format!("Failed to get ConfigMap {}/{}", namespace, name))?;
+
+    let Some(mut configmap) = existing else {
+        let configmap = ConfigMap {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            data: Some([(key, manifest)].into()),
+            ..Default::default()
+        };
+        api.create(&PostParams::default(), &configmap)
+            .await
+            .inspect_err(|_| telemetry::api_error("configmaps", "create"))
+            .with_context(|| format!("Failed to create ConfigMap {}/{}", namespace, name))?;
+        return Ok(());
+    };
+
+    let data = configmap.data.get_or_insert_with(BTreeMap::new);
+    data.insert(key.clone(), manifest);
+    let dropped = prune(data, &key, max_entries)?;
+    if dropped > 0 {
+        info!(
+            "Dropped the {} oldest backups from ConfigMap {}/{} to make room",
+            dropped, namespace, name
+        );
+    }
+    // The resourceVersion from the get makes a concurrent writer fail this instead of losing
+    // its backup
+    api.replace(name, &PostParams::default(), &configmap)
+        .await
+        .inspect_err(|_| telemetry::api_error("configmaps", "update"))
+        .with_context(|| format!("Failed to update ConfigMap {}/{}", namespace, name))?;
+    Ok(())
+}
+
+/// Back the PVC up to every configured destination. With `--backup-failure-policy=block` a
+/// failure is returned so the delete is skipped; otherwise it is only logged.
+pub(crate) async fn back_up(
+    client: &Client,
+    config: &ReaperConfig,
+    pvc: &PersistentVolumeClaim,
+) -> Result<()> {
+    let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+    let (mut result, now) = (Ok(()), Utc::now());
+    if let Some(dir) = &config.backup_dir {
+        result = write_file(dir, pvc, now).map(|path| {
+            info!(namespace = %namespace, pvc = %name, "Backed up PVC to {}", path.display())
+        });
+    }
+    // Try every destination even if one failed, so best-effort keeps whatever it can
+    if let Some(backup_namespace) = &config.backup_configmap_namespace {
+        let written = write_configmap(
+            client,
+            backup_namespace,
+            &config.backup_configmap_name,
+            pvc,
+            now,
+            config.backup_configmap_max_entries as usize,
+        )
+        .await;
+        if written.is_ok() {
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                "Backed up PVC to ConfigMap {}/{}",
+                backup_namespace,
+                config.backup_configmap_name
+            );
+        }
+        result = result.and(written);
+    }
+
+    match (result, config.backup_failure_policy) {
+        (Ok(()), _) => Ok(()),
+        (Err(e), BackupFailurePolicy::Block) => Err(e.context("Backup failed, not deleting PVC")),
+        (Err(e), BackupFailurePolicy::BestEffort) => {
+            warn!(
+                namespace = %namespace,
+                pvc = %name,
+                "Backup failed, deleting PVC anyway: {:#}",
+                e
+            );
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::{
+        api::core::v1::PersistentVolumeClaimSpec,
+        apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry,
+    };
+
+    fn bound_pvc() -> PersistentVolumeClaim {
+        PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                name: Some("data-0".to_string()),
+                namespace: Some("default".to_string()),
+                uid: Some("uid-1".to_string()),
+                labels: Some([("app".to_string(), "db".to_string())].into()),
+                managed_fields: Some(vec![ManagedFieldsEntry {
+                    manager: Some("kubectl".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            spec: Some(PersistentVolumeClaimSpec {
+                storage_class_name: Some("openebs-lvm".to_string()),
+                volume_name: Some("pv-1".to_string()),
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    fn time(at: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(at)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_manifest_round_trips() {
+        let pvc = bound_pvc();
+        let manifest = manifest(&pvc, time("2025-01-02T03:04:05Z")).unwrap();
+        assert!(manifest.starts_with(
+            "# pvc-reaper backup of default/data-0, bound to PV pv-1, taken 2025-01-02T03:04:05Z\n"
+        ));
+        assert_eq!(taken_at(&manifest), Some(time("2025-01-02T03:04:05Z")));
+        assert!(manifest.contains("kind: PersistentVolumeClaim"));
+
+        let restored: PersistentVolumeClaim = serde_yaml::from_str(&manifest).unwrap();
+        assert_eq!(restored.metadata.managed_fields, None);
+        assert_eq!(
+            restored.metadata,
+            ObjectMeta {
+                managed_fields: None,
+                ..pvc.metadata
+            }
+        );
+        assert_eq!(restored.spec, pvc.spec);
+    }
+
+    #[test]
+    fn test_write_file() {
+        let dir = std::env::temp_dir().join(format!("pvc-reaper-backup-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let at = time("2025-01-02T03:04:05Z");
+
+        let path = write_file(&dir, &bound_pvc(), at).unwrap();
+        assert_eq!(path, dir.join("default-data-0-20250102T030405Z.yaml"));
+        let restored: PersistentVolumeClaim =
+            serde_yaml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(restored.name_any(), "data-0");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_prune() {
+        let entry = |at: &str| {
+            format!(
+                "# pvc-reaper backup of default/data-0, bound to PV pv-1, taken {}\n",
+                at
+            )
+        };
+        let mut data: BTreeMap<String, String> = [
+            ("a.yaml".to_string(), entry("2025-01-03T00:00:00Z")),
+            ("b.yaml".to_string(), entry("2025-01-01T00:00:00Z")),
+            (
+                "c.yaml".to_string(),
+                "# written before backups were timestamped\n".to_string(),
+            ),
+            ("d.yaml".to_string(), entry("2025-01-02T00:00:00Z")),
+        ]
+        .into();
+
+        assert_eq!(prune(&mut data, "a.yaml", 4).unwrap(), 0);
+        // Untimed entries go first, then the oldest
+        assert_eq!(prune(&mut data, "a.yaml", 2).unwrap(), 2);
+        assert_eq!(data.keys().collect::<Vec<_>>(), ["a.yaml", "d.yaml"]);
+        // The new backup itself is never dropped
+        assert_eq!(prune(&mut data, "a.yaml", 0).unwrap(), 1);
+        assert_eq!(data.keys().collect::<Vec<_>>(), ["a.yaml"]);
+
+        data.insert("big.yaml".to_string(), "x".repeat(CONFIGMAP_MAX_BYTES));
+        assert!(prune(&mut data, "big.yaml", 10).is_err());
+    }
+}
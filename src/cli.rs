@@ -0,0 +1,407 @@
+//! Output of the one-shot subcommands, and the `--interactive` prompt.
+
+use crate::{Candidate, Explanation, OutputFormat, audit::AuditRecord};
+use anyhow::Result;
+use serde::Serialize;
+use std::io::{BufRead, Write};
+
+const CANDIDATE_HEADER: [&str; 7] = [
+    "NAMESPACE",
+    "NAME",
+    "STORAGECLASS",
+    "NODE",
+    "POD",
+    "REASON",
+    "PENDING",
+];
+
+const PROMPT_HEADER: [&str; 3] = ["NAMESPACE", "NAME", "REASON"];
+
+const HISTORY_HEADER: [&str; 7] = [
+    "TIMESTAMP",
+    "NAMESPACE",
+    "NAME",
+    "STORAGECLASS",
+    "NODE",
+    "REASON",
+    "DRYRUN",
+];
+
+/// Render candidates as a kubectl-style table, one row per PVC
+pub fn candidate_table(candidates: &[Candidate]) -> String {
+    let rows: Vec<[String; 7]> = candidates
+        .iter()
+        .map(|candidate| {
+            [
+                candidate.namespace.clone(),
+                candidate.name.clone(),
+                or_dash(candidate.storage_class.as_deref()),
+                or_dash(candidate.node.as_deref()),
+                or_dash(candidate.pod.as_deref()),
+                candidate.reason.variant().to_string(),
+                or_dash(
+                    candidate
+                        .pending_secs
+                        .map(|secs| format!("{}s", secs))
+                        .as_deref(),
+                ),
+            ]
+        })
+        .collect();
+    table(CANDIDATE_HEADER, &rows)
+}
+
+/// Render audit records as a kubectl-style table, one row per deletion
+pub fn history_table(records: &[AuditRecord]) -> String {
+    let rows: Vec<[String; 7]> = records
+        .iter()
+        .map(|record| {
+            [
+                record
+                    .timestamp
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+                record.namespace.clone(),
+                record.name.clone(),
+                or_dash(record.storage_class.as_deref()),
+                or_dash(record.selected_node.as_deref()),
+                record.reason.clone(),
+                record.dry_run.to_string(),
+            ]
+        })
+        .collect();
+    table(HISTORY_HEADER, &rows)
+}
+
+/// Print the candidate `rows` (namespace, name, reason) to `output`, then ask about each one.
+/// `y` confirms that claim and `a` it and every later one; anything else, including the end of
+/// `input`, declines it. Returns one answer per row.
+pub fn confirm_deletions(
+    rows: &[[String; 3]],
+    mut input: impl BufRead,
+    mut output: impl Write,
+) -> std::io::Result<Vec<bool>> {
+    write!(
+        output,
+        "{} deletion candidates:\n{}",
+        rows.len(),
+        table(PROMPT_HEADER, rows)
+    )?;
+
+    let mut answers = Vec::with_capacity(rows.len());
+    let mut all = false;
+    for [namespace, name, _] in rows {
+        if !all {
+            write!(output, "Delete {}/{}? [y/N/a] ", namespace, name)?;
+            output.flush()?;
+            let mut answer = String::new();
+            input.read_line(&mut answer)?;
+            match answer.trim().to_ascii_lowercase().as_str() {
+                "y" | "yes" => {}
+                "a" | "all" => all = true,
+                _ => {
+                    answers.push(false);
+                    continue;
+                }
+            }
+        }
+        answers.push(true);
+    }
+    Ok(answers)
+}
+
+/// Left-align `rows` under `header`, columns separated by three spaces
+fn table<const N: usize>(header: [&str; N], rows: &[[String; N]]) -> String {
+    let header = header.map(str::to_string);
+    let mut widths = [0; N];
+    for row in std::iter::once(&header).chain(rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    std::iter::once(&header)
+        .chain(rows)
+        .map(|row| {
+            let line = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell))
+                .collect::<Vec<_>>()
+                .join("   ");
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
+
+/// Render an explanation as one line per check followed by the decision
+pub fn explanation_text(explanation: &Explanation) -> String {
+    let width = explanation
+        .checks
+        .iter()
+        .map(|check| check.name.len())
+        .max()
+        .unwrap_or_default();
+    let mut text = format!("{}/{}\n", explanation.namespace, explanation.name);
+    for check in &explanation.checks {
+        let mark = if check.passed { "yes" } else { "no " };
+        text += &format!("  [{}] {:<width$}  {}\n", mark, check.name, check.detail);
+    }
+    text += &match &explanation.decision {
+        Some(Ok(reason)) => format!("decision: delete ({})\n", reason.describe()),
+        Some(Err(skip)) => format!("decision: skip ({})\n", skip.describe()),
+        None => "decision: not evaluated (storage criteria don't match)\n".to_string(),
+    };
+    text
+}
+
+/// Serialize `value` for `--output json|yaml`, `None` for text output
+pub fn machine_readable<T: Serialize>(value: &T, format: OutputFormat) -> Result<Option<String>> {
+    Ok(match format {
+        OutputFormat::Text => None,
+        OutputFormat::Json => Some(serde_json::to_string_pretty(value)? + "\n"),
+        OutputFormat::Yaml => Some(serde_yaml::to_string(value)?),
+    })
+}
+
+fn or_dash(value: Option<&str>) -> String {
+    value.unwrap_or("-").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DeleteReason;
+
+    #[test]
+    fn test_candidate_table() {
+        let candidates = [
+            Candidate {
+                namespace: "default".to_string(),
+                name: "data-db-0".to_string(),
+                storage_class: Some("openebs-lvm".to_string()),
+                node: Some("node-1".to_string()),
+                pod: Some("db-0".to_string()),
+                reason: DeleteReason::MissingNode {
+                    node: "node-1".to_string(),
+                    pod: "db-0".to_string(),
+                    missing_secs: 300,
+                },
+                pending_secs: Some(300),
+            },
+            Candidate {
+                namespace: "team-a".to_string(),
+                name: "data-web-3".to_string(),
+                storage_class: None,
+                node: None,
+                pod: None,
+                reason: DeleteReason::ScaledDownStatefulSet {
+                    sts: "web".to_string(),
+                    ordinal: 3,
+                },
+                pending_secs: None,
+            },
+        ];
+
+        assert_eq!(
+            candidate_table(&candidates),
+            "\
+NAMESPACE   NAME         STORAGECLASS   NODE     POD    REASON                  PENDING
+default     data-db-0    openebs-lvm    node-1   db-0   MissingNode             300s
+team-a      data-web-3   -              -        -      ScaledDownStatefulSet   -
+"
+        );
+        assert_eq!(
+            candidate_table(&[]),
+            "NAMESPACE   NAME   STORAGECLASS   NODE   POD   REASON   PENDING\n"
+        );
+    }
+
+    #[test]
+    fn test_confirm_deletions() {
+        let rows: Vec<[String; 3]> = (0..5)
+            .map(|i| {
+                [
+                    "default".to_string(),
+                    format!("data-{}", i),
+                    "no node".to_string(),
+                ]
+            })
+            .collect();
+        let mut output = Vec::new();
+        let answers = confirm_deletions(&rows, "y\n\nA\n".as_bytes(), &mut output).unwrap();
+        assert_eq!(answers, [true, false, true, true, true]);
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("5 deletion candidates:\nNAMESPACE   NAME     REASON\n"));
+        // `a` answers for the rest, so only three questions were asked
+        assert_eq!(output.matches("? [y/N/a] ").count(), 3);
+
+        // Running out of input declines whatever is left
+        let answers = confirm_deletions(&rows, "yes\n".as_bytes(), std::io::sink()).unwrap();
+        assert_eq!(answers, [true, false, false, false, false]);
+    }
+
+    #[test]
+    fn test_history_table() {
+        use chrono::{TimeZone, Utc};
+
+        let records = [AuditRecord {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+            namespace: "default".to_string(),
+            name: "data-db-0".to_string(),
+            uid: Some("uid-1".to_string()),
+            storage_class: Some("openebs-lvm".to_string()),
+            selected_node: None,
+            reason: "MissingNode".to_string(),
+            description: "pod 'db-0' references missing node 'node-1'".to_string(),
+            dry_run: true,
+        }];
+
+        assert_eq!(
+            history_table(&records),
+            "\
+TIMESTAMP              NAMESPACE   NAME        STORAGECLASS   NODE   REASON        DRYRUN
+2025-01-02T03:04:05Z   default     data-db-0   openebs-lvm    -      MissingNode   true
+"
+        );
+    }
+
+    #[test]
+    fn test_subcommand_parsing() {
+        use crate::{Command, ReaperConfig};
+        use clap::Parser;
+
+        let config = ReaperConfig::parse_from(["pvc-reaper", "--dry-run", "list"]);
+        assert_eq!(config.command, Some(Command::List));
+        assert!(config.dry_run);
+        assert_eq!(ReaperConfig::parse_from(["pvc-reaper"]).command, None);
+        assert_eq!(
+            ReaperConfig::parse_from(["pvc-reaper", "run"]).command,
+            Some(Command::Run)
+        );
+
+        let config = ReaperConfig::parse_from([
+            "pvc-reaper",
+            "history",
+            "--since",
+            "2025-01-02T00:00:00Z",
+            "--namespace",
+            "default",
+        ]);
+        let Some(Command::History {
+            since,
+            until,
+            namespace,
+            storage_class,
+        }) = config.command
+        else {
+            panic!("expected history, got {:?}", config.command);
+        };
+        assert_eq!(since.unwrap().to_rfc3339(), "2025-01-02T00:00:00+00:00");
+        assert_eq!(until, None);
+        assert_eq!(namespace.as_deref(), Some("default"));
+        assert_eq!(storage_class, None);
+
+        let config = ReaperConfig::parse_from(["pvc-reaper", "history", "--since", "24h"]);
+        let Some(Command::History {
+            since: Some(since), ..
+        }) = config.command
+        else {
+            panic!("expected history --since, got {:?}", config.command);
+        };
+        let age = chrono::Utc::now() - since;
+        assert!((86_395..=86_405).contains(&age.num_seconds()));
+    }
+
+    #[test]
+    fn test_explanation_text() {
+        use crate::{Check, SkipReason};
+
+        let explanation = Explanation {
+            namespace: "default".to_string(),
+            name: "data-db-0".to_string(),
+            checks: vec![
+                Check {
+                    name: "storage class",
+                    passed: true,
+                    detail: "'openebs-lvm' matches".to_string(),
+                },
+                Check {
+                    name: "threshold",
+                    passed: false,
+                    detail: "unschedulable for 30s, threshold 120s".to_string(),
+                },
+            ],
+            decision: Some(Err(SkipReason::BelowThreshold {
+                pod: "db-0".to_string(),
+                pending_secs: 30,
+                threshold_secs: 120,
+            })),
+        };
+
+        assert_eq!(
+            explanation_text(&explanation),
+            "\
+default/data-db-0
+  [yes] storage class  'openebs-lvm' matches
+  [no ] threshold      unschedulable for 30s, threshold 120s
+decision: skip (below threshold (30s < 120s))
+"
+        );
+    }
+
+    #[test]
+    fn test_result_schema() {
+        use crate::{DeleteReason, DeletedPvc, LoadedCounts, ReapResult, SkipReason, SkippedPvc};
+
+        // A change here breaks scripts parsing `--output json`; update the golden file and note
+        // it in the changelog only when that is intended
+        let result = ReapResult {
+            deleted_count: 1,
+            deleted_missing_node: 1,
+            skipped_count: 1,
+            reclaimed_bytes: 10 * 1024 * 1024 * 1024,
+            deleted: vec![DeletedPvc {
+                namespace: "default".to_string(),
+                name: "data-db-0".to_string(),
+                uid: Some("0b5e2c3a-7d1f-4c1e-9a55-1d2f3e4a5b6c".to_string()),
+                storage_class: Some("openebs-lvm".to_string()),
+                node: Some("node-1".to_string()),
+                size: Some("10Gi".to_string()),
+                size_bytes: 10 * 1024 * 1024 * 1024,
+                reason: DeleteReason::MissingNode {
+                    node: "node-1".to_string(),
+                    pod: "db-0".to_string(),
+                    missing_secs: 300,
+                },
+                dry_run: false,
+            }],
+            skipped: vec![SkippedPvc {
+                namespace: "default".to_string(),
+                name: "data-web-0".to_string(),
+                reason: SkipReason::BelowThreshold {
+                    pod: "web-0".to_string(),
+                    pending_secs: 30,
+                    threshold_secs: 120,
+                },
+            }],
+            loaded: LoadedCounts {
+                nodes: 3,
+                pods: 12,
+                pvcs: 2,
+            },
+            ..Default::default()
+        };
+
+        let golden: serde_json::Value =
+            serde_json::from_str(include_str!("../tests/golden/reap_result.json")).unwrap();
+        assert_eq!(serde_json::to_value(&result).unwrap(), golden);
+
+        let yaml = machine_readable(&result, OutputFormat::Yaml)
+            .unwrap()
+            .unwrap();
+        let parsed: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed, golden);
+        assert_eq!(machine_readable(&result, OutputFormat::Text).unwrap(), None);
+    }
+}
@@ -0,0 +1,120 @@
+//! Reaping several clusters, one per kubeconfig context, from a single process.
+
+use crate::{ReapResult, ReapTracker, ReaperConfig, reap, telemetry};
+use anyhow::{Context, Result};
+use futures::future::join_all;
+use kube::{Client, Config, config::KubeConfigOptions};
+use tokio::sync::Mutex;
+use tracing::{Instrument, error, info, info_span};
+
+/// A cluster being reaped, with the state its cycles carry over
+pub struct Cluster {
+    /// Kubeconfig context, `None` for the in-cluster or current configuration
+    pub context: Option<String>,
+    pub client: Client,
+    pub tracker: Mutex<ReapTracker>,
+}
+
+impl Cluster {
+    pub(crate) fn new(context: Option<String>, client: Client) -> Self {
+        Self {
+            context,
+            client,
+            tracker: Mutex::new(ReapTracker::default()),
+        }
+    }
+
+    /// Run `future` with this cluster's context attached to its log lines and metrics
+    pub async fn scoped<F: Future>(&self, future: F) -> F::Output {
+        match &self.context {
+            Some(context) => {
+                let span = info_span!("cluster", context = %context);
+                telemetry::with_cluster(context, future.instrument(span)).await
+            }
+            None => future.await,
+        }
+    }
+}
+
+/// Build a client per `--contexts` entry, or a single default one when none are given
+pub async fn connect(config: &ReaperConfig) -> Result<Vec<Cluster>> {
+    if config.contexts.is_empty() {
+        let client = Client::try_default()
+            .await
+            .context("Failed to create Kubernetes client")?;
+        return Ok(vec![Cluster::new(None, client)]);
+    }
+
+    let mut clusters = Vec::with_capacity(config.contexts.len());
+    for context in &config.contexts {
+        let options = KubeConfigOptions {
+            context: Some(context.clone()),
+            ..Default::default()
+        };
+        let kube_config = Config::from_kubeconfig(&options)
+            .await
+            .with_context(|| format!("Failed to load kubeconfig context '{}'", context))?;
+        let client = Client::try_from(kube_config).with_context(|| {
+            format!(
+                "Failed to create Kubernetes client for context '{}'",
+                context
+            )
+        })?;
+        clusters.push(Cluster::new(Some(context.clone()), client));
+    }
+    Ok(clusters)
+}
+
+/// Reap every cluster concurrently and add up their results. A failing cluster is logged and
+/// listed in `failed_clusters`; the cycle only fails when all of them did.
+pub async fn reap_all(clusters: &[Cluster], config: &ReaperConfig) -> Result<ReapResult> {
+    if let [cluster] = clusters {
+        return reap_cluster(cluster, config).await;
+    }
+
+    let results = join_all(clusters.iter().map(|cluster| reap_cluster(cluster, config))).await;
+    let mut total = ReapResult::default();
+    let mut last_error = None;
+    for (cluster, result) in clusters.iter().zip(results) {
+        let context = cluster.context.clone().unwrap_or_default();
+        match result {
+            Ok(result) => total.merge(result),
+            Err(e) => {
+                error!(context = %context, "Reaping context {} failed: {:#}", context, e);
+                total.failed_clusters.push(context);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    if let Some(e) = last_error
+        && total.failed_clusters.len() == clusters.len()
+    {
+        return Err(e.context("Reaping failed in every cluster"));
+    }
+    info!(
+        "Reaped {} clusters: deleted={} would_delete={} skipped={} failed={}",
+        clusters.len(),
+        total.deleted_count,
+        total.would_delete_count,
+        total.skipped_count,
+        total.failed_clusters.len()
+    );
+    Ok(total)
+}
+
+/// Wait for the notifications every cluster's cycles are still sending
+pub async fn flush_notifications(clusters: &[Cluster]) {
+    for cluster in clusters {
+        cluster.tracker.lock().await.flush_notifications().await;
+    }
+}
+
+async fn reap_cluster(cluster: &Cluster, config: &ReaperConfig) -> Result<ReapResult> {
+    cluster
+        .scoped(async {
+            let mut tracker = cluster.tracker.lock().await;
+            reap(&cluster.client, config, &mut tracker).await
+        })
+        .await
+}
@@ -1,548 +1,11160 @@
+pub mod audit;
+pub mod backup;
+pub mod cli;
+pub mod cluster;
+pub mod notify;
+#[cfg(feature = "otlp")]
+pub mod otel;
+pub mod server;
+pub mod telemetry;
+pub mod watch;
+
 use anyhow::{Context, Result};
+use audit::{AuditLog, AuditRecord};
 use chrono::{DateTime, Utc};
-use clap::Parser;
-use k8s_openapi::api::core::v1::{Node, PersistentVolumeClaim, Pod};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::api::{
+    apps::v1::StatefulSet,
+    core::v1::{
+        ConfigMap, Event as CoreEvent, Namespace, Node, PersistentVolume, PersistentVolumeClaim,
+        Pod, PodCondition, Taint,
+    },
+    storage::v1::{StorageClass, VolumeAttachment},
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
 use kube::{
-    Client, ResourceExt,
-    api::{Api, DeleteParams, ListParams},
+    Client, Resource, ResourceExt,
+    api::{
+        Api, ApiResource, DeleteParams, DynamicObject, GroupVersionKind, ListParams, ObjectList,
+        Patch, PatchParams, PostParams, Preconditions,
+    },
+    runtime::events::{Event, EventType, Recorder},
 };
-use std::collections::HashSet;
-use std::time::Duration;
-use tracing::{error, info};
+use notify::DeletionNotification;
+use regex::Regex;
+use serde::{Serialize, de::DeserializeOwned};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use tracing::{Instrument, Span, debug, error, field, info, info_span, instrument, warn};
 
 const SELECTED_NODE_ANNOTATION: &str = "volume.kubernetes.io/selected-node";
-const PROVISIONER_ANNOTATION: &str = "volume.beta.kubernetes.io/storage-provisioner";
+const PROVISIONER_ANNOTATION: &str = "volume.kubernetes.io/storage-provisioner";
+const BETA_PROVISIONER_ANNOTATION: &str = "volume.beta.kubernetes.io/storage-provisioner";
+const DEFAULT_CLASS_ANNOTATION: &str = "storageclass.kubernetes.io/is-default-class";
+const LVMVOLUME_GROUP: &str = "local.openebs.io";
+const LVMVOLUME_VERSION: &str = "v1alpha1";
+const LVMVOLUME_KIND: &str = "LVMVolume";
+const PROVISIONED_BY_ANNOTATION: &str = "pv.kubernetes.io/provisioned-by";
+const HOSTNAME_LABEL: &str = "kubernetes.io/hostname";
+const PAUSED_KEY: &str = "paused";
+const DEFAULT_PROTECTION_ANNOTATION: &str = "pvc-reaper.io/protected";
+const DEFAULT_OPT_IN_ANNOTATION: &str = "pvc-reaper.io/enabled";
+const KEEP_UNTIL_ANNOTATION: &str = "pvc-reaper.io/keep-until";
+const REAP_NOW_ANNOTATION: &str = "pvc-reaper.io/reap-now";
+const DELETE_AT_ANNOTATION: &str = "pvc-reaper.io/delete-at";
+const DELETE_REASON_ANNOTATION: &str = "pvc-reaper.io/delete-reason";
+const SYSTEM_NAMESPACES: [&str; 3] = ["kube-system", "kube-public", "kube-node-lease"];
+const EVENT_REPORTER: &str = "pvc-reaper";
+const REAPED_EVENT_REASON: &str = "PvcReaped";
+const CANDIDATE_EVENT_REASON: &str = "PvcReapCandidate";
+const PENDING_EVENT_REASON: &str = "PvcReapPending";
+const FINALIZERS_REMOVED_EVENT_REASON: &str = "PvcFinalizersRemoved";
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Human-readable lines
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+/// What to do with a PVC whose pre-deletion backup failed
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupFailurePolicy {
+    /// Skip the delete; the PVC is retried next cycle
+    Block,
+    /// Log the failure and delete anyway
+    BestEffort,
+}
+
+/// How `--dry-run` checks the deletes it would make
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DryRunMode {
+    /// Skip the delete call entirely; needs no delete permission
+    Client,
+    /// Send the delete with `dryRun=All`, so RBAC and admission webhooks validate it without
+    /// anything being persisted
+    Server,
+}
+
+/// What a cycle does while the pause ConfigMap's `paused` key is "true"
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMode {
+    /// Skip the cycle entirely
+    Skip,
+    /// Run the cycle as in client-side dry-run: candidates are evaluated and logged, but nothing
+    /// is deleted
+    DryRun,
+}
+
+/// How `list`, `explain` and `--once` print their result
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Tables and log lines for humans
+    Text,
+    /// Pretty-printed JSON on stdout
+    Json,
+    /// YAML on stdout
+    Yaml,
+}
+
+/// What to do; without a subcommand the reaper runs its loop
+#[derive(Subcommand, Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// Run the reaping loop (the default)
+    Run,
+    /// Print the PVCs that would be deleted right now, without deleting anything
+    List,
+    /// Walk every check for one PVC and print its outcome; exits 0 if the PVC would be deleted
+    /// right now and 2 if not
+    Explain {
+        /// The PVC as `<namespace>/<name>`
+        #[arg(value_parser = parse_namespaced_name)]
+        pvc: (String, String),
+    },
+    /// Print past deletions from the audit log (`--audit-log-path`)
+    History {
+        /// Only deletions after this time: an RFC 3339 timestamp or an age such as `24h`
+        #[arg(long, value_parser = parse_time_bound)]
+        since: Option<DateTime<Utc>>,
+        /// Only deletions before this time: an RFC 3339 timestamp or an age such as `1h`
+        #[arg(long, value_parser = parse_time_bound)]
+        until: Option<DateTime<Utc>>,
+        /// Only deletions in this namespace
+        #[arg(long)]
+        namespace: Option<String>,
+        /// Only deletions of PVCs with this storage class
+        #[arg(long)]
+        storage_class: Option<String>,
+    },
+}
+
+/// Parse a point in time given as an RFC 3339 timestamp or as an age before now (`24h`)
+fn parse_time_bound(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value.trim()) {
+        return Ok(time.with_timezone(&Utc));
+    }
+
+    let secs = parse_duration_secs(value)?;
+    i64::try_from(secs)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .and_then(|age| Utc::now().checked_sub_signed(age))
+        .ok_or_else(|| format!("duration '{}' is too long", value.trim()))
+}
+
+/// Parse a `<namespace>/<name>` object reference
+fn parse_namespaced_name(value: &str) -> Result<(String, String), String> {
+    match value.split_once('/') {
+        Some((namespace, name)) if !namespace.is_empty() && !name.is_empty() => {
+            Ok((namespace.to_string(), name.to_string()))
+        }
+        _ => Err(format!("expected <namespace>/<name>, got '{}'", value)),
+    }
+}
 
 #[derive(Parser, Debug, Clone)]
 #[command(author, version, about, long_about = None)]
 pub struct ReaperConfig {
-    /// Storage class names to filter PVCs (comma-separated for multiple)
+    /// Storage class names to filter PVCs (comma-separated for multiple); `*` and `?` globs
+    /// such as `openebs-lvm-*` are allowed
     #[arg(
         long,
         env = "STORAGE_CLASS_NAMES",
         value_delimiter = ',',
-        default_value = "openebs-lvm"
+        default_value = "openebs-lvm",
+        value_parser = StorageClassPattern::glob
+    )]
+    pub storage_classes: Vec<StorageClassPattern>,
+
+    /// Regular expressions matched against the full storage class name, in addition to
+    /// `--storage-classes` (comma-separated for multiple)
+    #[arg(
+        long,
+        env = "STORAGE_CLASS_REGEX",
+        value_delimiter = ',',
+        value_parser = StorageClassPattern::regex
     )]
-    pub storage_classes: Vec<String>,
+    pub storage_class_regex: Vec<StorageClassPattern>,
 
-    /// Storage provisioner annotation value to filter PVCs
+    /// Storage provisioner annotation values to filter PVCs (comma-separated for multiple)
     #[arg(
         long,
         env = "STORAGE_PROVISIONER",
+        value_delimiter = ',',
         default_value = "local.csi.openebs.io"
     )]
-    pub storage_provisioner: String,
+    pub storage_provisioner: Vec<String>,
+
+    /// PVC annotations holding the node a volume was provisioned on, checked in order
+    /// (comma-separated)
+    #[arg(
+        long,
+        env = "SELECTED_NODE_ANNOTATION",
+        value_delimiter = ',',
+        default_value = SELECTED_NODE_ANNOTATION
+    )]
+    pub selected_node_annotation: Vec<String>,
+
+    /// Only reap PVCs whose annotations match all of these comma-separated terms: `key=value`,
+    /// `key!=value` or `key` (exists)
+    #[arg(
+        long,
+        env = "PVC_ANNOTATION_SELECTOR",
+        value_delimiter = ',',
+        value_parser = AnnotationSelector::parse
+    )]
+    pub pvc_annotation_selector: Vec<AnnotationSelector>,
 
-    /// Interval between reaping loops in seconds
-    #[arg(long, env = "REAP_INTERVAL_SECS", default_value_t = 60)]
+    /// Interval between reaping loops (seconds, or a duration such as `1m`)
+    #[arg(long, env = "REAP_INTERVAL_SECS", default_value = "60", value_parser = parse_duration_secs)]
     pub reap_interval_secs: u64,
 
-    /// Dry run mode - don't actually delete PVCs
-    #[arg(long, env = "DRY_RUN", default_value_t = false)]
-    pub dry_run: bool,
+    /// Consecutive cycles a PVC must be a deletion candidate before it is deleted; 1 deletes on
+    /// the first
+    #[arg(
+        long,
+        env = "CONFIRMATION_CYCLES",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub confirmation_cycles: u32,
 
-    /// Check for unschedulable pods with unschedulable PVCs
-    #[arg(long, env = "CHECK_UNSCHEDULABLE_PODS", default_value_t = true)]
-    pub check_unschedulable_pods: bool,
+    /// Consecutive cycles a selected node must be absent from the node list before it counts as
+    /// missing; 1 acts on the first. Nodes re-registered by a cloud provider or restored from an
+    /// etcd backup reappear within a cycle or two
+    #[arg(
+        long,
+        env = "NODE_MISSING_CYCLES",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub node_missing_cycles: u32,
 
-    /// How long a pod must be unschedulable before considering its PVC for deletion (seconds)
-    #[arg(long, env = "UNSCHEDULABLE_POD_THRESHOLD_SECS", default_value_t = 120)]
-    pub unschedulable_pod_threshold_secs: u64,
-}
+    /// After deleting a PVC, don't delete a re-created claim with the same namespace and name
+    /// for this long (seconds, or a duration such as `10m`), whatever it looks like. 0 disables
+    /// the cooldown.
+    #[arg(
+        long,
+        env = "REDELETE_COOLDOWN_SECS",
+        default_value = "600",
+        value_parser = parse_duration_secs
+    )]
+    pub redelete_cooldown_secs: u64,
 
-#[derive(Debug, Default)]
-pub struct ReapResult {
-    pub deleted_count: usize,
-    pub skipped_count: usize,
-}
+    /// Stop deleting (or, in dry-run, selecting) PVCs after this many per cycle, oldest first;
+    /// the rest wait for the next cycle. Watch-triggered runs count towards the cycle before
+    /// them. 0 means unlimited.
+    #[arg(long, env = "MAX_DELETIONS_PER_CYCLE", default_value_t = 0)]
+    pub max_deletions_per_cycle: usize,
 
-#[derive(Debug)]
-struct State {
-    nodes: Vec<Node>,
-    node_names: HashSet<String>,
-    pods: Vec<Pod>,
-    pvcs: Vec<PersistentVolumeClaim>,
-    now: DateTime<Utc>,
-}
+    /// Stop deleting (or, in dry-run, selecting) PVCs in a namespace after this many per cycle;
+    /// the rest wait for the next cycle. 0 means unlimited.
+    #[arg(long, env = "MAX_DELETIONS_PER_NAMESPACE", default_value_t = 0)]
+    pub max_deletions_per_namespace: usize,
 
-impl State {
-    async fn new(client: &Client) -> Result<Self> {
-        let nodes = Api::<Node>::all(client.clone())
-            .list(&ListParams::default())
-            .await
-            .context("Failed to list nodes")?
-            .items;
+    /// Let deletions (or, in dry-run, selections) trickle out at this rate, across cycles: a
+    /// token bucket holding a minute's worth refills continuously, and candidates finding it
+    /// empty wait for the next cycle. Unset means unlimited.
+    #[arg(long, env = "DELETIONS_PER_MINUTE", value_parser = clap::value_parser!(u32).range(1..))]
+    pub deletions_per_minute: Option<u32>,
 
-        let pods = Api::<Pod>::all(client.clone())
-            .list(&ListParams::default())
-            .await
-            .context("Failed to list pods")?
-            .items;
+    /// Circuit breaker: delete nothing in a cycle where more than this fraction (0.0-1.0) of
+    /// matching PVCs are deletion candidates, e.g. because an API server fault hid most nodes.
+    /// Unset disables the breaker.
+    #[arg(long, env = "MAX_DELETION_FRACTION", value_parser = parse_fraction)]
+    pub max_deletion_fraction: Option<f64>,
 
-        let pvcs = Api::<PersistentVolumeClaim>::all(client.clone())
-            .list(&ListParams::default())
-            .await
-            .context("Failed to list PVCs")?
-            .items;
+    /// Number of candidates the circuit breaker always lets through, whatever their fraction
+    #[arg(long, env = "MAX_DELETION_FRACTION_FLOOR", default_value_t = 3)]
+    pub max_deletion_fraction_floor: usize,
 
-        let node_names = nodes.iter().map(ResourceExt::name_any).collect();
+    /// Delete nothing in a cycle where fewer nodes than this are Ready, e.g. because the
+    /// cluster went dark. 0 disables the check.
+    #[arg(long, env = "MIN_READY_NODES", default_value_t = 1)]
+    pub min_ready_nodes: usize,
 
-        Ok(Self {
-            nodes,
-            node_names,
-            pods,
-            pvcs,
-            now: Utc::now(),
-        })
-    }
+    /// Delete nothing in a cycle where less than this fraction (0.0-1.0) of nodes are Ready
+    #[arg(long, env = "MIN_READY_NODE_FRACTION", value_parser = parse_fraction)]
+    pub min_ready_node_fraction: Option<f64>,
 
-    async fn reap(&self, client: &Client, config: &ReaperConfig) -> Result<ReapResult> {
-        let mut result = ReapResult::default();
+    /// List each cycle's candidates and ask before deleting each one (`y`, or `a` for all);
+    /// unconfirmed candidates are skipped. Requires stdin to be a terminal.
+    #[arg(long, env = "INTERACTIVE", default_value_t = false)]
+    pub interactive: bool,
 
-        for pvc in &self.pvcs {
-            if !matches_storage_criteria(pvc, config) {
-                continue;
-            }
+    /// Instead of deleting a candidate right away, annotate it with `pvc-reaper.io/delete-at`
+    /// this far in the future (seconds, or a duration such as `1h`) and delete it once that time
+    /// has passed if it still qualifies. Unset deletes immediately.
+    #[arg(long, env = "SOFT_DELETE_DELAY_SECS", value_parser = parse_duration_secs)]
+    pub soft_delete_delay_secs: Option<u64>,
 
-            let namespace = pvc.namespace().unwrap_or_default();
-            let pvc_name = pvc.name_any();
+    /// Resolve the provisioner from the PVC's StorageClass when the PVC has no provisioner
+    /// annotation (requires permission to list StorageClasses). On by default; turn it off with
+    /// `--lookup-storage-classes=false`.
+    #[arg(
+        long,
+        env = "LOOKUP_STORAGE_CLASSES",
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true"
+    )]
+    pub lookup_storage_classes: bool,
 
-            match self.deletion_reason(pvc, config) {
-                Some(reason) => {
-                    let description = reason.describe();
-                    info!(
-                        "PVC {}/{} scheduled for deletion: {}",
-                        namespace, pvc_name, description
-                    );
+    /// Match PVCs without a `storageClassName` against the cluster's default StorageClass
+    /// (requires permission to list StorageClasses)
+    #[arg(long, env = "MATCH_DEFAULT_STORAGE_CLASS")]
+    pub match_default_storage_class: bool,
 
-                    if let Err(e) = self
-                        .perform_delete(client, config, &namespace, &pvc_name, &description)
-                        .await
-                    {
-                        error!("Failed to delete PVC {}/{}: {:#}", namespace, pvc_name, e);
-                    } else {
-                        result.deleted_count += 1;
-                    }
-                }
-                None => {
-                    result.skipped_count += 1;
-                }
-            }
-        }
+    /// PVC phases eligible for reaping (comma-separated); PVCs without a phase count as `Pending`
+    #[arg(
+        long,
+        env = "PVC_PHASES",
+        value_delimiter = ',',
+        default_value = "Pending,Bound"
+    )]
+    pub pvc_phases: Vec<String>,
 
-        info!(
-            "Reaping complete: deleted={}, skipped={}",
-            result.deleted_count, result.skipped_count
-        );
+    /// Only reap PVCs requesting at least this much storage (a Kubernetes quantity, e.g. `1Gi`);
+    /// PVCs without a storage request count as zero
+    #[arg(long, env = "MIN_PVC_SIZE", value_parser = parse_quantity_bytes)]
+    pub min_pvc_size: Option<u64>,
 
-        Ok(result)
-    }
+    /// Never reap PVCs requesting more than this much storage (a Kubernetes quantity, e.g. `100Gi`)
+    #[arg(long, env = "MAX_PVC_SIZE", value_parser = parse_quantity_bytes)]
+    pub max_pvc_size: Option<u64>,
 
-    fn deletion_reason(
-        &self,
-        pvc: &PersistentVolumeClaim,
-        config: &ReaperConfig,
-    ) -> Option<DeleteReason> {
-        let unschedulable_pod = self.unschedulable_pod(pvc)?;
-        let pod_name = unschedulable_pod.name_any();
+    /// Never reap PVCs owned by these kinds (comma-separated `Kind` or `group/Kind`, e.g.
+    /// `DataVolume,snapshot.storage.k8s.io/VolumeSnapshot`)
+    #[arg(long, env = "SKIP_OWNER_KINDS", value_delimiter = ',')]
+    pub skip_owner_kinds: Vec<String>,
 
-        if let Some(node) = self.missing_node(pvc) {
-            return Some(DeleteReason::MissingNode {
-                node,
-                pod: pod_name,
-            });
-        }
+    /// Only reap PVCs whose consuming pod has a controller owner of one of
+    /// `--controller-owner-kinds`; bare pods are never recreated once their PVC is gone. On by
+    /// default; turn it off with `--controller-owned-pods-only=false`.
+    #[arg(
+        long,
+        env = "CONTROLLER_OWNED_PODS_ONLY",
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true"
+    )]
+    pub controller_owned_pods_only: bool,
 
-        if config.check_unschedulable_pods {
-            let threshold = Duration::from_secs(config.unschedulable_pod_threshold_secs);
-            return pod_exceeds_unschedulable_thresh(unschedulable_pod, threshold, self.now)
-                .then_some(DeleteReason::UnschedulableTooLong { pod: pod_name });
-        }
+    /// Controller kinds that recreate their pods (comma-separated `Kind` or `group/Kind`)
+    #[arg(
+        long,
+        env = "CONTROLLER_OWNER_KINDS",
+        value_delimiter = ',',
+        default_value = "StatefulSet,ReplicaSet,Job,DaemonSet"
+    )]
+    pub controller_owner_kinds: Vec<String>,
 
-        None
-    }
+    /// Only reap PVCs in these namespaces (comma-separated; empty means all namespaces)
+    #[arg(long, env = "INCLUDE_NAMESPACES", value_delimiter = ',')]
+    pub include_namespaces: Vec<String>,
 
-    fn unschedulable_pod<'a>(&'a self, pvc: &'a PersistentVolumeClaim) -> Option<&'a Pod> {
-        let pvc_name = pvc.name_any();
+    /// List pods, PVCs and StatefulSets only in these namespaces (comma-separated) instead of
+    /// cluster-wide, for clusters that only grant namespaced RBAC. Cluster-scoped lists that are
+    /// forbidden are skipped with a warning; without nodes, missing-node checks are disabled.
+    #[arg(long, env = "NAMESPACES", value_delimiter = ',')]
+    pub namespaces: Vec<String>,
 
-        let pod = self.pods.iter().find(|p| pod_uses_pvc(p, &pvc_name))?;
+    /// Kubeconfig contexts to reap (comma-separated), one cluster each; without any, the
+    /// in-cluster or current kubeconfig configuration is used
+    #[arg(long, env = "CONTEXTS", value_delimiter = ',')]
+    pub contexts: Vec<String>,
 
-        if !pod_is_pending(pod) {
-            return None;
-        }
+    /// Never reap PVCs in these namespaces (comma-separated; wins over the include list)
+    #[arg(long, env = "EXCLUDE_NAMESPACES", value_delimiter = ',')]
+    pub exclude_namespaces: Vec<String>,
 
-        if !pod_is_unschedulable(pod) {
-            info!("Pod {} is pending but not unschedulable", pod.name_any());
-            return None;
-        }
+    /// Also reap in `kube-system`, `kube-public`, `kube-node-lease` and the reaper's own
+    /// namespace, which are skipped by default
+    #[arg(long, env = "INCLUDE_SYSTEM_NAMESPACES", default_value_t = false)]
+    pub include_system_namespaces: bool,
 
-        info!("Pod {} is unschedulable", pod.name_any());
+    /// Namespace the reaper runs in, excluded along with the system namespaces (usually set
+    /// from the downward API)
+    #[arg(long, env = "POD_NAMESPACE")]
+    pub reaper_namespace: Option<String>,
 
-        Some(pod)
-    }
+    /// Only reap PVCs in namespaces matching this label selector (e.g. `env-type=preview`),
+    /// re-evaluated every cycle
+    #[arg(long, env = "NAMESPACE_LABEL_SELECTOR")]
+    pub namespace_label_selector: Option<String>,
 
-    fn missing_node(&self, pvc: &PersistentVolumeClaim) -> Option<String> {
-        let node = get_selected_node(pvc)?;
-        if self.node_names.contains(node) {
-            None
-        } else {
-            Some(node.to_string())
-        }
-    }
+    /// PVCs annotated with this key set to a truthy value are never reaped
+    #[arg(long, env = "PROTECTION_ANNOTATION", default_value = DEFAULT_PROTECTION_ANNOTATION)]
+    pub protection_annotation: String,
 
-    async fn perform_delete(
-        &self,
-        client: &Client,
-        config: &ReaperConfig,
-        namespace: &str,
-        name: &str,
-        reason: &str,
-    ) -> Result<()> {
-        if config.dry_run {
-            info!(
-                "[DRY RUN] Would delete PVC {}/{} ({})",
-                namespace, name, reason
-            );
-            return Ok(());
-        }
+    /// Only reap PVCs annotated with `--opt-in-annotation` set to a truthy value
+    #[arg(long, env = "REQUIRE_OPT_IN", default_value_t = false)]
+    pub require_opt_in: bool,
 
-        delete_pvc(client, namespace, name).await
-    }
-}
+    /// Annotation marking a PVC as reapable when `--require-opt-in` is set
+    #[arg(long, env = "OPT_IN_ANNOTATION", default_value = DEFAULT_OPT_IN_ANNOTATION)]
+    pub opt_in_annotation: String,
 
-#[derive(Debug)]
-enum DeleteReason {
-    MissingNode { node: String, pod: String },
-    UnschedulableTooLong { pod: String },
-}
+    /// Annotation a namespace owner sets to a falsy value (e.g. `false`) on their Namespace to
+    /// keep the reaper out of it entirely; empty disables the namespace check
+    #[arg(long, env = "NAMESPACE_ANNOTATION", default_value = DEFAULT_OPT_IN_ANNOTATION)]
+    pub namespace_annotation: String,
 
-impl DeleteReason {
-    fn describe(&self) -> String {
-        match self {
-            Self::MissingNode { node, pod } => {
-                format!("pod '{}' references missing node '{}'", pod, node)
-            }
-            Self::UnschedulableTooLong { pod } => {
-                format!(
-                    "pod '{}' has been pending past the configured threshold",
-                    pod
-                )
-            }
-        }
-    }
-}
+    /// Only reap in namespaces annotated with `--namespace-annotation` set to a truthy value,
+    /// for rolling the reaper out team by team
+    #[arg(long, env = "REQUIRE_NAMESPACE_OPT_IN", default_value_t = false)]
+    pub require_namespace_opt_in: bool,
 
-/// Get annotation value from PVC metadata
-fn get_pvc_annotation<'a>(pvc: &'a PersistentVolumeClaim, key: &str) -> Option<&'a str> {
-    pvc.metadata
-        .annotations
-        .as_ref()?
-        .get(key)
-        .map(String::as_str)
-}
+    /// Dry run mode - don't actually delete PVCs
+    #[arg(long, env = "DRY_RUN", default_value_t = false)]
+    pub dry_run: bool,
 
-/// Get the selected node annotation from a PVC
-fn get_selected_node(pvc: &PersistentVolumeClaim) -> Option<&str> {
-    get_pvc_annotation(pvc, SELECTED_NODE_ANNOTATION)
-}
+    /// How dry-run checks PVC deletes: `client` skips the API call, `server` has the API server
+    /// validate it without persisting it
+    #[arg(long, env = "DRY_RUN_MODE", value_enum, default_value = "client")]
+    pub dry_run_mode: DryRunMode,
 
-pub async fn reap(client: &Client, config: &ReaperConfig) -> Result<ReapResult> {
-    let state = State::new(client).await?;
-    info!(
-        "Loaded state: {} nodes, {} pods, {} PVCs",
-        state.nodes.len(),
-        state.pods.len(),
-        state.pvcs.len()
-    );
+    /// Output format of `list`, `explain` and `--once`; `json` and `yaml` print the full
+    /// decision set on stdout and send logs to stderr
+    #[arg(long, env = "OUTPUT", value_enum, default_value = "text")]
+    pub output: OutputFormat,
 
-    state.reap(client, config).await
-}
+    /// Log one line per matching PVC per cycle explaining why it was deleted or skipped
+    #[arg(long, env = "EXPLAIN", default_value_t = false)]
+    pub explain: bool,
 
-pub fn matches_storage_criteria(pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> bool {
-    let storage_class = pvc
-        .spec
-        .as_ref()
-        .and_then(|s| s.storage_class_name.as_ref());
+    /// Check for unschedulable pods with unschedulable PVCs. On by default; turn it off with
+    /// `--check-unschedulable-pods=false`.
+    #[arg(
+        long,
+        env = "CHECK_UNSCHEDULABLE_PODS",
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true"
+    )]
+    pub check_unschedulable_pods: bool,
 
-    let provisioner = get_pvc_annotation(pvc, PROVISIONER_ANNOTATION);
+    /// How long a pod must be unschedulable before considering its PVC for deletion
+    /// (seconds, or a duration such as `5m`)
+    #[arg(
+        long,
+        env = "UNSCHEDULABLE_POD_THRESHOLD_SECS",
+        default_value = "120",
+        value_parser = parse_duration_secs
+    )]
+    pub unschedulable_pod_threshold_secs: u64,
 
-    matches!(
-        (storage_class, provisioner),
-        (Some(sc), Some(prov)) if config.storage_classes.contains(sc) && prov == config.storage_provisioner
-    )
-}
+    /// Before deleting for an unschedulable pod, require the pod's latest `FailedScheduling`
+    /// Event to be at most this old (seconds, or a duration such as `10m`), so a stale
+    /// `Unschedulable` condition the scheduler stopped retrying isn't acted on. 0 disables it.
+    #[arg(
+        long,
+        env = "SCHEDULING_EVENT_MAX_AGE_SECS",
+        default_value = "600",
+        value_parser = parse_duration_secs
+    )]
+    pub scheduling_event_max_age_secs: u64,
 
-fn pod_uses_pvc(pod: &Pod, pvc_name: &str) -> bool {
-    get_pod_pvc_names(pod)
-        .iter()
-        .any(|claim_name| claim_name == pvc_name)
-}
+    /// Per-storage-class overrides of `--unschedulable-pod-threshold-secs` (comma-separated
+    /// `class=duration`, class names may be globs, e.g. `openebs-lvm=2m,local-nvme-*=30m`)
+    #[arg(
+        long,
+        env = "CLASS_THRESHOLDS",
+        value_delimiter = ',',
+        value_parser = ClassThreshold::parse
+    )]
+    pub class_thresholds: Vec<ClassThreshold>,
 
-fn pod_is_pending(pod: &Pod) -> bool {
-    pod.status
-        .as_ref()
-        .and_then(|status| status.phase.as_deref())
-        .is_some_and(|phase| phase == "Pending")
-}
+    /// Only treat a pod as stuck on its volume when its `PodScheduled` message contains one of
+    /// these strings (comma-separated, case-insensitive); this applies to the missing-node check
+    /// too, since a pod short of CPU or memory stays pending whether or not its node is gone
+    #[arg(
+        long,
+        env = "VOLUME_FAILURE_MESSAGES",
+        value_delimiter = ',',
+        default_value = "volume node affinity conflict,persistentvolumeclaim,persistent volumes to bind"
+    )]
+    pub volume_failure_messages: Vec<String>,
 
-fn pod_exceeds_unschedulable_thresh(pod: &Pod, threshold: Duration, now: DateTime<Utc>) -> bool {
-    if !pod_is_pending(pod) {
-        return false;
-    }
+    /// Only treat a pod as stuck when its `PodScheduled` message matches this regex, instead of
+    /// `--volume-failure-messages`
+    #[arg(long, env = "POD_MESSAGE_REGEX", value_parser = parse_regex)]
+    pub pod_message_regex: Option<Regex>,
 
-    pod.metadata.creation_timestamp.as_ref().is_some_and(|ts| {
-        now.signed_duration_since(ts.0).num_seconds() >= threshold.as_secs() as i64
-    })
-}
+    /// Treat a selected node whose `Ready` condition has been `False`/`Unknown` for this long
+    /// like a missing node (seconds, or a duration such as `30m`); unset disables the check
+    #[arg(long, env = "NODE_NOT_READY_THRESHOLD_SECS", value_parser = parse_duration_secs)]
+    pub node_not_ready_threshold_secs: Option<u64>,
 
-fn pod_is_unschedulable(pod: &Pod) -> bool {
-    pod.status
-        .as_ref()
-        .and_then(|status| status.conditions.as_ref())
-        .and_then(|conds| {
-            conds.iter().find(|cond| {
-                cond.type_ == "PodScheduled"
-                    && cond.status == "False"
-                    && cond.reason.as_deref() == Some("Unschedulable")
-            })
-        })
-        .is_some()
-}
+    /// Treat a selected node that has stayed cordoned (`spec.unschedulable`) for
+    /// `--cordoned-threshold-secs` like a missing node; cordoning is often temporary
+    #[arg(long, env = "REAP_ON_CORDONED_NODES", default_value_t = false)]
+    pub reap_on_cordoned_nodes: bool,
 
-fn get_pod_pvc_names(pod: &Pod) -> Vec<String> {
-    pod.spec
-        .as_ref()
-        .and_then(|s| s.volumes.as_ref())
-        .map(|volumes| {
-            volumes
-                .iter()
-                .filter_map(|v| v.persistent_volume_claim.as_ref())
-                .map(|pvc| pvc.claim_name.clone())
-                .collect()
-        })
-        .unwrap_or_default()
-}
+    /// How long a node must stay cordoned before `--reap-on-cordoned-nodes` applies (seconds, or
+    /// a duration such as `2h`), measured from when the reaper first saw it cordoned
+    #[arg(
+        long,
+        env = "CORDONED_THRESHOLD_SECS",
+        default_value = "3600",
+        value_parser = parse_duration_secs
+    )]
+    pub cordoned_threshold_secs: u64,
 
-pub async fn delete_pvc(client: &Client, namespace: &str, name: &str) -> Result<()> {
-    Api::<PersistentVolumeClaim>::namespaced(client.clone(), namespace)
-        .delete(name, &DeleteParams::default())
-        .await
-        .context("Failed to delete PVC")?;
-    Ok(())
-}
+    /// Treat a selected node carrying one of these taints like a missing node (comma-separated
+    /// `key[=value]:effect`, e.g. `node.kubernetes.io/out-of-service:NoExecute`)
+    #[arg(
+        long,
+        env = "REAP_NODE_TAINTS",
+        value_delimiter = ',',
+        value_parser = TaintMatcher::parse
+    )]
+    pub reap_node_taints: Vec<TaintMatcher>,
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use k8s_openapi::{
-        api::core::v1::{PersistentVolumeClaimVolumeSource, PodCondition, PodStatus, Volume},
-        apimachinery::pkg::apis::meta::v1::{ObjectMeta, Time},
-    };
+    /// Also reap PVCs whose selected node is missing when no pod references them, e.g. after the
+    /// workload was scaled to zero
+    #[arg(
+        long,
+        env = "REAP_UNREFERENCED_MISSING_NODE_PVCS",
+        default_value_t = false
+    )]
+    pub reap_unreferenced_missing_node_pvcs: bool,
 
-    fn test_pvc(
-        name: &str,
-        storage_class: &str,
-        provisioner: &str,
-        selected_node: Option<&str>,
-    ) -> PersistentVolumeClaim {
+    /// How old a PVC must be before `--reap-unreferenced-missing-node-pvcs` deletes it (seconds,
+    /// or a duration such as `6h`)
+    #[arg(
+        long,
+        env = "UNREFERENCED_MISSING_NODE_MIN_AGE_SECS",
+        default_value = "21600",
+        value_parser = parse_duration_secs
+    )]
+    pub unreferenced_missing_node_min_age_secs: u64,
+
+    /// Only delete when the selected node is missing *and* the pod has been unschedulable past
+    /// the threshold, instead of either condition alone
+    #[arg(long, env = "REQUIRE_BOTH_CONDITIONS", default_value_t = false)]
+    pub require_both_conditions: bool,
+
+    /// Watch for Node deletions and immediately evaluate the PVCs pinned to a deleted node,
+    /// on top of the periodic loop. On by default; turn it off with `--watch-nodes=false`.
+    #[arg(
+        long,
+        env = "WATCH_NODES",
+        default_value_t = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        default_missing_value = "true"
+    )]
+    pub watch_nodes: bool,
+
+    /// Watch pods and evaluate their claims as soon as they turn unschedulable and again once
+    /// they cross the unschedulable threshold, so the interval can be raised without delaying
+    /// recovery
+    #[arg(long, env = "WATCH_PODS", default_value_t = false)]
+    pub watch_pods: bool,
+
+    /// Exit non-zero after this many consecutive failed cycles (0 retries forever)
+    #[arg(long, env = "MAX_CONSECUTIVE_FAILURES", default_value_t = 10)]
+    pub max_consecutive_failures: u32,
+
+    /// Delete PVCs left behind by StatefulSets scaled below their ordinal (requires permission
+    /// to list StatefulSets)
+    #[arg(long, env = "REAP_SCALED_DOWN_STATEFULSETS", default_value_t = false)]
+    pub reap_scaled_down_statefulsets: bool,
+
+    /// How long a scaled-down StatefulSet PVC must stay beyond the replica count before it is
+    /// deleted (seconds, or a duration such as `1h`)
+    #[arg(
+        long,
+        env = "SCALED_DOWN_GRACE_SECS",
+        default_value = "3600",
+        value_parser = parse_duration_secs
+    )]
+    pub scaled_down_grace_secs: u64,
+
+    /// Delete PVCs no pod references whose StatefulSet, named by an ownerReference or implied by
+    /// `<template>-<sts>-<ordinal>` naming, no longer exists (requires permission to list
+    /// StatefulSets)
+    #[arg(long, env = "REAP_ORPHANED_WORKLOAD_PVCS", default_value_t = false)]
+    pub reap_orphaned_workload_pvcs: bool,
+
+    /// How old an orphaned workload PVC must be before it is deleted (seconds, or a duration
+    /// such as `1d`)
+    #[arg(
+        long,
+        env = "ORPHANED_WORKLOAD_MIN_AGE_SECS",
+        default_value = "86400",
+        value_parser = parse_duration_secs
+    )]
+    pub orphaned_workload_min_age_secs: u64,
+
+    /// Delete PVCs whose consumers have all finished (`Succeeded` or `Failed`), e.g. claims of
+    /// completed Jobs
+    #[arg(long, env = "REAP_FINISHED_CONSUMER_PVCS", default_value_t = false)]
+    pub reap_finished_consumer_pvcs: bool,
+
+    /// How long ago the last consumer must have finished before `--reap-finished-consumer-pvcs`
+    /// deletes the PVC (seconds, or a duration such as `7d`)
+    #[arg(
+        long,
+        env = "FINISHED_POD_THRESHOLD_SECS",
+        default_value = "86400",
+        value_parser = parse_duration_secs
+    )]
+    pub finished_pod_threshold_secs: u64,
+
+    /// After deleting a PVC held by an unschedulable, controller-owned pod, delete the pod too so
+    /// its controller recreates it along with a fresh claim (requires permission to delete pods)
+    #[arg(long, env = "DELETE_STUCK_PODS", default_value_t = false)]
+    pub delete_stuck_pods: bool,
+
+    /// After deleting a PVC, also delete the PersistentVolume it was bound to, provided the PV's
+    /// claimRef still points at that PVC (requires permission to delete PersistentVolumes)
+    #[arg(long, env = "DELETE_BOUND_PV", default_value_t = false)]
+    pub delete_bound_pv: bool,
+
+    /// After deleting a PVC of a `WaitForFirstConsumer` StorageClass, wait for it to be gone and
+    /// create a fresh claim with the same spec but no selected node, so the scheduler can place
+    /// it on a healthy node (requires permission to create PVCs)
+    #[arg(long, env = "RECREATE_PVC", default_value_t = false)]
+    pub recreate_pvc: bool,
+
+    /// How long `--recreate-pvc` waits for the old claim to disappear (seconds, or a duration
+    /// such as `2m`)
+    #[arg(
+        long,
+        env = "RECREATE_PVC_TIMEOUT_SECS",
+        default_value = "60",
+        value_parser = parse_duration_secs
+    )]
+    pub recreate_pvc_timeout_secs: u64,
+
+    /// Strip the finalizers of matching PVCs that have been terminating for this long while
+    /// their selected node is missing (seconds, or a duration such as `30m`). Dangerous: the
+    /// volume may be left behind. Unset disables it.
+    #[arg(long, env = "FORCE_REMOVE_FINALIZERS_AFTER_SECS", value_parser = parse_duration_secs)]
+    pub force_remove_finalizers_after_secs: Option<u64>,
+
+    /// Delete `Released` PersistentVolumes from the configured provisioners whose claim no
+    /// longer exists (requires permission to list and delete PersistentVolumes)
+    #[arg(long, env = "CLEANUP_RELEASED_PVS", default_value_t = false)]
+    pub cleanup_released_pvs: bool,
+
+    /// How long a PV must have been `Released` before `--cleanup-released-pvs` deletes it
+    /// (seconds, or a duration such as `1h`)
+    #[arg(
+        long,
+        env = "RELEASED_PV_MIN_AGE_SECS",
+        default_value = "3600",
+        value_parser = parse_duration_secs
+    )]
+    pub released_pv_min_age_secs: u64,
+
+    /// Delete VolumeAttachments of the configured provisioners whose node no longer exists
+    /// (requires permission to list and delete VolumeAttachments)
+    #[arg(long, env = "CLEANUP_VOLUME_ATTACHMENTS", default_value_t = false)]
+    pub cleanup_volume_attachments: bool,
+
+    /// How old a VolumeAttachment must be before `--cleanup-volume-attachments` deletes it
+    /// (seconds, or a duration such as `15m`)
+    #[arg(
+        long,
+        env = "VOLUME_ATTACHMENT_MIN_AGE_SECS",
+        default_value = "900",
+        value_parser = parse_duration_secs
+    )]
+    pub volume_attachment_min_age_secs: u64,
+
+    /// Delete OpenEBS `LVMVolume` custom resources whose owner node no longer exists (requires
+    /// permission to list and delete `lvmvolumes.local.openebs.io`)
+    #[arg(long, env = "CLEANUP_OPENEBS_LVMVOLUMES", default_value_t = false)]
+    pub cleanup_openebs_lvmvolumes: bool,
+
+    /// Name of a ConfigMap whose `paused` key pauses all reaping when set to "true"
+    #[arg(long, env = "PAUSE_CONFIGMAP_NAME")]
+    pub pause_configmap_name: Option<String>,
+
+    /// Namespace of the pause ConfigMap
+    #[arg(long, env = "PAUSE_CONFIGMAP_NAMESPACE", default_value = "pvc-reaper")]
+    pub pause_configmap_namespace: String,
+
+    /// What a paused cycle does: `skip` skips it, `dry-run` still evaluates and logs candidates
+    /// as a kill switch that keeps the reaper observable
+    #[arg(long, env = "PAUSE_MODE", value_enum, default_value = "skip")]
+    pub pause_mode: PauseMode,
+
+    /// Address the HTTP server exposing `/metrics`, `/healthz` and `/readyz` binds to
+    #[arg(long, env = "METRICS_ADDR", default_value = "0.0.0.0:9090")]
+    pub metrics_addr: SocketAddr,
+
+    /// Maximum distinct namespaces used as metric labels; further namespaces are reported as `other`
+    #[arg(long, env = "METRICS_MAX_NAMESPACES", default_value_t = 100)]
+    pub metrics_max_namespaces: usize,
+
+    /// Bearer token required by `POST /reconcile`; without one the endpoint accepts any caller
+    #[arg(long, env = "RECONCILE_TOKEN", hide_env_values = true)]
+    pub reconcile_token: Option<String>,
+
+    /// Log output format
+    #[arg(long, env = "LOG_FORMAT", value_enum, default_value_t = LogFormat::Text)]
+    pub log_format: LogFormat,
+
+    /// OTLP/gRPC endpoint to export reconcile traces to (requires the `otlp` feature)
+    #[arg(long, env = "OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+
+    /// File to append a JSON line to for every deletion (and dry-run would-delete)
+    #[arg(long, env = "AUDIT_LOG_PATH")]
+    pub audit_log_path: Option<PathBuf>,
+
+    /// Directory to write each PVC's manifest to as `<namespace>-<name>-<timestamp>.yaml` right
+    /// before deleting it
+    #[arg(long, env = "BACKUP_DIR")]
+    pub backup_dir: Option<PathBuf>,
+
+    /// Namespace of a ConfigMap to store each PVC's manifest in, keyed by claim, right before
+    /// deleting it
+    #[arg(long, env = "BACKUP_CONFIGMAP_NAMESPACE")]
+    pub backup_configmap_namespace: Option<String>,
+
+    /// Name of the backup ConfigMap, created if missing
+    #[arg(
+        long,
+        env = "BACKUP_CONFIGMAP_NAME",
+        default_value = "pvc-reaper-backups"
+    )]
+    pub backup_configmap_name: String,
+
+    /// Most backups to keep in the backup ConfigMap; the oldest are dropped beyond it, or
+    /// sooner if the ConfigMap would outgrow the API server's 1MiB object limit
+    #[arg(
+        long,
+        env = "BACKUP_CONFIGMAP_MAX_ENTRIES",
+        default_value_t = 200,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub backup_configmap_max_entries: u32,
+
+    /// Whether a failed backup skips the delete (`block`) or is only logged (`best-effort`)
+    #[arg(
+        long,
+        env = "BACKUP_FAILURE_POLICY",
+        value_enum,
+        default_value = "block"
+    )]
+    pub backup_failure_policy: BackupFailurePolicy,
+
+    /// URL to POST a JSON notification to after each deletion
+    #[arg(long, env = "NOTIFY_WEBHOOK_URL")]
+    pub notify_webhook_url: Option<reqwest::Url>,
+
+    /// Slack incoming webhook to post a per-cycle deletion summary to
+    #[arg(long, env = "SLACK_WEBHOOK_URL")]
+    pub slack_webhook_url: Option<reqwest::Url>,
+
+    /// Run a single cycle and exit instead of looping; the exit code reflects whether the cycle
+    /// succeeded
+    #[arg(long, env = "ONCE", default_value_t = false)]
+    pub once: bool,
+
+    /// Prometheus Pushgateway to push metrics to; runs a single cycle, pushes and exits
+    #[arg(long, env = "PUSHGATEWAY_URL")]
+    pub pushgateway_url: Option<reqwest::Url>,
+
+    /// `job` label of the Pushgateway group
+    #[arg(long, env = "PUSHGATEWAY_JOB", default_value = "pvc-reaper")]
+    pub pushgateway_job: String,
+
+    /// Optional `instance` label of the Pushgateway group
+    #[arg(long, env = "PUSHGATEWAY_INSTANCE")]
+    pub pushgateway_instance: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// A storage class name pattern, compiled once when the configuration is parsed
+#[derive(Debug, Clone)]
+pub struct StorageClassPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl StorageClassPattern {
+    /// Parse a literal name or a glob where `*` matches any run of characters and `?` one
+    pub fn glob(pattern: &str) -> Result<Self, String> {
+        let translated: String = pattern
+            .chars()
+            .map(|c| match c {
+                '*' => ".*".to_string(),
+                '?' => ".".to_string(),
+                c => regex::escape(c.encode_utf8(&mut [0; 4])),
+            })
+            .collect();
+        Self::compile(pattern, &translated)
+    }
+
+    /// Parse a regular expression that must match the whole storage class name
+    pub fn regex(pattern: &str) -> Result<Self, String> {
+        Self::compile(pattern, pattern)
+    }
+
+    fn compile(pattern: &str, regex: &str) -> Result<Self, String> {
+        Regex::new(&format!("^(?:{})$", regex))
+            .map(|regex| Self {
+                pattern: pattern.to_string(),
+                regex,
+            })
+            .map_err(|e| format!("invalid storage class pattern '{}': {}", pattern, e))
+    }
+
+    pub fn matches(&self, storage_class: &str) -> bool {
+        self.regex.is_match(storage_class)
+    }
+}
+
+impl fmt::Display for StorageClassPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+/// One term of `--pvc-annotation-selector`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnnotationSelector {
+    /// `key=value`
+    Equals { key: String, value: String },
+    /// `key!=value`; also satisfied when the annotation is absent
+    NotEquals { key: String, value: String },
+    /// `key`
+    Exists { key: String },
+}
+
+impl AnnotationSelector {
+    /// Parse a single `key=value`, `key!=value` or `key` term
+    pub fn parse(term: &str) -> Result<Self, String> {
+        let term = term.trim();
+        let invalid = |why: &str| format!("invalid annotation selector term '{}': {}", term, why);
+        let key = |key: &str| {
+            let key = key.trim();
+            if key.is_empty() {
+                Err(invalid("empty key"))
+            } else if key.contains(char::is_whitespace) || key.contains(['=', '!']) {
+                Err(invalid("key contains whitespace, '=' or '!'"))
+            } else {
+                Ok(key.to_string())
+            }
+        };
+
+        if let Some((k, value)) = term.split_once("!=") {
+            return Ok(Self::NotEquals {
+                key: key(k)?,
+                value: value.trim().to_string(),
+            });
+        }
+        if let Some((k, value)) = term.split_once('=') {
+            let value = value.strip_prefix('=').unwrap_or(value);
+            return Ok(Self::Equals {
+                key: key(k)?,
+                value: value.trim().to_string(),
+            });
+        }
+        Ok(Self::Exists { key: key(term)? })
+    }
+
+    fn matches<'a>(&self, annotation: impl Fn(&str) -> Option<&'a str>) -> bool {
+        match self {
+            Self::Equals { key, value } => annotation(key) == Some(value.as_str()),
+            Self::NotEquals { key, value } => annotation(key) != Some(value.as_str()),
+            Self::Exists { key } => annotation(key).is_some(),
+        }
+    }
+}
+
+/// An unschedulable threshold override for storage classes matching `class`
+#[derive(Debug, Clone)]
+pub struct ClassThreshold {
+    pub class: StorageClassPattern,
+    pub threshold_secs: u64,
+}
+
+impl ClassThreshold {
+    /// Parse `class=duration`, where the duration is bare seconds or humantime
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let (class, threshold) = value.split_once('=').ok_or_else(|| {
+            format!(
+                "invalid class threshold '{}': expected class=duration",
+                value
+            )
+        })?;
+
+        Ok(Self {
+            class: StorageClassPattern::glob(class.trim())?,
+            threshold_secs: parse_duration_secs(threshold)?,
+        })
+    }
+}
+
+/// A node taint that marks the node as gone for good, parsed from `key[=value]:effect`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaintMatcher {
+    pub key: String,
+    /// Matches any value when unset
+    pub value: Option<String>,
+    pub effect: String,
+}
+
+impl TaintMatcher {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let invalid = |reason: &str| format!("invalid taint '{}': {}", value, reason);
+        let (key_value, effect) = value
+            .trim()
+            .rsplit_once(':')
+            .ok_or_else(|| invalid("expected key[=value]:effect"))?;
+        if !["NoSchedule", "PreferNoSchedule", "NoExecute"].contains(&effect) {
+            return Err(invalid(
+                "effect must be NoSchedule, PreferNoSchedule or NoExecute",
+            ));
+        }
+        let (key, taint_value) = match key_value.split_once('=') {
+            Some((key, taint_value)) => (key, Some(taint_value.to_string())),
+            None => (key_value, None),
+        };
+        if key.is_empty() {
+            return Err(invalid("empty key"));
+        }
+
+        Ok(Self {
+            key: key.to_string(),
+            value: taint_value,
+            effect: effect.to_string(),
+        })
+    }
+
+    pub fn matches(&self, taint: &Taint) -> bool {
+        taint.key == self.key
+            && taint.effect == self.effect
+            && self
+                .value
+                .as_ref()
+                .is_none_or(|value| taint.value.as_ref() == Some(value))
+    }
+}
+
+impl fmt::Display for TaintMatcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}={}:{}", self.key, value, self.effect),
+            None => write!(f, "{}:{}", self.key, self.effect),
+        }
+    }
+}
+
+impl ReaperConfig {
+    /// All configured storage class patterns, globs first
+    pub fn storage_class_patterns(&self) -> impl Iterator<Item = &StorageClassPattern> {
+        self.storage_classes.iter().chain(&self.storage_class_regex)
+    }
+
+    /// Unschedulable threshold for `storage_class`: the first matching `--class-thresholds`
+    /// entry, otherwise `--unschedulable-pod-threshold-secs`
+    pub fn unschedulable_threshold_secs(&self, storage_class: Option<&str>) -> u64 {
+        storage_class
+            .and_then(|class| {
+                self.class_thresholds
+                    .iter()
+                    .find(|threshold| threshold.class.matches(class))
+            })
+            .map_or(self.unschedulable_pod_threshold_secs, |threshold| {
+                threshold.threshold_secs
+            })
+    }
+
+    /// Every unschedulable threshold a PVC might be held to, across all `--class-thresholds`
+    fn unschedulable_thresholds(&self) -> BTreeSet<u64> {
+        self.class_thresholds
+            .iter()
+            .map(|threshold| threshold.threshold_secs)
+            .chain([self.unschedulable_pod_threshold_secs])
+            .collect()
+    }
+}
+
+/// Parse a duration given either as bare seconds (`300`) or in humantime format (`5m`, `1h30m`)
+pub fn parse_duration_secs(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Ok(secs);
+    }
+
+    humantime::parse_duration(value)
+        .map(|duration| duration.as_secs())
+        .map_err(|e| format!("invalid duration '{}': {}", value, e))
+}
+
+/// Parse a fraction between 0.0 and 1.0
+pub fn parse_fraction(value: &str) -> Result<f64, String> {
+    match value.trim().parse::<f64>() {
+        Ok(fraction) if (0.0..=1.0).contains(&fraction) => Ok(fraction),
+        _ => Err(format!(
+            "expected a fraction between 0.0 and 1.0, got '{}'",
+            value.trim()
+        )),
+    }
+}
+
+/// Compile a regular expression option, rejecting invalid patterns at startup
+pub fn parse_regex(value: &str) -> Result<Regex, String> {
+    Regex::new(value).map_err(|e| format!("invalid regex '{}': {}", value, e))
+}
+
+/// Parse a Kubernetes quantity (`100Gi`, `500M`) into bytes
+pub fn parse_quantity_bytes(value: &str) -> Result<u64, String> {
+    quantity_to_bytes(value).ok_or_else(|| format!("invalid quantity '{}'", value.trim()))
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ReapResult {
+    /// Total PVCs deleted; the sum of the per-reason counts below. Always zero in dry-run.
+    pub deleted_count: usize,
+    /// PVCs deleted because their selected node no longer exists
+    pub deleted_missing_node: usize,
+    /// PVCs deleted because their pod stayed unschedulable past the threshold
+    pub deleted_unschedulable: usize,
+    /// PVCs deleted because their StatefulSet was scaled down below their ordinal
+    pub deleted_scaled_down: usize,
+    /// PVCs deleted because the workload that created them no longer exists
+    pub deleted_orphaned: usize,
+    /// PVCs deleted because every pod using them finished long ago
+    pub deleted_finished: usize,
+    /// PVCs deleted because an operator annotated them with `pvc-reaper.io/reap-now`
+    pub deleted_operator_requested: usize,
+    /// PVCs that dry-run selected for deletion but left in place
+    pub would_delete_count: usize,
+    /// PVCs selected for deletion that were already gone when the delete was issued
+    pub already_gone_count: usize,
+    /// PVCs selected for deletion whose name had been taken by a new claim by the time the
+    /// delete was issued, so the UID precondition stopped it
+    pub replaced_count: usize,
+    /// PVCs selected for deletion that no longer qualified when fetched again right before the
+    /// delete, e.g. annotated as protected in the meantime
+    pub changed_count: usize,
+    /// Candidates left for the next cycle because `--max-deletions-per-cycle` or
+    /// `--max-deletions-per-namespace` was reached, or `--deletions-per-minute` ran out
+    pub deferred_count: usize,
+    /// Total matching PVCs left alone, including the per-reason counts below
+    pub skipped_count: usize,
+    /// PVCs skipped because their namespace is excluded by the namespace filters
+    pub skipped_namespace: usize,
+    /// PVCs skipped because they carry the protection annotation
+    pub skipped_protected: usize,
+    /// PVCs skipped because `--require-opt-in` is set and they lack the opt-in annotation
+    pub skipped_not_opted_in: usize,
+    /// Stuck pods deleted by `--delete-stuck-pods`. Always zero in dry-run.
+    pub stuck_pods_deleted: usize,
+    /// Stuck pods that dry-run selected for deletion but left in place
+    pub stuck_pods_would_delete: usize,
+    /// PVs of reaped PVCs deleted by `--delete-bound-pv`. Always zero in dry-run.
+    pub bound_pvs_deleted: usize,
+    /// PVs of reaped PVCs that `--delete-bound-pv` failed or declined to delete
+    pub bound_pv_failures: usize,
+    /// PVCs re-created by `--recreate-pvc`, including ones their controller re-created first
+    pub pvcs_recreated: usize,
+    /// PVCs `--recreate-pvc` failed to re-create
+    pub recreate_failures: usize,
+    /// Terminating PVCs whose finalizers `--force-remove-finalizers-after-secs` removed. Always
+    /// zero in dry-run.
+    pub finalizers_removed: usize,
+    /// Released PersistentVolumes deleted by `--cleanup-released-pvs`. Always zero in dry-run.
+    pub released_pvs_deleted: usize,
+    /// Released PersistentVolumes that dry-run selected for deletion but left in place
+    pub released_pvs_would_delete: usize,
+    /// VolumeAttachments deleted by `--cleanup-volume-attachments`. Always zero in dry-run.
+    pub volume_attachments_deleted: usize,
+    /// VolumeAttachments that dry-run selected for deletion but left in place
+    pub volume_attachments_would_delete: usize,
+    /// LVMVolumes deleted by `--cleanup-openebs-lvmvolumes`. Always zero in dry-run.
+    pub lvmvolumes_deleted: usize,
+    /// LVMVolumes that dry-run selected for deletion but left in place
+    pub lvmvolumes_would_delete: usize,
+    /// Requested storage of the PVCs actually deleted, in bytes
+    pub reclaimed_bytes: u64,
+    pub paused: bool,
+    /// The pause ConfigMap was engaged with `--pause-mode=dry-run`, so the cycle ran as in
+    /// dry-run
+    pub kill_switch: bool,
+    /// Deletions were skipped because more than `--max-deletion-fraction` of matching PVCs were
+    /// candidates
+    pub circuit_breaker_open: bool,
+    /// Deletions were skipped because fewer nodes were Ready than `--min-ready-nodes` or
+    /// `--min-ready-node-fraction` require
+    pub too_few_ready_nodes: bool,
+    /// Every PVC deleted (or that would have been, in dry-run) this cycle
+    pub deleted: Vec<DeletedPvc>,
+    /// Every matching PVC left alone this cycle, with why
+    pub skipped: Vec<SkippedPvc>,
+    /// Size of the cluster state loaded at the start of the cycle
+    pub loaded: LoadedCounts,
+    /// `--contexts` whose cycle failed; the counts above only cover the others
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed_clusters: Vec<String>,
+}
+
+/// Number of objects listed when loading cluster state
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct LoadedCounts {
+    pub nodes: usize,
+    pub pods: usize,
+    pub pvcs: usize,
+}
+
+/// A matching PVC a reaping cycle left alone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedPvc {
+    pub namespace: String,
+    pub name: String,
+    pub reason: SkipReason,
+}
+
+/// A PVC removed (or, in dry-run, selected for removal) by a reaping cycle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeletedPvc {
+    pub namespace: String,
+    pub name: String,
+    pub uid: Option<String>,
+    pub storage_class: Option<String>,
+    /// Node from the PVC's selected-node annotation
+    pub node: Option<String>,
+    /// Requested storage as written in the spec, e.g. `10Gi`
+    pub size: Option<String>,
+    /// Requested storage in bytes, zero when unset or unparseable
+    pub size_bytes: u64,
+    pub reason: DeleteReason,
+    pub dry_run: bool,
+}
+
+impl ReapResult {
+    /// Add another cluster's result to this one
+    pub fn merge(&mut self, other: ReapResult) {
+        // Destructured so a new field can't be forgotten here
+        let ReapResult {
+            deleted_count,
+            deleted_missing_node,
+            deleted_unschedulable,
+            deleted_scaled_down,
+            deleted_orphaned,
+            deleted_finished,
+            deleted_operator_requested,
+            would_delete_count,
+            already_gone_count,
+            replaced_count,
+            changed_count,
+            deferred_count,
+            skipped_count,
+            skipped_namespace,
+            skipped_protected,
+            skipped_not_opted_in,
+            stuck_pods_deleted,
+            stuck_pods_would_delete,
+            bound_pvs_deleted,
+            bound_pv_failures,
+            pvcs_recreated,
+            recreate_failures,
+            finalizers_removed,
+            released_pvs_deleted,
+            released_pvs_would_delete,
+            volume_attachments_deleted,
+            volume_attachments_would_delete,
+            lvmvolumes_deleted,
+            lvmvolumes_would_delete,
+            reclaimed_bytes,
+            paused,
+            kill_switch,
+            circuit_breaker_open,
+            too_few_ready_nodes,
+            deleted,
+            skipped,
+            loaded,
+            failed_clusters,
+        } = other;
+
+        self.deleted_count += deleted_count;
+        self.deleted_missing_node += deleted_missing_node;
+        self.deleted_unschedulable += deleted_unschedulable;
+        self.deleted_scaled_down += deleted_scaled_down;
+        self.deleted_orphaned += deleted_orphaned;
+        self.deleted_finished += deleted_finished;
+        self.deleted_operator_requested += deleted_operator_requested;
+        self.would_delete_count += would_delete_count;
+        self.already_gone_count += already_gone_count;
+        self.replaced_count += replaced_count;
+        self.changed_count += changed_count;
+        self.deferred_count += deferred_count;
+        self.skipped_count += skipped_count;
+        self.skipped_namespace += skipped_namespace;
+        self.skipped_protected += skipped_protected;
+        self.skipped_not_opted_in += skipped_not_opted_in;
+        self.stuck_pods_deleted += stuck_pods_deleted;
+        self.stuck_pods_would_delete += stuck_pods_would_delete;
+        self.bound_pvs_deleted += bound_pvs_deleted;
+        self.bound_pv_failures += bound_pv_failures;
+        self.pvcs_recreated += pvcs_recreated;
+        self.recreate_failures += recreate_failures;
+        self.finalizers_removed += finalizers_removed;
+        self.released_pvs_deleted += released_pvs_deleted;
+        self.released_pvs_would_delete += released_pvs_would_delete;
+        self.volume_attachments_deleted += volume_attachments_deleted;
+        self.volume_attachments_would_delete += volume_attachments_would_delete;
+        self.lvmvolumes_deleted += lvmvolumes_deleted;
+        self.lvmvolumes_would_delete += lvmvolumes_would_delete;
+        self.reclaimed_bytes += reclaimed_bytes;
+        self.paused |= paused;
+        self.kill_switch |= kill_switch;
+        self.circuit_breaker_open |= circuit_breaker_open;
+        self.too_few_ready_nodes |= too_few_ready_nodes;
+        self.deleted.extend(deleted);
+        self.skipped.extend(skipped);
+        self.loaded.nodes += loaded.nodes;
+        self.loaded.pods += loaded.pods;
+        self.loaded.pvcs += loaded.pvcs;
+        self.failed_clusters.extend(failed_clusters);
+    }
+
+    fn record_skip(&mut self, namespace: &str, name: &str, reason: &SkipReason) {
+        self.skipped_count += 1;
+        self.skipped.push(SkippedPvc {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            reason: reason.clone(),
+        });
+        match reason {
+            SkipReason::NamespaceExcluded { .. }
+            | SkipReason::SystemNamespace { .. }
+            | SkipReason::NamespaceTerminating { .. }
+            | SkipReason::NamespaceOptedOut { .. } => self.skipped_namespace += 1,
+            SkipReason::Protected { .. } | SkipReason::KeepUntil { .. } => {
+                self.skipped_protected += 1
+            }
+            SkipReason::NotOptedIn { .. } => self.skipped_not_opted_in += 1,
+            _ => {}
+        }
+    }
+
+    fn record_deletion(
+        &mut self,
+        pvc: &PersistentVolumeClaim,
+        reason: DeleteReason,
+        config: &ReaperConfig,
+    ) {
+        let size_bytes = requested_storage_bytes(pvc);
+        if config.dry_run {
+            self.would_delete_count += 1;
+        } else {
+            self.deleted_count += 1;
+            self.reclaimed_bytes += size_bytes;
+            match reason {
+                DeleteReason::MissingNode { .. }
+                | DeleteReason::MissingNodeAndUnschedulable { .. }
+                | DeleteReason::NodeNotReady { .. }
+                | DeleteReason::NodeCordoned { .. }
+                | DeleteReason::NodeTainted { .. }
+                | DeleteReason::MissingNodeUnreferenced { .. } => self.deleted_missing_node += 1,
+                DeleteReason::UnschedulableTooLong { .. } => self.deleted_unschedulable += 1,
+                DeleteReason::ScaledDownStatefulSet { .. } => self.deleted_scaled_down += 1,
+                DeleteReason::WorkloadDeleted { .. } => self.deleted_orphaned += 1,
+                DeleteReason::ConsumersFinished { .. } => self.deleted_finished += 1,
+                DeleteReason::OperatorRequested { .. } => self.deleted_operator_requested += 1,
+            }
+        }
+        self.deleted.push(DeletedPvc {
+            namespace: pvc.namespace().unwrap_or_default(),
+            name: pvc.name_any(),
+            uid: pvc.uid(),
+            storage_class: get_storage_class(pvc).map(str::to_string),
+            node: get_selected_node(pvc, config).map(str::to_string),
+            size: requested_storage(pvc).map(str::to_string),
+            size_bytes,
+            reason,
+            dry_run: config.dry_run,
+        });
+    }
+}
+
+/// Bookkeeping carried across reaping cycles by the long-running loop
+#[derive(Debug, Default)]
+pub struct ReapTracker {
+    /// UIDs of PVCs we've already issued a delete for that are still being listed
+    deletes_in_flight: HashSet<String>,
+    /// UIDs of candidates already warned about during their current pending window
+    warned: HashSet<String>,
+    /// Namespaces given their own metric label so far
+    namespace_labels: telemetry::NamespaceLabels,
+    /// When each scaled-down StatefulSet PVC (by UID) was first seen beyond the replica count
+    excess_since: HashMap<String, DateTime<Utc>>,
+    /// When each node was first seen cordoned, the API doesn't record it
+    cordoned_since: HashMap<String, DateTime<Utc>>,
+    /// Set once the LVMVolume CRD turned out not to be installed, disabling that cleanup
+    lvmvolume_crd_missing: bool,
+    /// Consecutive cycles each PVC (by UID) has been a deletion candidate, with
+    /// `--confirmation-cycles`
+    confirmations: HashMap<String, u32>,
+    /// Set once the missing pause ConfigMap was warned about, until it appears
+    pause_configmap_missing: bool,
+    /// UID and deletion time of the claims deleted within `--redelete-cooldown-secs`, by
+    /// `namespace/name`
+    recently_deleted: HashMap<String, (Option<String>, DateTime<Utc>)>,
+    /// Consecutive cycles each selected node has been absent from the node list, and when it
+    /// was first seen absent
+    missing_nodes: HashMap<String, (u32, DateTime<Utc>)>,
+    /// Tokens left in the `--deletions-per-minute` bucket and when it was last refilled, `None`
+    /// while it is full
+    deletion_tokens: Option<(f64, DateTime<Utc>)>,
+    /// Deletions attempted since the last periodic cycle started, in total and by namespace.
+    /// Targeted runs from the watches draw on the same `--max-deletions-per-cycle` and
+    /// `--max-deletions-per-namespace` budget as the cycle before them.
+    attempted: usize,
+    attempted_in: HashMap<String, usize>,
+    /// Webhook and Slack notifications still being sent
+    notifications: JoinSet<()>,
+    /// UIDs of candidates known to carry `pvc-reaper.io/delete-at`, with
+    /// `--soft-delete-delay-secs`
+    soft_delete_scheduled: HashSet<String>,
+    /// UIDs of candidates whose `pvc-reaper.io/delete-at` was removed by hand, left alone until
+    /// they stop qualifying
+    soft_delete_cancelled: HashSet<String>,
+}
+
+impl ReapTracker {
+    /// Wait for every notification sent so far, so a one-shot run doesn't exit without them
+    pub async fn flush_notifications(&mut self) {
+        notify::drain(&mut self.notifications).await;
+    }
+
+    /// Start a periodic cycle's deletion budget afresh
+    fn reset_attempts(&mut self) {
+        self.attempted = 0;
+        self.attempted_in.clear();
+    }
+
+    fn record_attempt(&mut self, namespace: &str) {
+        self.attempted += 1;
+        *self.attempted_in.entry(namespace.to_string()).or_default() += 1;
+    }
+
+    /// Whether a delete was already issued for this exact PVC (same UID) and is still propagating
+    fn delete_in_flight(&self, pvc: &PersistentVolumeClaim) -> bool {
+        pvc.uid()
+            .is_some_and(|uid| self.deletes_in_flight.contains(&uid))
+    }
+
+    fn record_delete(&mut self, pvc: &PersistentVolumeClaim, at: DateTime<Utc>) {
+        if let Some(uid) = pvc.uid() {
+            self.deletes_in_flight.insert(uid);
+        }
+        self.recently_deleted
+            .insert(claim_key(pvc), (pvc.uid(), at));
+    }
+
+    /// Until when this PVC is in its redelete cooldown: a claim of the same name, but another
+    /// UID, was deleted less than `cooldown_secs` before `now`
+    fn cooldown_until(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        cooldown_secs: u64,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let (uid, deleted_at) = self.recently_deleted.get(&claim_key(pvc))?;
+        let until = *deleted_at + chrono::Duration::seconds(cooldown_secs as i64);
+        (*uid != pvc.uid() && until > now).then_some(until)
+    }
+
+    /// Forget deletions whose cooldown has ended
+    fn prune_cooldowns(&mut self, cooldown_secs: u64, now: DateTime<Utc>) {
+        let cooldown = chrono::Duration::seconds(cooldown_secs as i64);
+        self.recently_deleted
+            .retain(|_, (_, deleted_at)| *deleted_at + cooldown > now);
+    }
+
+    /// Take a `--deletions-per-minute` token for a deletion at `now`, if one is left. The bucket
+    /// holds `per_minute` tokens and refills at that rate.
+    fn take_deletion_token(&mut self, per_minute: u32, now: DateTime<Utc>) -> bool {
+        let capacity = f64::from(per_minute);
+        let tokens = match self.deletion_tokens {
+            Some((tokens, refilled_at)) => {
+                let elapsed_mins = now
+                    .signed_duration_since(refilled_at)
+                    .num_milliseconds()
+                    .max(0) as f64
+                    / 60_000.0;
+                (tokens + elapsed_mins * capacity).min(capacity)
+            }
+            None => capacity,
+        };
+        let taken = tokens >= 1.0;
+        self.deletion_tokens = Some((if taken { tokens - 1.0 } else { tokens }, now));
+        taken
+    }
+
+    /// Record that a pending-deletion warning is due for this PVC. Returns false if it was
+    /// already warned about during the current window.
+    fn mark_warned(&mut self, pvc: &PersistentVolumeClaim) -> bool {
+        pvc.uid().is_some_and(|uid| self.warned.insert(uid))
+    }
+
+    /// When this PVC was first seen as a scaled-down StatefulSet ordinal, starting now if new
+    fn excess_since(&mut self, uid: &str, now: DateTime<Utc>) -> DateTime<Utc> {
+        *self.excess_since.entry(uid.to_string()).or_insert(now)
+    }
+
+    /// Restart the grace period for PVCs that are no longer beyond their StatefulSet's replicas
+    fn retain_excess(&mut self, still_excess: &HashSet<String>) {
+        self.excess_since
+            .retain(|uid, _| still_excess.contains(uid));
+    }
+
+    /// Track when each currently cordoned node was first seen cordoned, forgetting nodes that
+    /// were uncordoned or removed
+    fn observe_cordoned(
+        &mut self,
+        nodes: &[Node],
+        now: DateTime<Utc>,
+    ) -> HashMap<String, DateTime<Utc>> {
+        let cordoned: HashSet<String> = nodes
+            .iter()
+            .filter(|node| node_is_cordoned(node))
+            .map(ResourceExt::name_any)
+            .collect();
+        self.cordoned_since
+            .retain(|name, _| cordoned.contains(name));
+        for name in cordoned {
+            self.cordoned_since.entry(name).or_insert(now);
+        }
+        self.cordoned_since.clone()
+    }
+
+    /// Count another cycle for each selected node absent from the node list, forgetting nodes
+    /// that reappeared or are no longer selected by any PVC
+    fn observe_missing_nodes(
+        &mut self,
+        absent: HashSet<String>,
+        now: DateTime<Utc>,
+    ) -> HashMap<String, (u32, DateTime<Utc>)> {
+        self.missing_nodes.retain(|name, _| absent.contains(name));
+        for name in absent {
+            self.missing_nodes
+                .entry(name)
+                .and_modify(|(cycles, _)| *cycles += 1)
+                .or_insert((1, now));
+        }
+        self.missing_nodes.clone()
+    }
+
+    /// Count another consecutive cycle in which this PVC was a deletion candidate, returning the
+    /// count so far
+    fn confirm(&mut self, pvc: &PersistentVolumeClaim) -> u32 {
+        let Some(uid) = pvc.uid() else {
+            return u32::MAX;
+        };
+        let seen = self.confirmations.entry(uid).or_default();
+        *seen += 1;
+        *seen
+    }
+
+    /// Consecutive cycles this PVC has been a deletion candidate, without counting another
+    fn confirmed(&self, pvc: &PersistentVolumeClaim) -> u32 {
+        pvc.uid().map_or(u32::MAX, |uid| {
+            self.confirmations.get(&uid).copied().unwrap_or_default()
+        })
+    }
+
+    /// Restart the count for a PVC that stopped being a candidate
+    fn unconfirm(&mut self, pvc: &PersistentVolumeClaim) {
+        if let Some(uid) = pvc.uid() {
+            self.confirmations.remove(&uid);
+        }
+    }
+
+    /// Restart the count for PVCs that were not candidates this cycle, or no longer exist
+    fn retain_confirmations(&mut self, candidates: &HashSet<String>) {
+        self.confirmations.retain(|uid, _| candidates.contains(uid));
+    }
+
+    fn mark_soft_delete_scheduled(&mut self, pvc: &PersistentVolumeClaim) {
+        self.soft_delete_scheduled.extend(pvc.uid());
+    }
+
+    /// Whether this candidate's soft delete was cancelled, which a scheduled candidate that lost
+    /// its `pvc-reaper.io/delete-at` annotation now is
+    fn soft_delete_cancelled(&mut self, pvc: &PersistentVolumeClaim) -> bool {
+        let Some(uid) = pvc.uid() else {
+            return false;
+        };
+        if get_pvc_annotation(pvc, DELETE_AT_ANNOTATION).is_none()
+            && self.soft_delete_scheduled.remove(&uid)
+        {
+            self.soft_delete_cancelled.insert(uid.clone());
+        }
+        self.soft_delete_cancelled.contains(&uid)
+    }
+
+    /// Forget the soft deletes of PVCs that are no longer candidates, so one that qualifies
+    /// again starts a fresh countdown
+    fn retain_soft_deletes(&mut self, candidates: &HashSet<String>) {
+        self.soft_delete_scheduled
+            .retain(|uid| candidates.contains(uid));
+        self.soft_delete_cancelled
+            .retain(|uid| candidates.contains(uid));
+    }
+
+    /// End the warning window for PVCs that are no longer pending candidates
+    fn retain_warned(&mut self, still_pending: &HashSet<String>) {
+        self.warned.retain(|uid| still_pending.contains(uid));
+    }
+
+    /// Metric labels for a PVC, collapsing namespaces past the configured cap into `other`
+    fn metric_labels(
+        &mut self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> telemetry::PvcLabels {
+        telemetry::PvcLabels {
+            namespace: self.namespace_labels.label(
+                &pvc.namespace().unwrap_or_default(),
+                config.metrics_max_namespaces,
+            ),
+            storage_class: get_storage_class(pvc).unwrap_or_default().to_string(),
+        }
+    }
+
+    /// Forget deletes whose PVC has disappeared from the listing
+    fn prune(&mut self, pvcs: &[PersistentVolumeClaim]) {
+        let listed: HashSet<String> = pvcs.iter().filter_map(ResourceExt::uid).collect();
+        self.deletes_in_flight.retain(|uid| listed.contains(uid));
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    nodes: Vec<Node>,
+    node_names: HashSet<String>,
+    /// False when `--namespaces` is set and listing nodes is forbidden; every node is then
+    /// unknown rather than missing
+    nodes_listed: bool,
+    pods: Vec<Pod>,
+    pvcs: Vec<PersistentVolumeClaim>,
+    /// Used for `--cleanup-released-pvs` and to find the node a bound PVC's volume is pinned to
+    pvs: Vec<PersistentVolume>,
+    /// Empty unless `--cleanup-volume-attachments` is on
+    volume_attachments: Vec<VolumeAttachment>,
+    /// Empty unless `--cleanup-openebs-lvmvolumes` is on and this is a full cycle
+    lvmvolumes: Vec<DynamicObject>,
+    /// Provisioner of each StorageClass, empty when `--lookup-storage-classes` is off
+    storage_class_provisioners: HashMap<String, String>,
+    /// StorageClasses with `volumeBindingMode: WaitForFirstConsumer`, only looked up with
+    /// `--recreate-pvc`
+    wait_for_first_consumer_classes: HashSet<String>,
+    /// The cluster's default StorageClass, only looked up with `--match-default-storage-class`
+    default_storage_class: Option<String>,
+    /// Empty unless `--reap-scaled-down-statefulsets` or `--reap-orphaned-workload-pvcs` is on
+    statefulsets: Vec<StatefulSet>,
+    /// Namespaces matching `--namespace-label-selector`, `None` when it is unset
+    selected_namespaces: Option<HashSet<String>>,
+    /// Namespaces with a `deletionTimestamp`
+    terminating_namespaces: HashSet<String>,
+    /// Namespaces not opted out with `--namespace-annotation` (or opted in, with
+    /// `--require-namespace-opt-in`), `None` when the annotation is empty
+    enabled_namespaces: Option<HashSet<String>>,
+    /// When each cordoned node was first seen cordoned, empty unless `--reap-on-cordoned-nodes`
+    cordoned_since: HashMap<String, DateTime<Utc>>,
+    /// Consecutive cycles each absent selected node has been missing and since when; a node
+    /// not in here is on its first
+    missing_nodes: HashMap<String, (u32, DateTime<Utc>)>,
+    /// Only evaluate these PVCs, `None` for a full cycle
+    target: Option<Target>,
+    now: DateTime<Utc>,
+}
+
+impl State {
+    #[instrument(name = "list_state", level = "debug", skip_all)]
+    async fn new(client: &Client, config: &ReaperConfig) -> Result<Self> {
+        let start = Instant::now();
+        let nodes = list_cluster::<Node>(client, config, "nodes", &ListParams::default())
+            .await
+            .context("Failed to list nodes")?;
+        if nodes.is_none() {
+            warn!("Missing-node checks are disabled: not allowed to list nodes");
+        }
+        let nodes_listed = nodes.is_some();
+        let nodes = nodes.unwrap_or_default();
+
+        let pods = list_namespaced::<Pod>(client, config, "pods")
+            .await
+            .context("Failed to list pods")?;
+
+        let pvcs =
+            list_namespaced::<PersistentVolumeClaim>(client, config, "persistentvolumeclaims")
+                .await
+                .context("Failed to list PVCs")?;
+
+        let pvs = list_cluster::<PersistentVolume>(
+            client,
+            config,
+            "persistentvolumes",
+            &ListParams::default(),
+        )
+        .await
+        .context("Failed to list PersistentVolumes")?
+        .unwrap_or_default();
+
+        let volume_attachments = if config.cleanup_volume_attachments {
+            let list = Api::<VolumeAttachment>::all(client.clone())
+                .list(&ListParams::default())
+                .await
+                .inspect_err(|_| telemetry::api_error("volumeattachments", "list"))
+                .context("Failed to list VolumeAttachments")?;
+            complete_list(list, "volumeattachments")?
+        } else {
+            Vec::new()
+        };
+
+        let storage_classes = if config.lookup_storage_classes
+            || config.match_default_storage_class
+            || config.recreate_pvc
+        {
+            list_cluster::<StorageClass>(client, config, "storageclasses", &ListParams::default())
+                .await
+                .context("Failed to list StorageClasses")?
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let default_storage_class = config
+            .match_default_storage_class
+            .then(|| default_storage_class(&storage_classes))
+            .flatten();
+        let wait_for_first_consumer_classes = storage_classes
+            .iter()
+            .filter(|class| class.volume_binding_mode.as_deref() == Some("WaitForFirstConsumer"))
+            .map(ResourceExt::name_any)
+            .collect();
+        let storage_class_provisioners = if config.lookup_storage_classes {
+            storage_classes
+                .into_iter()
+                .map(|class| (class.name_any(), class.provisioner))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        let statefulsets =
+            if config.reap_scaled_down_statefulsets || config.reap_orphaned_workload_pvcs {
+                list_namespaced::<StatefulSet>(client, config, "statefulsets")
+                    .await
+                    .context("Failed to list StatefulSets")?
+            } else {
+                Vec::new()
+            };
+
+        // Namespaces outside the label selector are skipped anyway, so one filtered list serves
+        // both checks
+        let mut namespace_params = ListParams::default();
+        if let Some(selector) = &config.namespace_label_selector {
+            namespace_params = namespace_params.labels(selector);
+        }
+        let namespaces = list_cluster::<Namespace>(client, config, "namespaces", &namespace_params)
+            .await
+            .context("Failed to list namespaces")?;
+        let enabled_namespaces = enabled_namespaces(client, config, namespaces.as_deref()).await;
+        let namespaces = namespaces.unwrap_or_default();
+        let selected_namespaces = config
+            .namespace_label_selector
+            .as_ref()
+            .map(|_| namespaces.iter().map(ResourceExt::name_any).collect());
+        let terminating_namespaces = namespaces
+            .iter()
+            .filter(|namespace| namespace.metadata.deletion_timestamp.is_some())
+            .map(ResourceExt::name_any)
+            .collect();
+
+        telemetry::list_state_duration(start.elapsed());
+        let node_names = nodes.iter().map(ResourceExt::name_any).collect();
+
+        Ok(Self {
+            nodes,
+            node_names,
+            nodes_listed,
+            pods,
+            pvcs,
+            pvs,
+            volume_attachments,
+            lvmvolumes: vec![],
+            storage_class_provisioners,
+            wait_for_first_consumer_classes,
+            default_storage_class,
+            statefulsets,
+            selected_namespaces,
+            terminating_namespaces,
+            enabled_namespaces,
+            cordoned_since: HashMap::new(),
+            missing_nodes: HashMap::new(),
+            target: None,
+            now: Utc::now(),
+        })
+    }
+
+    /// The Ready node count and the required minimum, when fewer nodes than
+    /// `--min-ready-nodes` or `--min-ready-node-fraction` are Ready. Nodes that couldn't be
+    /// listed are never counted as missing.
+    fn ready_node_shortfall(&self, config: &ReaperConfig) -> Option<(usize, usize)> {
+        if !self.nodes_listed {
+            return None;
+        }
+        let ready = self.nodes.iter().filter(|node| node_is_ready(node)).count();
+        telemetry::ready_nodes(ready);
+        let required = config
+            .min_ready_node_fraction
+            .map_or(0, |fraction| {
+                (fraction * self.nodes.len() as f64).ceil() as usize
+            })
+            .max(config.min_ready_nodes);
+        (ready < required).then_some((ready, required))
+    }
+
+    /// Like [`matches_storage_criteria`], but falls back to the StorageClass's provisioner when
+    /// the PVC has no provisioner annotation yet, and to the default StorageClass when it has no
+    /// class at all
+    fn matches_storage_criteria(&self, pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> bool {
+        let (storage_class, provisioner) = self.effective_storage(pvc);
+        storage_criteria_match(storage_class, provisioner, config)
+            && matches_annotation_selector(pvc, config)
+    }
+
+    /// The storage class and provisioner [`State::matches_storage_criteria`] compares
+    fn effective_storage<'a>(
+        &'a self,
+        pvc: &'a PersistentVolumeClaim,
+    ) -> (Option<&'a str>, Option<&'a str>) {
+        let storage_class = get_storage_class(pvc).or(self.default_storage_class.as_deref());
+        let provisioner = get_pvc_provisioner(pvc).map(|(_, prov)| prov).or_else(|| {
+            storage_class
+                .and_then(|class| self.storage_class_provisioners.get(class))
+                .map(String::as_str)
+        });
+        (storage_class, provisioner)
+    }
+
+    /// Walk the checks behind [`State::matches_storage_criteria`] and [`State::decide`] for one
+    /// PVC, recording each intermediate result
+    fn explanation(&self, pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> Explanation {
+        let mut checks = Vec::new();
+        let mut check = |name, passed, detail: String| {
+            checks.push(Check {
+                name,
+                passed,
+                detail,
+            })
+        };
+
+        let protected = protected_by(pvc, config);
+        check(
+            "protection",
+            protected.is_none(),
+            match protected {
+                Some(annotation) => format!("protected by annotation {}", annotation),
+                None => format!("no {} annotation", config.protection_annotation),
+            },
+        );
+
+        if get_pvc_annotation(pvc, REAP_NOW_ANNOTATION).is_some_and(is_truthy) {
+            check(
+                "reap-now",
+                true,
+                match annotation_manager(pvc, REAP_NOW_ANNOTATION) {
+                    Some(manager) => format!("requested by '{}'", manager),
+                    None => "requested".to_string(),
+                },
+            );
+        }
+
+        let (storage_class, provisioner) = self.effective_storage(pvc);
+        let class_matched = storage_class
+            .is_some_and(|class| config.storage_class_patterns().any(|p| p.matches(class)));
+        check(
+            "storage class",
+            class_matched,
+            match storage_class {
+                Some(class) if class_matched => format!("'{}' matches", class),
+                Some(class) => format!("'{}' matches no configured pattern", class),
+                None => "no storage class".to_string(),
+            },
+        );
+        let provisioner_matched =
+            provisioner.is_some_and(|prov| config.storage_provisioner.iter().any(|p| p == prov));
+        check(
+            "provisioner",
+            provisioner_matched,
+            match provisioner {
+                Some(prov) if provisioner_matched => format!("'{}' matches", prov),
+                Some(prov) => format!("'{}' is not a configured provisioner", prov),
+                None => "no provisioner".to_string(),
+            },
+        );
+        if !config.pvc_annotation_selector.is_empty() {
+            let matched = matches_annotation_selector(pvc, config);
+            check(
+                "annotation selector",
+                matched,
+                if matched { "matches" } else { "doesn't match" }.to_string(),
+            );
+        }
+
+        let consumers: Vec<&Pod> = self.pods.iter().filter(|p| pod_uses_pvc(p, pvc)).collect();
+        check(
+            "consuming pods",
+            !consumers.is_empty(),
+            if consumers.is_empty() {
+                "none".to_string()
+            } else {
+                consumers
+                    .iter()
+                    .map(|pod| {
+                        format!(
+                            "{} ({})",
+                            pod.name_any(),
+                            pod_phase(pod).unwrap_or("Unknown")
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            },
+        );
+
+        let mounting = self.pod_mounting(pvc);
+        check(
+            "not mounted",
+            mounting.is_none(),
+            match mounting {
+                Some(pod) => format!("mounted by running pod '{}'", pod.name_any()),
+                None => "no running or scheduled consumer".to_string(),
+            },
+        );
+
+        let unschedulable = consumers
+            .iter()
+            .find(|pod| pod_is_pending(pod) && pod_is_unschedulable(pod));
+        check(
+            "unschedulable pod",
+            unschedulable.is_some(),
+            match unschedulable {
+                Some(pod) => format!(
+                    "'{}': {}",
+                    pod.name_any(),
+                    scheduling_message(pod).unwrap_or("no message")
+                ),
+                None => "no consumer is Pending with PodScheduled=False/Unschedulable".to_string(),
+            },
+        );
+        if let Some(pod) = unschedulable {
+            let related = match &config.pod_message_regex {
+                Some(regex) => scheduling_message(pod).is_some_and(|m| regex.is_match(m)),
+                None => is_volume_failure(pod, config),
+            };
+            check(
+                "volume related",
+                related,
+                match &config.pod_message_regex {
+                    Some(regex) => format!("message matched against /{}/", regex),
+                    None => "message matched against --volume-failure-messages".to_string(),
+                },
+            );
+
+            let unschedulable_secs = pod_unschedulable_secs(pod, self.now).unwrap_or_default();
+            let threshold_secs = config.unschedulable_threshold_secs(get_storage_class(pvc));
+            check(
+                "threshold",
+                unschedulable_secs >= threshold_secs as i64,
+                format!(
+                    "unschedulable for {}s, threshold {}s",
+                    unschedulable_secs, threshold_secs
+                ),
+            );
+        }
+
+        let node = self.pinned_node(pvc, config);
+        let missing = self.missing_node(pvc, config);
+        check(
+            "node missing",
+            missing.is_some(),
+            match node {
+                None => "no selected node".to_string(),
+                Some(_) if !self.nodes_listed => "nodes could not be listed".to_string(),
+                Some(node) if missing.is_some() => format!("'{}' no longer exists", node),
+                Some(node) if self.absent_node(pvc, config).is_some() => format!(
+                    "'{}' missing for {}/{} cycles",
+                    node,
+                    self.node_missing_cycles(node),
+                    config.node_missing_cycles
+                ),
+                Some(node) => format!("'{}' exists", node),
+            },
+        );
+
+        let decision = match protected {
+            Some(annotation) => Some(Err(SkipReason::Protected {
+                annotation: annotation.to_string(),
+            })),
+            None if self.matches_storage_criteria(pvc, config) => Some(self.decide(
+                pvc,
+                config,
+                &mut ReapTracker::default(),
+                &mut HashSet::new(),
+            )),
+            None => None,
+        };
+
+        Explanation {
+            namespace: pvc.namespace().unwrap_or_default(),
+            name: pvc.name_any(),
+            checks,
+            decision,
+        }
+    }
+
+    /// Whether a PVC that passed the storage checks should be deleted
+    fn decide(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+        tracker: &mut ReapTracker,
+        excess_ordinals: &mut HashSet<String>,
+    ) -> Result<DeleteReason, SkipReason> {
+        let skip = self
+            .terminating(pvc)
+            .or_else(|| self.namespace_terminating(pvc))
+            .or_else(|| self.namespace_unselected(pvc))
+            .or_else(|| self.namespace_opted_out(pvc, config))
+            .or_else(|| self.kept_until(pvc))
+            .or_else(|| pre_evaluation_skip(pvc, config, tracker));
+        match skip {
+            Some(skip) => Err(skip),
+            None => self
+                .reap_now_decision(pvc)
+                .or_else(|| self.scaled_down_decision(pvc, config, tracker, excess_ordinals))
+                .or_else(|| self.orphaned_workload_decision(pvc, config))
+                .or_else(|| self.unreferenced_missing_node_decision(pvc, config))
+                .or_else(|| self.finished_consumers_decision(pvc, config))
+                .unwrap_or_else(|| self.deletion_reason(pvc, config)),
+        }
+    }
+
+    /// What [`State::decide`] makes of every matching PVC. Uses a fresh tracker, so grace periods
+    /// tracked across cycles (scaled-down ordinals, cordons) count as just started.
+    fn candidates(&self, config: &ReaperConfig) -> CandidateList {
+        let mut tracker = ReapTracker::default();
+        let mut excess_ordinals = HashSet::new();
+        let mut list = CandidateList::default();
+        for pvc in &self.pvcs {
+            let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+            if !self.matches_storage_criteria(pvc, config) {
+                continue;
+            }
+            let reason = match self.decide(pvc, config, &mut tracker, &mut excess_ordinals) {
+                Ok(reason) => reason,
+                Err(reason) => {
+                    list.skipped.push(SkippedPvc {
+                        namespace,
+                        name,
+                        reason,
+                    });
+                    continue;
+                }
+            };
+
+            let pending_secs = reason
+                .pod()
+                .and_then(|pod_name| {
+                    self.pods.iter().find(|pod| {
+                        pod.namespace().as_deref() == Some(namespace.as_str())
+                            && pod.name_any() == pod_name
+                    })
+                })
+                .filter(|pod| pod_is_unschedulable(pod))
+                .and_then(|pod| pod_unschedulable_secs(pod, self.now));
+            list.candidates.push(Candidate {
+                namespace,
+                name,
+                storage_class: get_storage_class(pvc).map(str::to_string),
+                node: self.pinned_node(pvc, config).map(str::to_string),
+                pod: reason.pod().map(str::to_string),
+                reason,
+                pending_secs,
+            });
+        }
+        list
+    }
+
+    /// Delete PVCs an operator annotated with `pvc-reaper.io/reap-now`, whatever their pods'
+    /// state, unless a running pod still mounts them
+    fn reap_now_decision(
+        &self,
+        pvc: &PersistentVolumeClaim,
+    ) -> Option<Result<DeleteReason, SkipReason>> {
+        if !get_pvc_annotation(pvc, REAP_NOW_ANNOTATION).is_some_and(is_truthy) {
+            return None;
+        }
+
+        if let Some(pod) = self.pod_mounting(pvc) {
+            warn!(
+                "PVC {} is annotated {} but mounted by running pod {}, refusing to delete it",
+                pvc.name_any(),
+                REAP_NOW_ANNOTATION,
+                pod.name_any()
+            );
+            return Some(Err(SkipReason::InUse {
+                pod: pod.name_any(),
+            }));
+        }
+
+        Some(Ok(DeleteReason::OperatorRequested {
+            requested_by: annotation_manager(pvc, REAP_NOW_ANNOTATION).map(str::to_string),
+        }))
+    }
+
+    /// Skip PVCs that are already being deleted, e.g. held back by a finalizer
+    fn terminating(&self, pvc: &PersistentVolumeClaim) -> Option<SkipReason> {
+        let since = pvc.metadata.deletion_timestamp.as_ref()?;
+        let terminating_secs = self.now.signed_duration_since(since.0).num_seconds();
+        debug!(
+            namespace = %pvc.namespace().unwrap_or_default(),
+            pvc = %pvc.name_any(),
+            terminating_secs,
+            "PVC is already terminating, skipping"
+        );
+        Some(SkipReason::Terminating { terminating_secs })
+    }
+
+    /// Skip PVCs whose keep-until annotation is still in the future. Unparseable timestamps
+    /// protect the PVC indefinitely rather than being ignored.
+    fn kept_until(&self, pvc: &PersistentVolumeClaim) -> Option<SkipReason> {
+        let until = get_pvc_annotation(pvc, KEEP_UNTIL_ANNOTATION)?;
+        match DateTime::parse_from_rfc3339(until.trim()) {
+            Ok(until) if until <= self.now => None,
+            Ok(_) => Some(SkipReason::KeepUntil {
+                until: until.to_string(),
+            }),
+            Err(e) => {
+                warn!(
+                    namespace = %pvc.namespace().unwrap_or_default(),
+                    pvc = %pvc.name_any(),
+                    "Invalid {} annotation '{}', keeping PVC: {}",
+                    KEEP_UNTIL_ANNOTATION,
+                    until,
+                    e
+                );
+                Some(SkipReason::KeepUntil {
+                    until: until.to_string(),
+                })
+            }
+        }
+    }
+
+    /// Whether a targeted run covers this PVC; full cycles cover every PVC
+    fn is_targeted(&self, pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> bool {
+        match &self.target {
+            None => true,
+            Some(Target::Nodes(nodes)) => self
+                .pinned_node(pvc, config)
+                .is_some_and(|node| nodes.contains(node)),
+            Some(Target::Claims(claims)) => {
+                claims.contains(&(pvc.namespace().unwrap_or_default(), pvc.name_any()))
+            }
+        }
+    }
+
+    /// Skip PVCs in namespaces being deleted, which garbage-collect them on their own
+    fn namespace_terminating(&self, pvc: &PersistentVolumeClaim) -> Option<SkipReason> {
+        let namespace = pvc.namespace().unwrap_or_default();
+        if !self.terminating_namespaces.contains(&namespace) {
+            return None;
+        }
+        debug!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            "Namespace is terminating, skipping PVC"
+        );
+        Some(SkipReason::NamespaceTerminating { namespace })
+    }
+
+    /// Skip PVCs outside the namespaces matched by `--namespace-label-selector`
+    fn namespace_unselected(&self, pvc: &PersistentVolumeClaim) -> Option<SkipReason> {
+        let namespace = pvc.namespace().unwrap_or_default();
+        self.selected_namespaces
+            .as_ref()
+            .is_some_and(|selected| !selected.contains(&namespace))
+            .then_some(SkipReason::NamespaceExcluded { namespace })
+    }
+
+    /// Skip PVCs in namespaces whose owners opted out with `--namespace-annotation`, or never
+    /// opted in under `--require-namespace-opt-in`
+    fn namespace_opted_out(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<SkipReason> {
+        let namespace = pvc.namespace().unwrap_or_default();
+        self.enabled_namespaces
+            .as_ref()
+            .is_some_and(|enabled| !enabled.contains(&namespace))
+            .then(|| SkipReason::NamespaceOptedOut {
+                namespace,
+                annotation: config.namespace_annotation.clone(),
+            })
+    }
+
+    async fn reap(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        tracker: &mut ReapTracker,
+    ) -> Result<ReapResult> {
+        let mut result = ReapResult::default();
+        let mut eval_pass = EvaluationPass::default();
+        let mut delete_pass = DeletionPass::new(client, config);
+        let targeted = self.target.is_some();
+        if !targeted {
+            tracker.prune(&self.pvcs);
+            tracker.reset_attempts();
+        }
+        tracker.prune_cooldowns(config.redelete_cooldown_secs, self.now);
+
+        // Oldest first, so a deletion cap works through the backlog in a stable order
+        let mut pvcs: Vec<&PersistentVolumeClaim> = self.pvcs.iter().collect();
+        pvcs.sort_by(|a, b| {
+            (a.creation_timestamp(), a.namespace(), a.name_any()).cmp(&(
+                b.creation_timestamp(),
+                b.namespace(),
+                b.name_any(),
+            ))
+        });
+
+        // Evaluate every PVC before deleting any, so the circuit breaker sees the whole cycle
+        let mut evaluations = Vec::new();
+        for pvc in pvcs {
+            if !self.is_targeted(pvc, config) {
+                continue;
+            }
+
+            if !self.matches_storage_criteria(pvc, config) {
+                continue;
+            }
+
+            evaluations.push(self.evaluate(pvc, config, tracker, &mut eval_pass));
+        }
+
+        // A targeted run evaluated only some PVCs, but the breaker weighs them against all
+        let matching = self
+            .pvcs
+            .iter()
+            .filter(|pvc| protected_by(pvc, config).is_none())
+            .filter(|pvc| self.matches_storage_criteria(pvc, config))
+            .count();
+        let candidates = eval_pass.gauges.candidates;
+        result.circuit_breaker_open = circuit_breaker_trips(config, candidates, matching);
+        if result.circuit_breaker_open {
+            error!(
+                candidates,
+                matching,
+                "Circuit breaker open: {} of {} matching PVCs are deletion candidates, more than --max-deletion-fraction={} allows; deleting nothing this cycle",
+                candidates,
+                matching,
+                config.max_deletion_fraction.unwrap_or_default()
+            );
+            telemetry::circuit_breaker_tripped();
+        }
+
+        let ready_shortfall = self.ready_node_shortfall(config);
+        if let Some((ready, required)) = ready_shortfall {
+            error!(
+                ready,
+                required,
+                "Only {} of {} nodes are Ready, below the required {}; deleting nothing this cycle",
+                ready,
+                self.nodes.len(),
+                required
+            );
+            telemetry::ready_node_floor_tripped();
+            result.too_few_ready_nodes = true;
+        }
+
+        // An empty node list with claims pinned to nodes is far likelier a bad listing than a
+        // cluster without nodes, and would make every pinned claim look orphaned
+        let node_list_empty = self.nodes_listed
+            && self.nodes.is_empty()
+            && self
+                .pvcs
+                .iter()
+                .any(|pvc| self.pinned_node(pvc, config).is_some());
+        if node_list_empty {
+            error!(
+                "Node list is empty but PVCs reference selected nodes; skipping missing-node deletions this cycle"
+            );
+        }
+
+        // Hold candidates back if a safety check failed
+        for evaluation in &mut evaluations {
+            let Ok(candidate) = &evaluation.reason else {
+                continue;
+            };
+            let candidate = candidate.clone();
+            if let Some((ready, required)) = ready_shortfall {
+                evaluation.reason = Err(SkipReason::TooFewReadyNodes {
+                    reason: candidate,
+                    ready,
+                    required,
+                });
+            } else if node_list_empty && candidate.is_missing_node() {
+                evaluation.reason = Err(SkipReason::NodeListEmpty { reason: candidate });
+            } else if result.circuit_breaker_open {
+                evaluation.reason = Err(SkipReason::CircuitBreakerOpen {
+                    reason: candidate,
+                    candidates,
+                    matching,
+                });
+            }
+        }
+
+        // Only candidates that passed everything else cost an Event list
+        if config.scheduling_event_max_age_secs > 0 {
+            for evaluation in &mut evaluations {
+                let Ok(candidate @ DeleteReason::UnschedulableTooLong { pod, .. }) =
+                    &evaluation.reason
+                else {
+                    continue;
+                };
+                let skip = self
+                    .stale_scheduling_event(client, config, &evaluation.namespace, pod, candidate)
+                    .instrument(evaluation.span.clone())
+                    .await;
+                if let Some(skip) = skip {
+                    evaluation.reason = Err(skip);
+                }
+            }
+        }
+
+        if config.interactive {
+            confirm_interactively(&mut evaluations).await?;
+        }
+
+        // The cleanup passes act on the same node list, so a failed check stops them too
+        let unsafe_cycle =
+            node_list_empty || ready_shortfall.is_some() || result.circuit_breaker_open;
+
+        for evaluation in evaluations {
+            let span = evaluation.span.clone();
+            self.act_on(
+                client,
+                config,
+                tracker,
+                evaluation,
+                &mut delete_pass,
+                &mut result,
+            )
+            .instrument(span)
+            .await;
+        }
+
+        for (namespace, deferred) in &delete_pass.deferred_in {
+            info!(
+                namespace = %namespace,
+                deferred,
+                "Reached --max-deletions-per-namespace={} in namespace {}, deferred {} candidates to the next cycle",
+                config.max_deletions_per_namespace,
+                namespace,
+                deferred
+            );
+        }
+        let deferred_by_cap = result.deferred_count
+            - delete_pass.deferred_in.values().sum::<usize>()
+            - delete_pass.rate_limited;
+        if deferred_by_cap > 0 {
+            warn!(
+                "Reached --max-deletions-per-cycle={}, deferred {} candidates to the next cycle",
+                config.max_deletions_per_cycle, deferred_by_cap
+            );
+        }
+        if delete_pass.rate_limited > 0 {
+            warn!(
+                "Out of --deletions-per-minute={} tokens, deferred {} candidates to the next cycle",
+                config.deletions_per_minute.unwrap_or_default(),
+                delete_pass.rate_limited
+            );
+        }
+
+        if let Some(Err(e)) = delete_pass.audit_log.as_mut().map(AuditLog::flush) {
+            error!("Failed to flush audit log: {}", e);
+        }
+
+        if let Some(url) = &config.slack_webhook_url {
+            notify::spawn_slack_summary(&mut tracker.notifications, url.clone(), &result.deleted);
+        }
+
+        // A targeted run only saw some PVCs, so it must not forget state about the others
+        if !targeted {
+            tracker.retain_warned(&delete_pass.pending_candidates);
+            tracker.retain_confirmations(&eval_pass.confirming);
+            if config.soft_delete_delay_secs.is_some() {
+                self.cancel_scheduled_deletions(client, config, &delete_pass.scheduled)
+                    .await;
+                tracker.retain_soft_deletes(&delete_pass.scheduled);
+            }
+            tracker.retain_excess(&eval_pass.excess_ordinals);
+            eval_pass.gauges.publish();
+            if unsafe_cycle {
+                warn!(
+                    "Skipping the finalizer, PV, VolumeAttachment and LVMVolume cleanup passes this cycle"
+                );
+            } else {
+                self.cleanup(client, config, &delete_pass.recorder, tracker, &mut result)
+                    .await;
+            }
+        }
+        delete_pass.summary.log();
+
+        info!(
+            "Reaping complete: deleted={} (missing_node={}, unschedulable={}, scaled_down={}, orphaned={}, finished={}, reap_now={}), already_gone={}, replaced={}, changed={}, would_delete={}, skipped={} (namespace={}, protected={}, not_opted_in={})",
+            result.deleted_count,
+            result.deleted_missing_node,
+            result.deleted_unschedulable,
+            result.deleted_scaled_down,
+            result.deleted_orphaned,
+            result.deleted_finished,
+            result.deleted_operator_requested,
+            result.already_gone_count,
+            result.replaced_count,
+            result.changed_count,
+            result.would_delete_count,
+            result.skipped_count,
+            result.skipped_namespace,
+            result.skipped_protected,
+            result.skipped_not_opted_in
+        );
+
+        Ok(result)
+    }
+
+    /// Decide one matching PVC for the evaluation pass of [`State::reap`], inside the span that
+    /// follows it through the deletion pass
+    fn evaluate<'a>(
+        &self,
+        pvc: &'a PersistentVolumeClaim,
+        config: &ReaperConfig,
+        tracker: &mut ReapTracker,
+        pass: &mut EvaluationPass,
+    ) -> Evaluation<'a> {
+        let namespace = pvc.namespace().unwrap_or_default();
+        let pvc_name = pvc.name_any();
+        let span = info_span!(
+            "pvc",
+            namespace = %namespace,
+            pvc = %pvc_name,
+            node = self.pinned_node(pvc, config).unwrap_or_default(),
+            storage_class = get_storage_class(pvc).unwrap_or_default(),
+            decision = field::Empty,
+            reason = field::Empty,
+        );
+        let labels = tracker.metric_labels(pvc, config);
+
+        let reason = span.in_scope(|| {
+            let mut reason = self.decide(pvc, config, tracker, &mut pass.excess_ordinals);
+            if let Err(SkipReason::NamespaceOptedOut { annotation, .. }) = &reason
+                && pass.opted_out_logged.insert(namespace.clone())
+            {
+                info!(
+                    namespace = %namespace,
+                    "Namespace is not enabled for reaping by annotation {}, skipping its PVCs",
+                    annotation
+                );
+            }
+            // An explicit reap-now is honoured even for a claim that was just re-created
+            if let Ok(candidate) = &reason
+                && !matches!(candidate, DeleteReason::OperatorRequested { .. })
+                && let Some(until) =
+                    tracker.cooldown_until(pvc, config.redelete_cooldown_secs, self.now)
+            {
+                info!(
+                    namespace = %namespace,
+                    pvc = %pvc_name,
+                    reason = %candidate.describe(),
+                    "PVC was re-created after being deleted, in redelete cooldown until {}",
+                    until.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+                );
+                reason = Err(SkipReason::RedeleteCooldown {
+                    reason: candidate.clone(),
+                    until,
+                });
+            }
+            pass.gauges.observe(self, pvc, &reason);
+            if config.confirmation_cycles > 1 {
+                match reason {
+                    Ok(candidate) => {
+                        pass.confirming.extend(pvc.uid());
+                        // Watch events between cycles mustn't rush a PVC through confirmation
+                        let seen = if self.target.is_some() {
+                            tracker.confirmed(pvc)
+                        } else {
+                            tracker.confirm(pvc)
+                        };
+                        reason = if seen < config.confirmation_cycles {
+                            info!(
+                                namespace = %namespace,
+                                pvc = %pvc_name,
+                                reason = %candidate.describe(),
+                                "PVC is a deletion candidate {}/{}, waiting for confirmation",
+                                seen,
+                                config.confirmation_cycles
+                            );
+                            Err(SkipReason::AwaitingConfirmation {
+                                reason: candidate,
+                                seen,
+                                required: config.confirmation_cycles,
+                            })
+                        } else {
+                            Ok(candidate)
+                        };
+                    }
+                    Err(_) => tracker.unconfirm(pvc),
+                }
+            }
+            reason
+        });
+        Evaluation {
+            pvc,
+            namespace,
+            pvc_name,
+            span,
+            labels,
+            reason,
+        }
+    }
+
+    /// Carry out one evaluated PVC's decision in the deletion pass of [`State::reap`], after
+    /// holding it back for soft delete, the deletion caps and the rate limit
+    async fn act_on(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        tracker: &mut ReapTracker,
+        evaluation: Evaluation<'_>,
+        pass: &mut DeletionPass,
+        result: &mut ReapResult,
+    ) {
+        let Evaluation {
+            pvc,
+            namespace,
+            pvc_name,
+            labels,
+            mut reason,
+            ..
+        } = evaluation;
+        if let Some(delay_secs) = config.soft_delete_delay_secs
+            && let Ok(candidate) = reason
+        {
+            pass.scheduled.extend(pvc.uid());
+            reason = if tracker.soft_delete_cancelled(pvc) {
+                info!(
+                    namespace = %namespace,
+                    pvc = %pvc_name,
+                    "Scheduled deletion was cancelled by removing the {} annotation, leaving the PVC alone while it qualifies",
+                    DELETE_AT_ANNOTATION
+                );
+                Err(SkipReason::SoftDeleteCancelled { reason: candidate })
+            } else {
+                let delete_at = match scheduled_delete_at(pvc) {
+                    Some(delete_at) => {
+                        tracker.mark_soft_delete_scheduled(pvc);
+                        delete_at
+                    }
+                    None => {
+                        let delete_at = self.now + chrono::Duration::seconds(delay_secs as i64);
+                        if schedule_deletion(
+                            client,
+                            config,
+                            &pass.recorder,
+                            pvc,
+                            &candidate,
+                            delete_at,
+                        )
+                        .await
+                        {
+                            tracker.mark_soft_delete_scheduled(pvc);
+                        }
+                        delete_at
+                    }
+                };
+                if delete_at <= self.now {
+                    Ok(candidate)
+                } else {
+                    Err(SkipReason::ScheduledForDeletion {
+                        reason: candidate,
+                        delete_at,
+                    })
+                }
+            };
+        }
+        let namespace_attempts = tracker
+            .attempted_in
+            .get(&namespace)
+            .copied()
+            .unwrap_or_default();
+        if config.max_deletions_per_namespace > 0
+            && namespace_attempts >= config.max_deletions_per_namespace
+            && let Ok(candidate) = reason
+        {
+            result.deferred_count += 1;
+            *pass.deferred_in.entry(namespace.clone()).or_default() += 1;
+            reason = Err(SkipReason::NamespaceLimitReached {
+                reason: candidate,
+                namespace: namespace.clone(),
+                limit: config.max_deletions_per_namespace,
+            });
+        }
+        if config.max_deletions_per_cycle > 0
+            && tracker.attempted >= config.max_deletions_per_cycle
+            && let Ok(candidate) = reason
+        {
+            result.deferred_count += 1;
+            reason = Err(SkipReason::DeletionLimitReached {
+                reason: candidate,
+                limit: config.max_deletions_per_cycle,
+            });
+        }
+        if let Some(per_minute) = config.deletions_per_minute
+            && let Ok(candidate) = &reason
+            && !tracker.take_deletion_token(per_minute, self.now)
+        {
+            result.deferred_count += 1;
+            pass.rate_limited += 1;
+            reason = Err(SkipReason::RateLimited {
+                reason: candidate.clone(),
+                per_minute,
+            });
+        }
+        if config.explain {
+            explain(&namespace, &pvc_name, &reason);
+        }
+
+        match reason {
+            Ok(reason) => {
+                let description = reason.describe();
+                info!(
+                    namespace = %namespace,
+                    pvc = %pvc_name,
+                    reason = %description,
+                    dry_run = config.dry_run,
+                    "PVC scheduled for deletion"
+                );
+                tracker.record_attempt(&namespace);
+
+                let outcome = match self.revalidate(client, config, pvc, &reason).await {
+                    Ok(Revalidation::Current) => {
+                        self.perform_delete(client, config, &pass.recorder, pvc, &reason, &labels)
+                            .await
+                    }
+                    Ok(Revalidation::Gone) => Ok(DeleteOutcome::AlreadyGone),
+                    Ok(Revalidation::Replaced) => Ok(DeleteOutcome::Replaced),
+                    Ok(Revalidation::Changed) => {
+                        record_decision("changed", reason.variant());
+                        result.changed_count += 1;
+                        return;
+                    }
+                    Err(e) => Err(e),
+                };
+                match outcome {
+                    Err(e) => {
+                        error!(
+                            namespace = %namespace,
+                            pvc = %pvc_name,
+                            reason = %description,
+                            "Failed to delete PVC: {:#}",
+                            e
+                        );
+                        record_decision("delete_failed", reason.variant());
+                        telemetry::delete_error();
+                        pass.summary.record_delete_failure();
+                    }
+                    Ok(DeleteOutcome::AlreadyGone) => {
+                        record_decision("already_gone", reason.variant());
+                        result.already_gone_count += 1;
+                    }
+                    Ok(DeleteOutcome::Replaced) => {
+                        record_decision("replaced", reason.variant());
+                        result.replaced_count += 1;
+                    }
+                    Ok(DeleteOutcome::Deleted) => {
+                        if config.dry_run {
+                            record_decision("would_delete", reason.variant());
+                        } else {
+                            record_decision("delete", reason.variant());
+                            tracker.record_delete(pvc, self.now);
+                        }
+                        if let Some(log) = pass.audit_log.as_mut()
+                            && let Err(e) = log.append(&AuditRecord::new(pvc, &reason, config))
+                        {
+                            error!(
+                                namespace = %namespace,
+                                pvc = %pvc_name,
+                                "Failed to write audit record: {}",
+                                e
+                            );
+                        }
+                        if let Some(url) = &config.notify_webhook_url {
+                            notify::spawn_webhook(
+                                &mut tracker.notifications,
+                                url.clone(),
+                                DeletionNotification::new(pvc, &reason, config),
+                            );
+                        }
+                        if config.delete_bound_pv {
+                            delete_reaped_pv(client, config, pvc, result).await;
+                        }
+                        if config.recreate_pvc {
+                            self.recreate(client, config, pvc, result).await;
+                        }
+                        if config.delete_stuck_pods {
+                            self.delete_stuck_pod(client, config, pvc, &reason, result)
+                                .await;
+                        }
+                        pass.summary.record_delete(&reason);
+                        result.record_deletion(pvc, reason, config);
+                    }
+                }
+            }
+            Err(skip) => {
+                record_decision("skip", skip.variant());
+                if let Some((cause, delete_at)) = self.pending_deletion(&skip, config) {
+                    pass.pending_candidates.extend(pvc.uid());
+                    if tracker.mark_warned(pvc) {
+                        let event = pending_event(&cause, delete_at);
+                        if let Err(e) = pass.recorder.publish(&event, &pvc.object_ref(&())).await {
+                            warn!(
+                                namespace = %namespace,
+                                pvc = %pvc_name,
+                                "Failed to record pending event for PVC: {:#}",
+                                e
+                            );
+                        }
+                    }
+                }
+
+                pass.summary.record_skip(&namespace, &pvc_name, &skip);
+                result.record_skip(&namespace, &pvc_name, &skip);
+                telemetry::pvc_skipped(&labels);
+            }
+        }
+    }
+
+    /// Run the enabled cleanup passes that follow the PVC deletions of a periodic cycle
+    async fn cleanup(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        recorder: &Recorder,
+        tracker: &mut ReapTracker,
+        result: &mut ReapResult,
+    ) {
+        if config.force_remove_finalizers_after_secs.is_some() {
+            self.force_remove_finalizers(client, config, recorder, result)
+                .await;
+        }
+        if config.cleanup_released_pvs {
+            self.cleanup_released_pvs(client, config, result).await;
+        }
+        if config.cleanup_volume_attachments {
+            self.cleanup_volume_attachments(client, config, result)
+                .await;
+        }
+        if config.cleanup_openebs_lvmvolumes && !tracker.lvmvolume_crd_missing {
+            self.cleanup_lvmvolumes(client, config, result).await;
+        }
+    }
+
+    /// Re-create a reaped `WaitForFirstConsumer` PVC without its stale selected node
+    async fn recreate(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        pvc: &PersistentVolumeClaim,
+        result: &mut ReapResult,
+    ) {
+        let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+        let storage_class = get_storage_class(pvc).or(self.default_storage_class.as_deref());
+        if !storage_class.is_some_and(|class| self.wait_for_first_consumer_classes.contains(class))
+        {
+            debug!(
+                namespace = %namespace,
+                pvc = %name,
+                "StorageClass doesn't wait for the first consumer, not re-creating PVC"
+            );
+            return;
+        }
+        if config.dry_run {
+            info!(namespace = %namespace, pvc = %name, dry_run = true, "[DRY RUN] Would re-create PVC");
+            return;
+        }
+
+        let timeout = Duration::from_secs(config.recreate_pvc_timeout_secs);
+        match recreate_pvc(client, pvc, &fresh_claim(pvc, config), timeout).await {
+            Ok(()) => {
+                info!(namespace = %namespace, pvc = %name, "Re-created PVC");
+                result.pvcs_recreated += 1;
+            }
+            Err(e) => {
+                error!(namespace = %namespace, pvc = %name, "Failed to re-create PVC: {:#}", e);
+                result.recreate_failures += 1;
+            }
+        }
+    }
+
+    /// Delete the unschedulable pod behind a reaped PVC so its controller recreates it and
+    /// re-provisions the claim; failures are logged and don't affect the PVC deletion
+    async fn delete_stuck_pod(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        result: &mut ReapResult,
+    ) {
+        let Some(pod) = self.stuck_pod(pvc, reason, config) else {
+            return;
+        };
+        let (namespace, name) = (pod.namespace().unwrap_or_default(), pod.name_any());
+        if config.dry_run {
+            info!(
+                namespace = %namespace,
+                pod = %name,
+                pvc = %pvc.name_any(),
+                dry_run = true,
+                "[DRY RUN] Would delete stuck pod"
+            );
+            result.stuck_pods_would_delete += 1;
+            return;
+        }
+
+        let api = Api::<Pod>::namespaced(client.clone(), &namespace);
+        match delete_object(api, "pods", &name).await {
+            Ok(DeleteOutcome::Deleted) => {
+                info!(
+                    namespace = %namespace,
+                    pod = %name,
+                    pvc = %pvc.name_any(),
+                    "Deleted stuck pod"
+                );
+                result.stuck_pods_deleted += 1;
+            }
+            Ok(DeleteOutcome::AlreadyGone | DeleteOutcome::Replaced) => {}
+            Err(e) => error!(
+                namespace = %namespace,
+                pod = %name,
+                "Failed to delete stuck pod: {:#}",
+                e
+            ),
+        }
+    }
+
+    /// The controller-owned pod named by a deletion reason; bare pods are never deleted since
+    /// nothing would recreate them
+    fn stuck_pod(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        config: &ReaperConfig,
+    ) -> Option<&Pod> {
+        let name = reason.pod()?;
+        self.pods
+            .iter()
+            .find(|pod| pod.name_any() == name && pod.namespace() == pvc.namespace())
+            .filter(|pod| pod_is_controller_owned(pod, config))
+    }
+
+    /// Drop the finalizers of the PVCs selected by [`State::stuck_terminating`]
+    async fn force_remove_finalizers(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        recorder: &Recorder,
+        result: &mut ReapResult,
+    ) {
+        for pvc in &self.pvcs {
+            let Some((node, terminating_secs)) = self.stuck_terminating(pvc, config) else {
+                continue;
+            };
+            let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+            let finalizers = pvc.finalizers().join(",");
+            if config.dry_run {
+                info!(
+                    namespace = %namespace,
+                    pvc = %name,
+                    finalizers,
+                    terminating_secs,
+                    dry_run = true,
+                    "[DRY RUN] Would remove finalizers from terminating PVC"
+                );
+                continue;
+            }
+
+            let event = finalizers_removed_event(&finalizers, &node, terminating_secs);
+            if let Err(e) = recorder.publish(&event, &pvc.object_ref(&())).await {
+                warn!(namespace = %namespace, pvc = %name, "Failed to record event for PVC: {:#}", e);
+            }
+            let api = Api::<PersistentVolumeClaim>::namespaced(client.clone(), &namespace);
+            match api
+                .patch(
+                    &name,
+                    &PatchParams::default(),
+                    &Patch::Merge(finalizer_patch(pvc)),
+                )
+                .await
+            {
+                Ok(_) => {
+                    warn!(
+                        namespace = %namespace,
+                        pvc = %name,
+                        finalizers,
+                        terminating_secs,
+                        "Removed finalizers from terminating PVC on missing node {}",
+                        node
+                    );
+                    result.finalizers_removed += 1;
+                }
+                Err(kube::Error::Api(e)) if e.code == 404 => {}
+                Err(e) => {
+                    telemetry::api_error("persistentvolumeclaims", "patch");
+                    error!(namespace = %namespace, pvc = %name, "Failed to remove finalizers: {:#}", e);
+                }
+            }
+        }
+    }
+
+    /// Remove `pvc-reaper.io/delete-at` from PVCs that were not candidates this cycle, e.g.
+    /// because they got protected or their pod recovered
+    async fn cancel_scheduled_deletions(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        scheduled: &HashSet<String>,
+    ) {
+        for pvc in &self.pvcs {
+            if get_pvc_annotation(pvc, DELETE_AT_ANNOTATION).is_none()
+                || pvc.metadata.deletion_timestamp.is_some()
+                || pvc.uid().is_some_and(|uid| scheduled.contains(&uid))
+            {
+                continue;
+            }
+            let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+            if config.dry_run {
+                info!(
+                    namespace = %namespace,
+                    pvc = %name,
+                    dry_run = true,
+                    "[DRY RUN] Would cancel scheduled deletion of PVC that no longer qualifies"
+                );
+                continue;
+            }
+
+            let patch = delete_at_patch(None);
+            match patch_pvc(client, &namespace, &name, patch).await {
+                Ok(()) => info!(
+                    namespace = %namespace,
+                    pvc = %name,
+                    "Cancelled scheduled deletion of PVC that no longer qualifies"
+                ),
+                Err(e) => error!(
+                    namespace = %namespace,
+                    pvc = %name,
+                    "Failed to cancel scheduled deletion: {:#}",
+                    e
+                ),
+            }
+        }
+    }
+
+    /// The missing node and terminating time of a matching, unprotected PVC with finalizers that
+    /// has been terminating longer than `--force-remove-finalizers-after-secs`
+    fn stuck_terminating(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<(String, i64)> {
+        let timeout_secs = config.force_remove_finalizers_after_secs?;
+        let since = pvc.metadata.deletion_timestamp.as_ref()?;
+        if pvc.finalizers().is_empty()
+            || protected_by(pvc, config).is_some()
+            || !self.matches_storage_criteria(pvc, config)
+        {
+            return None;
+        }
+
+        let terminating_secs = self.now.signed_duration_since(since.0).num_seconds();
+        if terminating_secs <= timeout_secs as i64 {
+            return None;
+        }
+        let node = self.missing_node(pvc, config)?;
+        Some((node, terminating_secs))
+    }
+
+    /// Delete the `Released` PVs selected by [`State::released_pv_age`]; failures are logged
+    /// and left for the next cycle
+    async fn cleanup_released_pvs(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        result: &mut ReapResult,
+    ) {
+        for pv in &self.pvs {
+            let Some(released_secs) = self.released_pv_age(pv, config) else {
+                continue;
+            };
+            let name = pv.name_any();
+            if config.dry_run {
+                info!(pv = %name, released_secs, dry_run = true, "[DRY RUN] Would delete released PV");
+                result.released_pvs_would_delete += 1;
+                continue;
+            }
+
+            let api = Api::<PersistentVolume>::all(client.clone());
+            match delete_object(api, "persistentvolumes", &name).await {
+                Ok(DeleteOutcome::Deleted) => {
+                    info!(pv = %name, released_secs, "Deleted released PV");
+                    result.released_pvs_deleted += 1;
+                }
+                Ok(DeleteOutcome::AlreadyGone | DeleteOutcome::Replaced) => {}
+                Err(e) => error!(pv = %name, "Failed to delete released PV: {:#}", e),
+            }
+        }
+
+        info!(
+            "Released PV cleanup complete: deleted={}, would_delete={}",
+            result.released_pvs_deleted, result.released_pvs_would_delete
+        );
+    }
+
+    /// How long `pv` has been `Released`, if it comes from a configured provisioner, its claim
+    /// is gone and it has been released for at least `--released-pv-min-age-secs`
+    fn released_pv_age(&self, pv: &PersistentVolume, config: &ReaperConfig) -> Option<i64> {
+        let status = pv.status.as_ref()?;
+        if status.phase.as_deref() != Some("Released") {
+            return None;
+        }
+
+        let spec = pv.spec.as_ref()?;
+        let driver = spec
+            .csi
+            .as_ref()
+            .map(|csi| csi.driver.as_str())
+            .or_else(|| {
+                pv.annotations()
+                    .get(PROVISIONED_BY_ANNOTATION)
+                    .map(String::as_str)
+            })?;
+        if !config.storage_provisioner.iter().any(|prov| prov == driver) {
+            return None;
+        }
+
+        // A claim with the same name but a different UID is a new claim; the PV stays released
+        let claim = spec.claim_ref.as_ref()?;
+        let claim_exists = self.pvcs.iter().any(|pvc| match &claim.uid {
+            Some(uid) => pvc.uid().as_ref() == Some(uid),
+            None => pvc.namespace() == claim.namespace && Some(pvc.name_any()) == claim.name,
+        });
+        if claim_exists {
+            return None;
+        }
+
+        let released_at = status
+            .last_phase_transition_time
+            .as_ref()
+            .or(pv.metadata.creation_timestamp.as_ref())?;
+        let released_secs = self.now.signed_duration_since(released_at.0).num_seconds();
+        (released_secs >= config.released_pv_min_age_secs as i64).then_some(released_secs)
+    }
+
+    /// Delete the VolumeAttachments selected by [`State::stale_attachment_age`]; failures are
+    /// logged and left for the next cycle
+    async fn cleanup_volume_attachments(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        result: &mut ReapResult,
+    ) {
+        for attachment in &self.volume_attachments {
+            let Some(age_secs) = self.stale_attachment_age(attachment, config) else {
+                continue;
+            };
+            let name = attachment.name_any();
+            let node = &attachment.spec.node_name;
+            if config.dry_run {
+                info!(
+                    volume_attachment = %name,
+                    node = %node,
+                    age_secs,
+                    dry_run = true,
+                    "[DRY RUN] Would delete VolumeAttachment on missing node"
+                );
+                result.volume_attachments_would_delete += 1;
+                continue;
+            }
+
+            let api = Api::<VolumeAttachment>::all(client.clone());
+            match delete_object(api, "volumeattachments", &name).await {
+                Ok(DeleteOutcome::Deleted) => {
+                    info!(volume_attachment = %name, node = %node, age_secs, "Deleted VolumeAttachment on missing node");
+                    result.volume_attachments_deleted += 1;
+                }
+                Ok(DeleteOutcome::AlreadyGone | DeleteOutcome::Replaced) => {}
+                Err(e) => {
+                    error!(volume_attachment = %name, "Failed to delete VolumeAttachment: {:#}", e)
+                }
+            }
+        }
+
+        info!(
+            "VolumeAttachment cleanup complete: deleted={}, would_delete={}",
+            result.volume_attachments_deleted, result.volume_attachments_would_delete
+        );
+    }
+
+    /// Age of a VolumeAttachment from a configured provisioner whose node has been missing for
+    /// `--node-missing-cycles`, if it is at least `--volume-attachment-min-age-secs` old
+    fn stale_attachment_age(
+        &self,
+        attachment: &VolumeAttachment,
+        config: &ReaperConfig,
+    ) -> Option<i64> {
+        let spec = &attachment.spec;
+        if !config.storage_provisioner.contains(&spec.attacher)
+            || !self.nodes_listed
+            || self.node_names.contains(&spec.node_name)
+            || self.node_missing_cycles(&spec.node_name) < config.node_missing_cycles
+        {
+            return None;
+        }
+
+        let created = attachment.metadata.creation_timestamp.as_ref()?;
+        let age_secs = self.now.signed_duration_since(created.0).num_seconds();
+        (age_secs >= config.volume_attachment_min_age_secs as i64).then_some(age_secs)
+    }
+
+    /// Delete the OpenEBS LVMVolumes selected by [`State::lvmvolume_missing_owner`]; failures
+    /// are logged and left for the next cycle
+    async fn cleanup_lvmvolumes(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        result: &mut ReapResult,
+    ) {
+        let resource = lvmvolume_resource();
+        for volume in &self.lvmvolumes {
+            let Some(node) = self.lvmvolume_missing_owner(volume, config) else {
+                continue;
+            };
+            let (namespace, name) = (volume.namespace().unwrap_or_default(), volume.name_any());
+            if config.dry_run {
+                info!(
+                    namespace = %namespace,
+                    lvmvolume = %name,
+                    node = %node,
+                    dry_run = true,
+                    "[DRY RUN] Would delete LVMVolume on missing node"
+                );
+                result.lvmvolumes_would_delete += 1;
+                continue;
+            }
+
+            match Api::<DynamicObject>::namespaced_with(client.clone(), &namespace, &resource)
+                .delete(&name, &DeleteParams::default())
+                .await
+            {
+                Ok(_) => {
+                    info!(namespace = %namespace, lvmvolume = %name, node = %node, "Deleted LVMVolume on missing node");
+                    result.lvmvolumes_deleted += 1;
+                }
+                Err(kube::Error::Api(e)) if e.code == 404 => {}
+                Err(e) => {
+                    telemetry::api_error("lvmvolumes", "delete");
+                    error!(namespace = %namespace, lvmvolume = %name, "Failed to delete LVMVolume: {:#}", e);
+                }
+            }
+        }
+
+        info!(
+            "LVMVolume cleanup complete: deleted={}, would_delete={}",
+            result.lvmvolumes_deleted, result.lvmvolumes_would_delete
+        );
+    }
+
+    /// The `spec.ownerNodeID` of an LVMVolume that isn't already being deleted, once that node
+    /// has been missing for `--node-missing-cycles`
+    fn lvmvolume_missing_owner(
+        &self,
+        volume: &DynamicObject,
+        config: &ReaperConfig,
+    ) -> Option<String> {
+        if !self.nodes_listed || volume.metadata.deletion_timestamp.is_some() {
+            return None;
+        }
+        let node = lvmvolume_owner(volume)?;
+        (!self.node_names.contains(node)
+            && self.node_missing_cycles(node) >= config.node_missing_cycles)
+            .then(|| node.to_string())
+    }
+
+    /// Decision for a PVC left behind by a scaled-down StatefulSet, or `None` if the PVC isn't
+    /// one and should go through the regular consumer-pod checks
+    fn scaled_down_decision(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+        tracker: &mut ReapTracker,
+        excess_ordinals: &mut HashSet<String>,
+    ) -> Option<Result<DeleteReason, SkipReason>> {
+        if !config.reap_scaled_down_statefulsets {
+            return None;
+        }
+
+        let (sts, ordinal) = self.excess_ordinal(pvc)?;
+        if self.pods.iter().any(|pod| pod_uses_pvc(pod, pvc)) {
+            return None;
+        }
+
+        let uid = pvc.uid()?;
+        let since = tracker.excess_since(&uid, self.now);
+        excess_ordinals.insert(uid);
+
+        let remaining_secs = config.scaled_down_grace_secs as i64
+            - self.now.signed_duration_since(since).num_seconds();
+        if remaining_secs > 0 {
+            return Some(Err(SkipReason::ScaleDownGrace {
+                sts,
+                ordinal,
+                remaining_secs,
+            }));
+        }
+
+        Some(Ok(DeleteReason::ScaledDownStatefulSet { sts, ordinal }))
+    }
+
+    /// The StatefulSet and ordinal a PVC was created for through `volumeClaimTemplates`
+    /// (`<template>-<sts>-<ordinal>`), if that ordinal is beyond the StatefulSet's replicas
+    fn excess_ordinal(&self, pvc: &PersistentVolumeClaim) -> Option<(String, u32)> {
+        let namespace = pvc.namespace();
+        let pvc_name = pvc.name_any();
+
+        self.statefulsets
+            .iter()
+            .filter(|sts| sts.namespace() == namespace)
+            .find_map(|sts| {
+                let sts_name = sts.name_any();
+                let spec = sts.spec.as_ref()?;
+                let start = spec
+                    .ordinals
+                    .as_ref()
+                    .and_then(|ordinals| ordinals.start)
+                    .unwrap_or(0);
+                let end = i64::from(start) + i64::from(spec.replicas.unwrap_or(1));
+
+                spec.volume_claim_templates
+                    .iter()
+                    .flatten()
+                    .find_map(|template| {
+                        let prefix = format!("{}-{}-", template.name_any(), sts_name);
+                        let ordinal: u32 = pvc_name.strip_prefix(&prefix)?.parse().ok()?;
+                        (i64::from(ordinal) >= end).then(|| (sts_name.clone(), ordinal))
+                    })
+            })
+    }
+
+    /// Decision for a PVC whose StatefulSet was deleted, or `None` if the PVC isn't one and
+    /// should go through the regular consumer-pod checks
+    fn orphaned_workload_decision(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<Result<DeleteReason, SkipReason>> {
+        if !config.reap_orphaned_workload_pvcs || self.pods.iter().any(|pod| pod_uses_pvc(pod, pvc))
+        {
+            return None;
+        }
+
+        let owner = self.missing_workload(pvc)?;
+        let remaining_secs = config.orphaned_workload_min_age_secs as i64 - self.pvc_age_secs(pvc);
+        if remaining_secs > 0 {
+            return Some(Err(SkipReason::OrphanTooYoung {
+                owner,
+                remaining_secs,
+            }));
+        }
+
+        Some(Ok(DeleteReason::WorkloadDeleted { owner }))
+    }
+
+    /// Decision for a PVC on a missing node that no pod references, or `None` if the PVC isn't
+    /// one and should go through the regular consumer-pod checks
+    fn unreferenced_missing_node_decision(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<Result<DeleteReason, SkipReason>> {
+        if !config.reap_unreferenced_missing_node_pvcs
+            || self.pods.iter().any(|pod| pod_uses_pvc(pod, pvc))
+        {
+            return None;
+        }
+
+        let node = self.missing_node(pvc, config)?;
+        let remaining_secs =
+            config.unreferenced_missing_node_min_age_secs as i64 - self.pvc_age_secs(pvc);
+        if remaining_secs > 0 {
+            return Some(Err(SkipReason::UnreferencedTooYoung {
+                node,
+                remaining_secs,
+            }));
+        }
+
+        Some(Ok(DeleteReason::MissingNodeUnreferenced { node }))
+    }
+
+    /// Decision for a PVC whose consumers have all finished, or `None` if any consumer is still
+    /// pending or running (or its completion time is unknown) and the regular checks apply
+    fn finished_consumers_decision(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<Result<DeleteReason, SkipReason>> {
+        if !config.reap_finished_consumer_pvcs {
+            return None;
+        }
+
+        let mut last: Option<(&Pod, DateTime<Utc>)> = None;
+        for pod in self.pods.iter().filter(|pod| pod_uses_pvc(pod, pvc)) {
+            if !matches!(pod_phase(pod), Some("Succeeded" | "Failed")) {
+                return None;
+            }
+            let finished_at = pod_finished_at(pod)?;
+            if last.is_none_or(|(_, latest)| finished_at > latest) {
+                last = Some((pod, finished_at));
+            }
+        }
+
+        let (pod, finished_at) = last?;
+        let pod = pod.name_any();
+        let finished_secs = self.now.signed_duration_since(finished_at).num_seconds();
+        let remaining_secs = config.finished_pod_threshold_secs as i64 - finished_secs;
+        if remaining_secs > 0 {
+            return Some(Err(SkipReason::FinishedTooRecently {
+                pod,
+                remaining_secs,
+            }));
+        }
+
+        Some(Ok(DeleteReason::ConsumersFinished { pod, finished_secs }))
+    }
+
+    fn pvc_age_secs(&self, pvc: &PersistentVolumeClaim) -> i64 {
+        pvc.creation_timestamp()
+            .map(|created| self.now.signed_duration_since(created.0).num_seconds())
+            .unwrap_or_default()
+    }
+
+    /// The deleted StatefulSet a PVC was created for. A StatefulSet ownerReference is
+    /// authoritative; otherwise a `<template>-<sts>-<ordinal>` name that no listed StatefulSet's
+    /// `volumeClaimTemplates` account for counts as orphaned.
+    fn missing_workload(&self, pvc: &PersistentVolumeClaim) -> Option<String> {
+        let namespace = pvc.namespace();
+        let statefulsets = || {
+            self.statefulsets
+                .iter()
+                .filter(|sts| sts.namespace() == namespace)
+        };
+
+        let owners: Vec<&str> = pvc
+            .owner_references()
+            .iter()
+            .filter(|owner| owner.kind == "StatefulSet")
+            .map(|owner| owner.name.as_str())
+            .collect();
+        if !owners.is_empty() {
+            return owners
+                .into_iter()
+                .find(|owner| !statefulsets().any(|sts| sts.name_any() == *owner))
+                .map(|owner| format!("StatefulSet/{}", owner));
+        }
+
+        let pvc_name = pvc.name_any();
+        let (prefix, ordinal) = pvc_name.rsplit_once('-')?;
+        if ordinal.parse::<u32>().is_err() || !prefix.contains('-') {
+            return None;
+        }
+        let claimed = statefulsets().any(|sts| {
+            let sts_name = sts.name_any();
+            sts.spec
+                .iter()
+                .flat_map(|spec| spec.volume_claim_templates.iter().flatten())
+                .any(|template| prefix == format!("{}-{}", template.name_any(), sts_name))
+        });
+
+        (!claimed).then(|| format!("StatefulSet of '{}-<ordinal>'", prefix))
+    }
+
+    fn deletion_reason(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Result<DeleteReason, SkipReason> {
+        if let Some(pod) = self.pod_mounting(pvc) {
+            info!(
+                "PVC {} is mounted by running pod {}, never deleting it",
+                pvc.name_any(),
+                pod.name_any()
+            );
+            return Err(SkipReason::InUse {
+                pod: pod.name_any(),
+            });
+        }
+
+        let unschedulable_pod = self.unschedulable_pod(pvc, config)?;
+        let pod_name = unschedulable_pod.name_any();
+
+        if let Some(node) = self.pinned_node(pvc, config) {
+            if !pod_targets_node(unschedulable_pod, node) {
+                info!(
+                    "Pod {} cannot target selected node {}, unschedulable for an unrelated reason",
+                    pod_name, node
+                );
+                return Err(SkipReason::PodTargetsOtherNode {
+                    pod: pod_name,
+                    node: node.to_string(),
+                });
+            }
+            // Being allowed onto the node doesn't make it the blocker: a pod short of CPU or
+            // memory would stay pending with a fresh claim too
+            if let Some(skip) = unrelated_scheduling_failure(unschedulable_pod, config) {
+                info!(
+                    "Pod {} is unschedulable for a reason unrelated to its volumes: {}",
+                    pod_name,
+                    scheduling_message(unschedulable_pod).unwrap_or_default()
+                );
+                return Err(skip);
+            }
+        }
+
+        if let Some((node, since)) = self.node_not_ready(pvc, config) {
+            return Ok(DeleteReason::NodeNotReady {
+                node,
+                pod: pod_name,
+                since,
+            });
+        }
+
+        if let Some((node, cordoned_secs)) = self.node_cordoned(pvc, config) {
+            return Ok(DeleteReason::NodeCordoned {
+                node,
+                pod: pod_name,
+                cordoned_secs,
+            });
+        }
+
+        if let Some((node, taint)) = self.node_tainted(pvc, config) {
+            return Ok(DeleteReason::NodeTainted {
+                node,
+                pod: pod_name,
+                taint,
+            });
+        }
+
+        if let Some((node, cycles)) = self.node_recently_missing(pvc, config) {
+            info!(
+                "Node {} has been missing for {}/{} cycles, waiting before treating it as gone",
+                node, cycles, config.node_missing_cycles
+            );
+            return Err(SkipReason::NodeRecentlyMissing {
+                node,
+                cycles,
+                required: config.node_missing_cycles,
+            });
+        }
+
+        let missing_node = self.missing_node(pvc, config);
+        if config.require_both_conditions {
+            if missing_node.is_none() {
+                return Err(SkipReason::NodeExists {
+                    node: self.pinned_node(pvc, config).map(str::to_string),
+                });
+            }
+        } else if let Some(node) = missing_node {
+            return Ok(DeleteReason::MissingNode {
+                missing_secs: self.node_missing_secs(&node),
+                node,
+                pod: pod_name,
+            });
+        }
+
+        if config.check_unschedulable_pods || config.require_both_conditions {
+            let message = scheduling_message(unschedulable_pod).unwrap_or_default();
+            let mut matched = None;
+            if missing_node.is_some() {
+                // The scheduling message was already checked against the missing node
+            } else if let Some(regex) = config
+                .pod_message_regex
+                .as_ref()
+                .filter(|regex| !regex.as_str().is_empty())
+            {
+                let Some(found) = regex.find(message) else {
+                    info!(
+                        "Pod {} scheduling message doesn't match --pod-message-regex: {}",
+                        pod_name, message
+                    );
+                    return Err(SkipReason::MessageNotMatched {
+                        pod: pod_name,
+                        message: message.to_string(),
+                    });
+                };
+                matched = Some(found.as_str().to_string());
+            } else if !is_volume_failure(unschedulable_pod, config) {
+                info!(
+                    "Pod {} is unschedulable for a reason unrelated to its volumes: {}",
+                    pod_name, message
+                );
+                return Err(SkipReason::NotVolumeRelated {
+                    pod: pod_name,
+                    message: message.to_string(),
+                });
+            }
+
+            let threshold_secs = config.unschedulable_threshold_secs(get_storage_class(pvc));
+            let threshold = Duration::from_secs(threshold_secs);
+            if pod_exceeds_unschedulable_thresh(unschedulable_pod, threshold, self.now) {
+                return Ok(match missing_node {
+                    Some(node) => DeleteReason::MissingNodeAndUnschedulable {
+                        node,
+                        pod: pod_name,
+                        pending_secs: pod_unschedulable_secs(unschedulable_pod, self.now)
+                            .unwrap_or_default(),
+                    },
+                    None => DeleteReason::UnschedulableTooLong {
+                        pod: pod_name,
+                        matched,
+                    },
+                });
+            }
+
+            return Err(SkipReason::BelowThreshold {
+                pod: pod_name,
+                pending_secs: pod_unschedulable_secs(unschedulable_pod, self.now)
+                    .unwrap_or_default(),
+                threshold_secs,
+            });
+        }
+
+        Err(SkipReason::NodeExists {
+            node: self.pinned_node(pvc, config).map(str::to_string),
+        })
+    }
+
+    /// Any pod in the PVC's namespace that is Running, or Pending but already bound to a node,
+    /// with the claim mounted
+    fn pod_mounting(&self, pvc: &PersistentVolumeClaim) -> Option<&Pod> {
+        self.pods.iter().find(|pod| {
+            pod_uses_pvc(pod, pvc)
+                && (pod_phase(pod) == Some("Running")
+                    || (pod_is_pending(pod) && pod_is_scheduled(pod)))
+        })
+    }
+
+    /// The pending, unschedulable pod among all of the PVC's consumers. Running consumers are
+    /// ruled out beforehand by [`State::pod_mounting`].
+    fn unschedulable_pod<'a>(
+        &'a self,
+        pvc: &'a PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Result<&'a Pod, SkipReason> {
+        let consumers: Vec<&Pod> = self.pods.iter().filter(|p| pod_uses_pvc(p, pvc)).collect();
+        let first = consumers.first().ok_or(SkipReason::NoConsumingPod)?;
+
+        let Some(pod) = consumers
+            .iter()
+            .find(|p| pod_is_pending(p) && pod_is_unschedulable(p))
+        else {
+            if let Some(pending) = consumers.iter().find(|p| pod_is_pending(p)) {
+                info!(
+                    "Pod {} is pending but not unschedulable",
+                    pending.name_any()
+                );
+                return Err(SkipReason::PodNotUnschedulable {
+                    pod: pending.name_any(),
+                });
+            }
+
+            return Err(SkipReason::PodNotPending {
+                pod: first.name_any(),
+                phase: pod_phase(first).unwrap_or("Unknown").to_string(),
+            });
+        };
+
+        info!("Pod {} is unschedulable", pod.name_any());
+
+        if config.controller_owned_pods_only && !pod_is_controller_owned(pod, config) {
+            info!(
+                "Pod {} is not managed by a controller, its PVC would not be recreated",
+                pod.name_any()
+            );
+            return Err(SkipReason::UnmanagedPod {
+                pod: pod.name_any(),
+            });
+        }
+
+        Ok(pod)
+    }
+
+    /// The node a PVC is pinned to: its selected-node annotation, or for bound PVCs without one,
+    /// the single hostname in the bound PV's required nodeAffinity
+    fn pinned_node<'a>(
+        &'a self,
+        pvc: &'a PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<&'a str> {
+        get_selected_node(pvc, config).or_else(|| {
+            let volume = pvc.spec.as_ref()?.volume_name.as_deref()?;
+            let pv = self.pvs.iter().find(|pv| pv.name_any() == volume)?;
+            pv_pinned_hostname(pv)
+        })
+    }
+
+    /// The selected node, if it is absent from the node list
+    fn absent_node<'a>(
+        &'a self,
+        pvc: &'a PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<&'a str> {
+        let node = self.pinned_node(pvc, config)?;
+        (self.nodes_listed && !self.node_names.contains(node)).then_some(node)
+    }
+
+    /// Every node that PVCs, VolumeAttachments from a configured provisioner or LVMVolumes
+    /// reference but that is absent from the node list
+    fn absent_nodes(&self, config: &ReaperConfig) -> HashSet<String> {
+        let attachment_nodes = self
+            .volume_attachments
+            .iter()
+            .filter(|attachment| {
+                config
+                    .storage_provisioner
+                    .contains(&attachment.spec.attacher)
+            })
+            .map(|attachment| attachment.spec.node_name.as_str());
+        self.pvcs
+            .iter()
+            .filter_map(|pvc| self.pinned_node(pvc, config))
+            .chain(attachment_nodes)
+            .chain(self.lvmvolumes.iter().filter_map(lvmvolume_owner))
+            .filter(|node| !self.node_names.contains(*node))
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Count another cycle for each absent node on a full cycle. A targeted run reuses the
+    /// counts so far, since watch events firing between cycles would otherwise rush a node
+    /// through `--node-missing-cycles`.
+    fn track_missing_nodes(&mut self, config: &ReaperConfig, tracker: &mut ReapTracker) {
+        if !self.nodes_listed {
+            return;
+        }
+        self.missing_nodes = if self.target.is_none() {
+            tracker.observe_missing_nodes(self.absent_nodes(config), self.now)
+        } else {
+            tracker.missing_nodes.clone()
+        };
+    }
+
+    /// The selected node, once it has been absent for `--node-missing-cycles` consecutive cycles
+    fn missing_node(&self, pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> Option<String> {
+        let node = self.absent_node(pvc, config)?;
+        (self.node_missing_cycles(node) >= config.node_missing_cycles).then(|| node.to_string())
+    }
+
+    /// The selected node and the cycles it has been absent for, while that is still short of
+    /// `--node-missing-cycles`
+    fn node_recently_missing(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<(String, u32)> {
+        let node = self.absent_node(pvc, config)?;
+        let cycles = self.node_missing_cycles(node);
+        (cycles < config.node_missing_cycles).then(|| (node.to_string(), cycles))
+    }
+
+    /// Consecutive cycles a node has been absent, counting this one
+    fn node_missing_cycles(&self, node: &str) -> u32 {
+        self.missing_nodes
+            .get(node)
+            .map_or(1, |(cycles, _)| *cycles)
+    }
+
+    /// How long ago a node was first seen absent, 0 on its first cycle
+    fn node_missing_secs(&self, node: &str) -> i64 {
+        self.missing_nodes.get(node).map_or(0, |(_, since)| {
+            self.now.signed_duration_since(*since).num_seconds()
+        })
+    }
+
+    /// The selected node and when it stopped being `Ready`, once that is longer ago than
+    /// `--node-not-ready-threshold-secs`
+    fn node_not_ready(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<(String, DateTime<Utc>)> {
+        let threshold_secs = config.node_not_ready_threshold_secs?;
+        let name = self.pinned_node(pvc, config)?;
+        let node = self.nodes.iter().find(|node| node.name_any() == name)?;
+        let since = node_not_ready_since(node)?;
+        let not_ready_secs = self.now.signed_duration_since(since).num_seconds();
+        (not_ready_secs > threshold_secs as i64).then(|| (name.to_string(), since))
+    }
+
+    /// The selected node and how long it has been cordoned, once that exceeds
+    /// `--cordoned-threshold-secs`
+    fn node_cordoned(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<(String, i64)> {
+        if !config.reap_on_cordoned_nodes {
+            return None;
+        }
+        let node = self.pinned_node(pvc, config)?;
+        let since = self.cordoned_since.get(node)?;
+        let cordoned_secs = self.now.signed_duration_since(*since).num_seconds();
+        (cordoned_secs > config.cordoned_threshold_secs as i64)
+            .then(|| (node.to_string(), cordoned_secs))
+    }
+
+    /// The selected node and the first of its taints matching `--reap-node-taints`
+    fn node_tainted(
+        &self,
+        pvc: &PersistentVolumeClaim,
+        config: &ReaperConfig,
+    ) -> Option<(String, String)> {
+        if config.reap_node_taints.is_empty() {
+            return None;
+        }
+        let name = self.pinned_node(pvc, config)?;
+        let node = self.nodes.iter().find(|node| node.name_any() == name)?;
+        let taints = node.spec.as_ref()?.taints.as_ref()?;
+        config.reap_node_taints.iter().find_map(|matcher| {
+            taints
+                .iter()
+                .any(|taint| matcher.matches(taint))
+                .then(|| (name.to_string(), matcher.to_string()))
+        })
+    }
+
+    async fn perform_delete(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        recorder: &Recorder,
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        labels: &telemetry::PvcLabels,
+    ) -> Result<DeleteOutcome> {
+        let namespace = pvc.namespace().unwrap_or_default();
+        let name = pvc.name_any();
+
+        let size = requested_storage(pvc).unwrap_or("unknown");
+        let provisioner_source = get_pvc_provisioner(pvc)
+            .map(|(key, _)| key)
+            .unwrap_or("storageclass");
+        if config.dry_run {
+            if config.dry_run_mode == DryRunMode::Server {
+                let outcome = delete_pvc(client, &namespace, &name, pvc.uid(), true)
+                    .await
+                    .context("API server rejected the dry-run delete")?;
+                if outcome != DeleteOutcome::Deleted {
+                    return Ok(outcome);
+                }
+            }
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                reason = %reason.describe(),
+                size,
+                provisioner_source,
+                dry_run = true,
+                "[DRY RUN] Would delete PVC"
+            );
+        } else {
+            backup::back_up(client, config, pvc).await?;
+            match delete_pvc(client, &namespace, &name, pvc.uid(), false).await? {
+                DeleteOutcome::Deleted => {}
+                DeleteOutcome::AlreadyGone => {
+                    info!(
+                        namespace = %namespace,
+                        pvc = %name,
+                        "PVC was already deleted by someone else"
+                    );
+                    return Ok(DeleteOutcome::AlreadyGone);
+                }
+                DeleteOutcome::Replaced => {
+                    info!(
+                        namespace = %namespace,
+                        pvc = %name,
+                        "PVC was re-created since it was evaluated, leaving the new claim alone"
+                    );
+                    return Ok(DeleteOutcome::Replaced);
+                }
+            }
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                reason = %reason.describe(),
+                size,
+                provisioner_source,
+                dry_run = false,
+                "Deleted PVC"
+            );
+        }
+
+        // Only once the delete went through, so a claim that was left alone gets no event
+        let event = deletion_event(pvc, reason, config);
+        if let Err(e) = recorder.publish(&event, &pvc.object_ref(&())).await {
+            warn!(
+                namespace = %namespace,
+                pvc = %name,
+                "Failed to record event for PVC: {:#}",
+                e
+            );
+        }
+        telemetry::pvc_deleted(labels, config.dry_run);
+        telemetry::reclaimed_bytes(requested_storage_bytes(pvc), config.dry_run);
+
+        Ok(DeleteOutcome::Deleted)
+    }
+
+    /// What a candidate held back by a grace period or `--confirmation-cycles` is waiting on,
+    /// and when it will be deleted if it still qualifies. Soft-delete waits are announced when
+    /// they are scheduled instead.
+    fn pending_deletion(
+        &self,
+        skip: &SkipReason,
+        config: &ReaperConfig,
+    ) -> Option<(String, DateTime<Utc>)> {
+        match skip {
+            SkipReason::BelowThreshold {
+                pod,
+                pending_secs,
+                threshold_secs,
+            } => Some((
+                format!("pod '{}' is unschedulable", pod),
+                self.now + chrono::Duration::seconds(*threshold_secs as i64 - *pending_secs),
+            )),
+            SkipReason::AwaitingConfirmation {
+                reason,
+                seen,
+                required,
+            } => Some((
+                reason.describe(),
+                self.now
+                    + chrono::Duration::seconds(
+                        i64::from(required.saturating_sub(*seen))
+                            * config.reap_interval_secs as i64,
+                    ),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Skip reason for an `UnschedulableTooLong` candidate whose pod has no `FailedScheduling`
+    /// Event within `--scheduling-event-max-age-secs`. Events that can't be listed count as
+    /// stale.
+    async fn stale_scheduling_event(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        namespace: &str,
+        pod: &str,
+        candidate: &DeleteReason,
+    ) -> Option<SkipReason> {
+        let last_seen = last_failed_scheduling(client, namespace, pod)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    namespace = %namespace,
+                    pod = %pod,
+                    "Failed to list the pod's Events, treating its scheduling state as stale: {:#}",
+                    e
+                );
+                None
+            });
+        let max_age = chrono::Duration::seconds(config.scheduling_event_max_age_secs as i64);
+        if last_seen.is_some_and(|at| self.now - at <= max_age) {
+            return None;
+        }
+        info!(
+            namespace = %namespace,
+            pod = %pod,
+            "Pod has no FailedScheduling event in the last {}s, the scheduler may have stopped trying",
+            config.scheduling_event_max_age_secs
+        );
+        Some(SkipReason::StaleSchedulingEvent {
+            reason: candidate.clone(),
+            last_seen,
+        })
+    }
+
+    /// Fetch the PVC again right before deleting it, since the list it was evaluated from can be
+    /// tens of seconds old on a big cluster. A dry run skips the fetch.
+    async fn revalidate(
+        &self,
+        client: &Client,
+        config: &ReaperConfig,
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+    ) -> Result<Revalidation> {
+        if config.dry_run {
+            return Ok(Revalidation::Current);
+        }
+        let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+        let fresh = Api::<PersistentVolumeClaim>::namespaced(client.clone(), &namespace)
+            .get_opt(&name)
+            .await
+            .inspect_err(|_| telemetry::api_error("persistentvolumeclaims", "get"))
+            .context("Failed to re-fetch PVC before deleting it")?;
+        let Some(fresh) = fresh.filter(|fresh| fresh.metadata.deletion_timestamp.is_none()) else {
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                "PVC was already deleted by someone else"
+            );
+            return Ok(Revalidation::Gone);
+        };
+        if fresh.uid() != pvc.uid() {
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                "PVC was re-created since it was evaluated, leaving the new claim alone"
+            );
+            return Ok(Revalidation::Replaced);
+        }
+        if let Some(change) = self.changed_since_evaluation(pvc, &fresh, reason, config) {
+            info!(
+                namespace = %namespace,
+                pvc = %name,
+                "PVC changed since it was evaluated, not deleting it: {}",
+                change
+            );
+            return Ok(Revalidation::Changed);
+        }
+        Ok(Revalidation::Current)
+    }
+
+    /// What about a freshly fetched PVC no longer qualifies it for the deletion `reason` decided
+    /// from `evaluated`, the copy it was listed as
+    fn changed_since_evaluation(
+        &self,
+        evaluated: &PersistentVolumeClaim,
+        fresh: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        config: &ReaperConfig,
+    ) -> Option<String> {
+        if let Some(annotation) = protected_by(fresh, config) {
+            return Some(format!("now protected by annotation {}", annotation));
+        }
+        if let Some(skip) = self.kept_until(fresh) {
+            return Some(skip.describe());
+        }
+        if config.require_opt_in
+            && !get_pvc_annotation(fresh, &config.opt_in_annotation).is_some_and(is_truthy)
+        {
+            return Some(format!(
+                "no longer opted in by annotation {}",
+                config.opt_in_annotation
+            ));
+        }
+        if !matches_annotation_selector(fresh, config) {
+            return Some("no longer matches --pvc-annotation-selector".to_string());
+        }
+        if matches!(reason, DeleteReason::OperatorRequested { .. })
+            && !get_pvc_annotation(fresh, REAP_NOW_ANNOTATION).is_some_and(is_truthy)
+        {
+            return Some(format!("{} was removed", REAP_NOW_ANNOTATION));
+        }
+        if !self.matches_storage_criteria(fresh, config) {
+            return Some("no longer matches the storage criteria".to_string());
+        }
+        if config.soft_delete_delay_secs.is_some()
+            && scheduled_delete_at(fresh).is_none_or(|delete_at| delete_at > self.now)
+        {
+            return Some(format!("{} was removed or postponed", DELETE_AT_ANNOTATION));
+        }
+        let (before, after) = (
+            get_selected_node(evaluated, config),
+            get_selected_node(fresh, config),
+        );
+        (before != after).then(|| {
+            format!(
+                "selected node changed from {} to {}",
+                before.unwrap_or("none"),
+                after.unwrap_or("none")
+            )
+        })
+    }
+}
+
+/// Why a PVC was selected for deletion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum DeleteReason {
+    /// The PVC's selected node has been missing for `missing_secs` and `pod` is stuck waiting
+    /// for it
+    MissingNode {
+        node: String,
+        pod: String,
+        missing_secs: i64,
+    },
+    /// Both of the above, required by `--require-both-conditions`
+    MissingNodeAndUnschedulable {
+        node: String,
+        pod: String,
+        pending_secs: i64,
+    },
+    /// The PVC's selected node no longer exists and no pod references the PVC at all
+    MissingNodeUnreferenced { node: String },
+    /// The PVC's selected node has not been `Ready` since `since`, longer than
+    /// `--node-not-ready-threshold-secs`, and `pod` is stuck waiting for it
+    NodeNotReady {
+        node: String,
+        pod: String,
+        since: DateTime<Utc>,
+    },
+    /// The PVC's selected node has been cordoned for `cordoned_secs`, longer than
+    /// `--cordoned-threshold-secs`, and `pod` is stuck waiting for it
+    NodeCordoned {
+        node: String,
+        pod: String,
+        cordoned_secs: i64,
+    },
+    /// The PVC's selected node carries `taint`, one of `--reap-node-taints`, and `pod` is stuck
+    /// waiting for it
+    NodeTainted {
+        node: String,
+        pod: String,
+        taint: String,
+    },
+    /// `pod` has been unschedulable for longer than the configured threshold. `matched` is the
+    /// part of its scheduling message matched by `--pod-message-regex`, if set.
+    UnschedulableTooLong {
+        pod: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        matched: Option<String>,
+    },
+    /// The PVC belongs to StatefulSet ordinal `ordinal`, which is beyond the StatefulSet's
+    /// current replica count
+    ScaledDownStatefulSet { sts: String, ordinal: u32 },
+    /// No pod references the PVC and `owner`, the workload that created it, no longer exists
+    WorkloadDeleted { owner: String },
+    /// Every pod using the PVC has `Succeeded` or `Failed`; `pod`, the last to finish, did so
+    /// `finished_secs` ago, longer than `--finished-pod-threshold-secs`
+    ConsumersFinished { pod: String, finished_secs: i64 },
+    /// The PVC carries `pvc-reaper.io/reap-now`; `requested_by` is the field manager that set it,
+    /// if known
+    OperatorRequested {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        requested_by: Option<String>,
+    },
+}
+
+impl DeleteReason {
+    /// Whether the reason rests on the PVC's selected node being absent from the node list
+    pub fn is_missing_node(&self) -> bool {
+        matches!(
+            self,
+            Self::MissingNode { .. }
+                | Self::MissingNodeAndUnschedulable { .. }
+                | Self::MissingNodeUnreferenced { .. }
+        )
+    }
+
+    /// Variant name, e.g. `MissingNode`
+    pub fn variant(&self) -> &'static str {
+        match self {
+            Self::MissingNode { .. } => "MissingNode",
+            Self::MissingNodeAndUnschedulable { .. } => "MissingNodeAndUnschedulable",
+            Self::MissingNodeUnreferenced { .. } => "MissingNodeUnreferenced",
+            Self::NodeNotReady { .. } => "NodeNotReady",
+            Self::NodeCordoned { .. } => "NodeCordoned",
+            Self::NodeTainted { .. } => "NodeTainted",
+            Self::UnschedulableTooLong { .. } => "UnschedulableTooLong",
+            Self::ScaledDownStatefulSet { .. } => "ScaledDownStatefulSet",
+            Self::WorkloadDeleted { .. } => "WorkloadDeleted",
+            Self::ConsumersFinished { .. } => "ConsumersFinished",
+            Self::OperatorRequested { .. } => "OperatorRequested",
+        }
+    }
+
+    /// Name of the pod whose state led to the deletion, if a pod was involved
+    pub fn pod(&self) -> Option<&str> {
+        match self {
+            Self::MissingNode { pod, .. }
+            | Self::MissingNodeAndUnschedulable { pod, .. }
+            | Self::NodeNotReady { pod, .. }
+            | Self::NodeCordoned { pod, .. }
+            | Self::NodeTainted { pod, .. }
+            | Self::UnschedulableTooLong { pod, .. }
+            | Self::ConsumersFinished { pod, .. } => Some(pod),
+            Self::MissingNodeUnreferenced { .. }
+            | Self::ScaledDownStatefulSet { .. }
+            | Self::WorkloadDeleted { .. }
+            | Self::OperatorRequested { .. } => None,
+        }
+    }
+
+    /// Human-readable explanation used in logs and Events
+    pub fn describe(&self) -> String {
+        match self {
+            Self::MissingNode {
+                node,
+                pod,
+                missing_secs,
+            } => format!(
+                "pod '{}' references node '{}', missing for {}s",
+                pod, node, missing_secs
+            ),
+            Self::MissingNodeAndUnschedulable {
+                node,
+                pod,
+                pending_secs,
+            } => format!(
+                "pod '{}' references missing node '{}' and has been unschedulable for {}s",
+                pod, node, pending_secs
+            ),
+            Self::MissingNodeUnreferenced { node } => {
+                format!("no pod uses it and its node '{}' is missing", node)
+            }
+            Self::NodeNotReady { node, pod, since } => format!(
+                "pod '{}' references node '{}', which has not been Ready since {}",
+                pod,
+                node,
+                since.to_rfc3339()
+            ),
+            Self::NodeCordoned {
+                node,
+                pod,
+                cordoned_secs,
+            } => format!(
+                "pod '{}' references node '{}', which has been cordoned for {}s",
+                pod, node, cordoned_secs
+            ),
+            Self::NodeTainted { node, pod, taint } => format!(
+                "pod '{}' references node '{}', which is tainted with {}",
+                pod, node, taint
+            ),
+            Self::UnschedulableTooLong { pod, matched: None } => {
+                format!(
+                    "pod '{}' has been pending past the configured threshold",
+                    pod
+                )
+            }
+            Self::UnschedulableTooLong {
+                pod,
+                matched: Some(matched),
+            } => format!(
+                "pod '{}' has been pending past the configured threshold ('{}')",
+                pod, matched
+            ),
+            Self::ScaledDownStatefulSet { sts, ordinal } => {
+                format!(
+                    "ordinal {} is beyond the replica count of StatefulSet '{}'",
+                    ordinal, sts
+                )
+            }
+            Self::WorkloadDeleted { owner } => {
+                format!("unused and its owner {} no longer exists", owner)
+            }
+            Self::ConsumersFinished { pod, finished_secs } => format!(
+                "all consumers finished, the last (pod '{}') {}s ago",
+                pod, finished_secs
+            ),
+            Self::OperatorRequested {
+                requested_by: Some(manager),
+            } => format!("requested by '{}' via {}", manager, REAP_NOW_ANNOTATION),
+            Self::OperatorRequested { requested_by: None } => {
+                format!("requested via {}", REAP_NOW_ANNOTATION)
+            }
+        }
+    }
+}
+
+/// Why a PVC was left alone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type")]
+pub enum SkipReason {
+    /// No pod references the PVC
+    NoConsumingPod,
+    /// The consuming pod is not in the `Pending` phase
+    PodNotPending { pod: String, phase: String },
+    /// The consuming pod is pending but not marked `Unschedulable`
+    PodNotUnschedulable { pod: String },
+    /// The consuming pod's own constraints exclude the PVC's selected node
+    PodTargetsOtherNode { pod: String, node: String },
+    /// The consuming pod hasn't been unschedulable for long enough yet
+    BelowThreshold {
+        pod: String,
+        pending_secs: i64,
+        threshold_secs: u64,
+    },
+    /// The consuming pod is unschedulable, but not because of its volumes
+    NotVolumeRelated { pod: String, message: String },
+    /// The consuming pod's scheduling message doesn't match `--pod-message-regex`
+    MessageNotMatched { pod: String, message: String },
+    /// `pod` has no controller owner among `--controller-owner-kinds` to recreate it
+    UnmanagedPod { pod: String },
+    /// The selected node exists and unschedulable pod checks are disabled
+    NodeExists { node: Option<String> },
+    /// The selected node is absent from the node list, but only for `cycles` consecutive cycles,
+    /// fewer than `--node-missing-cycles`
+    NodeRecentlyMissing {
+        node: String,
+        cycles: u32,
+        required: u32,
+    },
+    /// `pod` is running (or scheduled) with the claim mounted; this check can't be disabled
+    InUse { pod: String },
+    /// A delete for this exact PVC was already issued and is still propagating
+    DeleteInFlight,
+    /// The PVC is a deletion candidate, but a claim with its name (and another UID) was deleted
+    /// less than `--redelete-cooldown-secs` ago
+    RedeleteCooldown {
+        reason: DeleteReason,
+        until: DateTime<Utc>,
+    },
+    /// The PVC has been a deletion candidate for `seen` consecutive cycles, fewer than
+    /// `--confirmation-cycles`
+    AwaitingConfirmation {
+        reason: DeleteReason,
+        seen: u32,
+        required: u32,
+    },
+    /// The PVC is a deletion candidate annotated to be deleted at `delete_at`, with
+    /// `--soft-delete-delay-secs`
+    ScheduledForDeletion {
+        reason: DeleteReason,
+        delete_at: DateTime<Utc>,
+    },
+    /// The PVC is a deletion candidate whose `pvc-reaper.io/delete-at` annotation was removed,
+    /// cancelling its soft delete until it stops qualifying
+    SoftDeleteCancelled { reason: DeleteReason },
+    /// The PVC is a deletion candidate, but only `ready` nodes were Ready, fewer than the
+    /// `required` by `--min-ready-nodes` or `--min-ready-node-fraction`
+    TooFewReadyNodes {
+        reason: DeleteReason,
+        ready: usize,
+        required: usize,
+    },
+    /// The PVC's selected node is missing, but so is every other node: the node list came back
+    /// empty while PVCs reference selected nodes
+    NodeListEmpty { reason: DeleteReason },
+    /// The PVC is a deletion candidate, but was not confirmed at the `--interactive` prompt
+    Declined { reason: DeleteReason },
+    /// The PVC is a deletion candidate, but `candidates` of `matching` PVCs were, more than
+    /// `--max-deletion-fraction` allows
+    CircuitBreakerOpen {
+        reason: DeleteReason,
+        candidates: usize,
+        matching: usize,
+    },
+    /// The PVC is a deletion candidate, but `--max-deletions-per-cycle` was already reached
+    DeletionLimitReached { reason: DeleteReason, limit: usize },
+    /// The PVC's pod is unschedulable, but its latest `FailedScheduling` Event (if any, at
+    /// `last_seen`) is older than `--scheduling-event-max-age-secs`
+    StaleSchedulingEvent {
+        reason: DeleteReason,
+        last_seen: Option<DateTime<Utc>>,
+    },
+    /// The PVC is a deletion candidate, but the `--deletions-per-minute` bucket is empty
+    RateLimited {
+        reason: DeleteReason,
+        per_minute: u32,
+    },
+    /// The PVC is a deletion candidate, but its namespace already reached
+    /// `--max-deletions-per-namespace`
+    NamespaceLimitReached {
+        reason: DeleteReason,
+        namespace: String,
+        limit: usize,
+    },
+    /// The PVC already has a `deletionTimestamp`, e.g. a finalizer is holding it
+    Terminating { terminating_secs: i64 },
+    /// The PVC's namespace is not allowed by `--include-namespaces`/`--exclude-namespaces` or
+    /// doesn't match `--namespace-label-selector`
+    NamespaceExcluded { namespace: String },
+    /// The PVC's namespace is being deleted and will garbage-collect it
+    NamespaceTerminating { namespace: String },
+    /// The PVC's namespace opted out with `--namespace-annotation`, didn't opt in under
+    /// `--require-namespace-opt-in`, or couldn't be fetched
+    NamespaceOptedOut {
+        namespace: String,
+        annotation: String,
+    },
+    /// The PVC's `pvc-reaper.io/keep-until` timestamp is in the future or unparseable
+    KeepUntil { until: String },
+    /// The PVC is in a system namespace or the reaper's own
+    SystemNamespace { namespace: String },
+    /// The PVC carries the protection annotation, checked before any other criteria
+    Protected { annotation: String },
+    /// `--require-opt-in` is set and the PVC isn't annotated as reapable
+    NotOptedIn { annotation: String },
+    /// The PVC's phase is not listed in `--pvc-phases`
+    PhaseNotAllowed { phase: String },
+    /// The PVC is owned by a kind listed in `--skip-owner-kinds`
+    OwnedBy { kind: String, name: String },
+    /// The PVC's storage request is outside `--min-pvc-size`/`--max-pvc-size`
+    SizeOutOfRange { size_bytes: u64 },
+    /// The PVC belongs to a scaled-down StatefulSet ordinal but the grace period hasn't elapsed
+    ScaleDownGrace {
+        sts: String,
+        ordinal: u32,
+        remaining_secs: i64,
+    },
+    /// The PVC's selected node is missing and no pod references it, but the PVC is younger than
+    /// `--unreferenced-missing-node-min-age-secs`
+    UnreferencedTooYoung { node: String, remaining_secs: i64 },
+    /// The PVC's workload no longer exists but the PVC is younger than
+    /// `--orphaned-workload-min-age-secs`
+    OrphanTooYoung { owner: String, remaining_secs: i64 },
+    /// Every consumer has finished, but `pod` did so less than `--finished-pod-threshold-secs`
+    /// ago
+    FinishedTooRecently { pod: String, remaining_secs: i64 },
+}
+
+impl SkipReason {
+    /// Variant name, e.g. `NoConsumingPod`
+    pub fn variant(&self) -> &'static str {
+        match self {
+            Self::NoConsumingPod => "NoConsumingPod",
+            Self::PodNotPending { .. } => "PodNotPending",
+            Self::PodNotUnschedulable { .. } => "PodNotUnschedulable",
+            Self::PodTargetsOtherNode { .. } => "PodTargetsOtherNode",
+            Self::BelowThreshold { .. } => "BelowThreshold",
+            Self::NotVolumeRelated { .. } => "NotVolumeRelated",
+            Self::MessageNotMatched { .. } => "MessageNotMatched",
+            Self::UnmanagedPod { .. } => "UnmanagedPod",
+            Self::NodeExists { .. } => "NodeExists",
+            Self::NodeRecentlyMissing { .. } => "NodeRecentlyMissing",
+            Self::InUse { .. } => "InUse",
+            Self::DeleteInFlight => "DeleteInFlight",
+            Self::RedeleteCooldown { .. } => "RedeleteCooldown",
+            Self::AwaitingConfirmation { .. } => "AwaitingConfirmation",
+            Self::ScheduledForDeletion { .. } => "ScheduledForDeletion",
+            Self::SoftDeleteCancelled { .. } => "SoftDeleteCancelled",
+            Self::TooFewReadyNodes { .. } => "TooFewReadyNodes",
+            Self::NodeListEmpty { .. } => "NodeListEmpty",
+            Self::Declined { .. } => "Declined",
+            Self::CircuitBreakerOpen { .. } => "CircuitBreakerOpen",
+            Self::DeletionLimitReached { .. } => "DeletionLimitReached",
+            Self::RateLimited { .. } => "RateLimited",
+            Self::StaleSchedulingEvent { .. } => "StaleSchedulingEvent",
+            Self::NamespaceLimitReached { .. } => "NamespaceLimitReached",
+            Self::Terminating { .. } => "Terminating",
+            Self::NamespaceExcluded { .. } => "NamespaceExcluded",
+            Self::NamespaceOptedOut { .. } => "NamespaceOptedOut",
+            Self::SystemNamespace { .. } => "SystemNamespace",
+            Self::NamespaceTerminating { .. } => "NamespaceTerminating",
+            Self::KeepUntil { .. } => "KeepUntil",
+            Self::Protected { .. } => "Protected",
+            Self::NotOptedIn { .. } => "NotOptedIn",
+            Self::PhaseNotAllowed { .. } => "PhaseNotAllowed",
+            Self::OwnedBy { .. } => "OwnedBy",
+            Self::SizeOutOfRange { .. } => "SizeOutOfRange",
+            Self::ScaleDownGrace { .. } => "ScaleDownGrace",
+            Self::OrphanTooYoung { .. } => "OrphanTooYoung",
+            Self::UnreferencedTooYoung { .. } => "UnreferencedTooYoung",
+            Self::FinishedTooRecently { .. } => "FinishedTooRecently",
+        }
+    }
+
+    /// Human-readable explanation used in logs
+    pub fn describe(&self) -> String {
+        match self {
+            Self::NoConsumingPod => "no consuming pod".to_string(),
+            Self::PodNotPending { pod, phase } => {
+                format!("pod '{}' not Pending (phase {})", pod, phase)
+            }
+            Self::PodNotUnschedulable { pod } => format!("pod '{}' not Unschedulable", pod),
+            Self::PodTargetsOtherNode { pod, node } => {
+                format!("pod '{}' cannot target selected node '{}'", pod, node)
+            }
+            Self::BelowThreshold {
+                pending_secs,
+                threshold_secs,
+                ..
+            } => format!("below threshold ({}s < {}s)", pending_secs, threshold_secs),
+            Self::NotVolumeRelated { pod, message } => {
+                format!(
+                    "pod '{}' is unschedulable for other reasons: {}",
+                    pod, message
+                )
+            }
+            Self::MessageNotMatched { pod, message } => {
+                format!("pod '{}' message doesn't match the regex: {}", pod, message)
+            }
+            Self::UnmanagedPod { pod } => format!("pod '{}' is not controller-owned", pod),
+            Self::NodeExists { node: Some(node) } => format!("node {} exists", node),
+            Self::NodeExists { node: None } => "no selected node".to_string(),
+            Self::NodeRecentlyMissing {
+                node,
+                cycles,
+                required,
+            } => format!(
+                "node {} missing for {}/{} cycles, may still reappear",
+                node, cycles, required
+            ),
+            Self::InUse { pod } => format!("mounted by running pod '{}'", pod),
+            Self::DeleteInFlight => "deletion already in progress".to_string(),
+            Self::RedeleteCooldown { reason, until } => format!(
+                "candidate ({}), re-created after being deleted, in cooldown until {}",
+                reason.describe(),
+                until.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ),
+            Self::AwaitingConfirmation {
+                reason,
+                seen,
+                required,
+            } => format!(
+                "candidate {}/{} ({}), waiting for confirmation",
+                seen,
+                required,
+                reason.describe()
+            ),
+            Self::ScheduledForDeletion { reason, delete_at } => format!(
+                "candidate ({}), scheduled for deletion at {}",
+                reason.describe(),
+                delete_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ),
+            Self::SoftDeleteCancelled { reason } => format!(
+                "candidate ({}), scheduled deletion cancelled by removing {}",
+                reason.describe(),
+                DELETE_AT_ANNOTATION
+            ),
+            Self::TooFewReadyNodes {
+                reason,
+                ready,
+                required,
+            } => format!(
+                "candidate ({}), held back with only {} Ready nodes (at least {} required)",
+                reason.describe(),
+                ready,
+                required
+            ),
+            Self::NodeListEmpty { reason } => format!(
+                "candidate ({}), but the node list came back empty",
+                reason.describe()
+            ),
+            Self::Declined { reason } => format!(
+                "candidate ({}), declined at the interactive prompt",
+                reason.describe()
+            ),
+            Self::CircuitBreakerOpen {
+                reason,
+                candidates,
+                matching,
+            } => format!(
+                "candidate ({}), held back by the circuit breaker ({} of {} matching PVCs are candidates)",
+                reason.describe(),
+                candidates,
+                matching
+            ),
+            Self::DeletionLimitReached { reason, limit } => format!(
+                "candidate ({}), deferred after {} deletions this cycle",
+                reason.describe(),
+                limit
+            ),
+            Self::StaleSchedulingEvent {
+                reason,
+                last_seen: Some(at),
+            } => format!(
+                "candidate ({}), but the last FailedScheduling event is from {}",
+                reason.describe(),
+                at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ),
+            Self::StaleSchedulingEvent {
+                reason,
+                last_seen: None,
+            } => format!(
+                "candidate ({}), but no recent FailedScheduling event was found",
+                reason.describe()
+            ),
+            Self::RateLimited { reason, per_minute } => format!(
+                "candidate ({}), deferred by the limit of {} deletions per minute",
+                reason.describe(),
+                per_minute
+            ),
+            Self::NamespaceLimitReached {
+                reason,
+                namespace,
+                limit,
+            } => format!(
+                "candidate ({}), deferred after {} deletions in namespace {} this cycle",
+                reason.describe(),
+                limit,
+                namespace
+            ),
+            Self::Terminating { terminating_secs } => {
+                format!("already terminating for {}s", terminating_secs)
+            }
+            Self::NamespaceExcluded { namespace } => {
+                format!("namespace {} excluded by filters", namespace)
+            }
+            Self::NamespaceTerminating { namespace } => {
+                format!("namespace {} is terminating", namespace)
+            }
+            Self::NamespaceOptedOut {
+                namespace,
+                annotation,
+            } => format!(
+                "namespace {} is not enabled for reaping (annotation {})",
+                namespace, annotation
+            ),
+            Self::KeepUntil { until } => format!("kept until {}", until),
+            Self::SystemNamespace { namespace } => format!("{} is a system namespace", namespace),
+            Self::Protected { annotation } => format!("protected by annotation {}", annotation),
+            Self::NotOptedIn { annotation } => {
+                format!("not opted in (missing annotation {})", annotation)
+            }
+            Self::PhaseNotAllowed { phase } => format!("PVC phase {} not allowed", phase),
+            Self::OwnedBy { kind, name } => format!("owned by {} '{}'", kind, name),
+            Self::SizeOutOfRange { size_bytes } => {
+                format!("size {} bytes outside the configured range", size_bytes)
+            }
+            Self::ScaleDownGrace {
+                sts,
+                ordinal,
+                remaining_secs,
+            } => format!(
+                "ordinal {} of scaled-down StatefulSet '{}' in grace period ({}s remaining)",
+                ordinal, sts, remaining_secs
+            ),
+            Self::UnreferencedTooYoung {
+                node,
+                remaining_secs,
+            } => format!(
+                "unused on missing node '{}' but too young ({}s remaining)",
+                node, remaining_secs
+            ),
+            Self::OrphanTooYoung {
+                owner,
+                remaining_secs,
+            } => format!(
+                "owner {} is gone but the PVC is too young ({}s remaining)",
+                owner, remaining_secs
+            ),
+            Self::FinishedTooRecently {
+                pod,
+                remaining_secs,
+            } => format!(
+                "pod '{}' finished too recently ({}s remaining)",
+                pod, remaining_secs
+            ),
+        }
+    }
+}
+
+/// Number of not-yet-due candidates listed in the end-of-cycle summary
+const SUMMARY_TOP_CANDIDATES: usize = 5;
+const RECREATE_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Per-cycle tally of decisions, logged at debug level once the cycle is done
+#[derive(Debug, Default)]
+struct DecisionSummary {
+    counts: BTreeMap<String, usize>,
+    /// PVCs whose pod is unschedulable but below the threshold: `(pvc, pending, threshold)`
+    upcoming: Vec<(String, i64, u64)>,
+}
+
+impl DecisionSummary {
+    fn record_delete(&mut self, reason: &DeleteReason) {
+        *self
+            .counts
+            .entry(format!("deleted/{}", reason.variant()))
+            .or_default() += 1;
+    }
+
+    fn record_delete_failure(&mut self) {
+        *self.counts.entry("delete_failed".to_string()).or_default() += 1;
+    }
+
+    fn record_skip(&mut self, namespace: &str, name: &str, reason: &SkipReason) {
+        *self
+            .counts
+            .entry(format!("skipped/{}", reason.variant()))
+            .or_default() += 1;
+
+        if let SkipReason::BelowThreshold {
+            pending_secs,
+            threshold_secs,
+            ..
+        } = reason
+        {
+            self.upcoming.push((
+                format!("{}/{}", namespace, name),
+                *pending_secs,
+                *threshold_secs,
+            ));
+        }
+    }
+
+    /// The longest-pending candidates that haven't reached the threshold yet
+    fn top_upcoming(&self) -> Vec<&(String, i64, u64)> {
+        let mut upcoming: Vec<_> = self.upcoming.iter().collect();
+        upcoming.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        upcoming.truncate(SUMMARY_TOP_CANDIDATES);
+        upcoming
+    }
+
+    fn log(&self) {
+        for (decision, count) in &self.counts {
+            debug!("Decision summary: {} = {}", decision, count);
+        }
+        for (pvc, pending, threshold) in self.top_upcoming() {
+            debug!(
+                "Upcoming candidate: PVC {} pending {}s of {}s threshold",
+                pvc, pending, threshold
+            );
+        }
+    }
+}
+
+/// Build the Event recorded on a PVC just before it is deleted (or would be, in dry-run)
+fn deletion_event(
+    pvc: &PersistentVolumeClaim,
+    reason: &DeleteReason,
+    config: &ReaperConfig,
+) -> Event {
+    let node = get_selected_node(pvc, config).unwrap_or("<none>");
+    let (type_, event_reason, action, verb) = if config.dry_run {
+        (
+            EventType::Normal,
+            CANDIDATE_EVENT_REASON,
+            "DryRunDelete",
+            "Would delete",
+        )
+    } else {
+        (EventType::Warning, REAPED_EVENT_REASON, "Delete", "Deleted")
+    };
+
+    Event {
+        type_,
+        reason: event_reason.to_string(),
+        note: Some(format!(
+            "{} PVC: {} (selected node '{}')",
+            verb,
+            reason.describe(),
+            node
+        )),
+        action: action.to_string(),
+        secondary: None,
+    }
+}
+
+/// Merge patch clearing a PVC's finalizers, conditional on the resourceVersion that was
+/// evaluated so a concurrent update makes it fail
+fn finalizer_patch(pvc: &PersistentVolumeClaim) -> serde_json::Value {
+    serde_json::json!({
+        "metadata": {
+            "finalizers": null,
+            "resourceVersion": pvc.resource_version(),
+        }
+    })
+}
+
+/// When the PVC's `pvc-reaper.io/delete-at` annotation schedules it to be deleted; an
+/// unparseable value counts as unscheduled
+fn scheduled_delete_at(pvc: &PersistentVolumeClaim) -> Option<DateTime<Utc>> {
+    let delete_at = get_pvc_annotation(pvc, DELETE_AT_ANNOTATION)?;
+    DateTime::parse_from_rfc3339(delete_at.trim())
+        .map(|delete_at| delete_at.with_timezone(&Utc))
+        .ok()
+}
+
+/// Merge patch setting the soft-delete annotations, or removing them for `None`
+fn delete_at_patch(schedule: Option<(DateTime<Utc>, &DeleteReason)>) -> serde_json::Value {
+    let (delete_at, reason) = match schedule {
+        Some((delete_at, reason)) => (
+            Some(delete_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)),
+            Some(reason.describe()),
+        ),
+        None => (None, None),
+    };
+    serde_json::json!({
+        "metadata": {
+            "annotations": {
+                DELETE_AT_ANNOTATION: delete_at,
+                DELETE_REASON_ANNOTATION: reason,
+            }
+        }
+    })
+}
+
+/// Annotate a candidate PVC with when it will be deleted and why, recording a Warning Event.
+/// Returns whether the annotation was set.
+async fn schedule_deletion(
+    client: &Client,
+    config: &ReaperConfig,
+    recorder: &Recorder,
+    pvc: &PersistentVolumeClaim,
+    reason: &DeleteReason,
+    delete_at: DateTime<Utc>,
+) -> bool {
+    let (namespace, name) = (pvc.namespace().unwrap_or_default(), pvc.name_any());
+    if config.dry_run {
+        info!(
+            namespace = %namespace,
+            pvc = %name,
+            reason = %reason.describe(),
+            %delete_at,
+            dry_run = true,
+            "[DRY RUN] Would schedule PVC for deletion"
+        );
+        return false;
+    }
+
+    if let Err(e) = patch_pvc(
+        client,
+        &namespace,
+        &name,
+        delete_at_patch(Some((delete_at, reason))),
+    )
+    .await
+    {
+        error!(
+            namespace = %namespace,
+            pvc = %name,
+            "Failed to schedule PVC for deletion: {:#}",
+            e
+        );
+        return false;
+    }
+    warn!(
+        namespace = %namespace,
+        pvc = %name,
+        reason = %reason.describe(),
+        %delete_at,
+        "Scheduled PVC for deletion"
+    );
+    let event = deletion_scheduled_event(reason, delete_at, config);
+    if let Err(e) = recorder.publish(&event, &pvc.object_ref(&())).await {
+        warn!(namespace = %namespace, pvc = %name, "Failed to record event for PVC: {:#}", e);
+    }
+    true
+}
+
+/// Merge-patch a PVC; one that is already gone counts as patched
+async fn patch_pvc(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    patch: serde_json::Value,
+) -> Result<()> {
+    let api = Api::<PersistentVolumeClaim>::namespaced(client.clone(), namespace);
+    match api
+        .patch(name, &PatchParams::default(), &Patch::Merge(patch))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(e)) if e.code == 404 => Ok(()),
+        Err(e) => {
+            telemetry::api_error("persistentvolumeclaims", "patch");
+            Err(e).with_context(|| format!("Failed to patch PVC {}/{}", namespace, name))
+        }
+    }
+}
+
+/// Build the pending-deletion Warning Event recorded when a PVC is annotated for deletion
+fn deletion_scheduled_event(
+    reason: &DeleteReason,
+    delete_at: DateTime<Utc>,
+    config: &ReaperConfig,
+) -> Event {
+    Event {
+        type_: EventType::Warning,
+        reason: PENDING_EVENT_REASON.to_string(),
+        note: Some(format!(
+            "pvc-reaper will delete this PVC at {} ({}); remove the {} annotation or set {} to cancel",
+            delete_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            reason.describe(),
+            DELETE_AT_ANNOTATION,
+            config.protection_annotation
+        )),
+        action: "ScheduleDelete".to_string(),
+        secondary: None,
+    }
+}
+
+/// Build the Warning Event recorded when finalizers are forcibly removed from a PVC
+fn finalizers_removed_event(finalizers: &str, node: &str, terminating_secs: i64) -> Event {
+    Event {
+        type_: EventType::Warning,
+        reason: FINALIZERS_REMOVED_EVENT_REASON.to_string(),
+        note: Some(format!(
+            "Removing finalizers [{}] after {}s terminating: selected node '{}' is missing",
+            finalizers, terminating_secs, node
+        )),
+        action: "RemoveFinalizers".to_string(),
+        secondary: None,
+    }
+}
+
+/// What the evaluation pass of `State::reap` gathers across PVCs
+#[derive(Default)]
+struct EvaluationPass {
+    gauges: CandidateGauges,
+    /// UIDs of scaled-down StatefulSet PVCs beyond the replica count
+    excess_ordinals: HashSet<String>,
+    /// Namespaces whose opt-out was already logged this cycle
+    opted_out_logged: HashSet<String>,
+    /// UIDs of candidates counting towards `--confirmation-cycles`
+    confirming: HashSet<String>,
+}
+
+/// What the deletion pass of `State::reap` shares and gathers across PVCs
+struct DeletionPass {
+    recorder: Recorder,
+    audit_log: Option<AuditLog>,
+    summary: DecisionSummary,
+    /// UIDs of candidates whose pending deletion was announced
+    pending_candidates: HashSet<String>,
+    /// UIDs of candidates with a soft delete scheduled
+    scheduled: HashSet<String>,
+    /// Candidates held back by `--max-deletions-per-namespace`, by namespace
+    deferred_in: BTreeMap<String, usize>,
+    /// Candidates held back by `--deletions-per-minute`
+    rate_limited: usize,
+}
+
+impl DeletionPass {
+    fn new(client: &Client, config: &ReaperConfig) -> Self {
+        Self {
+            recorder: Recorder::new(client.clone(), EVENT_REPORTER.into()),
+            audit_log: config.audit_log_path.as_deref().and_then(|path| {
+                AuditLog::open(path)
+                    .inspect_err(|e| error!("Failed to open audit log {}: {}", path.display(), e))
+                    .ok()
+            }),
+            summary: DecisionSummary::default(),
+            pending_candidates: HashSet::new(),
+            scheduled: HashSet::new(),
+            deferred_in: BTreeMap::new(),
+            rate_limited: 0,
+        }
+    }
+}
+
+/// A PVC's decision from the evaluation pass of `State::reap`, before anything is deleted
+struct Evaluation<'a> {
+    pvc: &'a PersistentVolumeClaim,
+    namespace: String,
+    pvc_name: String,
+    span: Span,
+    labels: telemetry::PvcLabels,
+    reason: Result<DeleteReason, SkipReason>,
+}
+
+/// Ask on the terminal about each candidate with `--interactive`, skipping the declined ones
+async fn confirm_interactively(evaluations: &mut [Evaluation<'_>]) -> Result<()> {
+    let rows: Vec<[String; 3]> = evaluations
+        .iter()
+        .filter_map(|evaluation| {
+            let reason = evaluation.reason.as_ref().ok()?;
+            Some([
+                evaluation.namespace.clone(),
+                evaluation.pvc_name.clone(),
+                reason.describe(),
+            ])
+        })
+        .collect();
+    if rows.is_empty() {
+        return Ok(());
+    }
+
+    let answers = tokio::task::spawn_blocking(move || {
+        cli::confirm_deletions(&rows, std::io::stdin().lock(), std::io::stderr())
+    })
+    .await
+    .context("Interactive prompt panicked")?
+    .context("Failed to read confirmation from the terminal")?;
+
+    let mut answers = answers.into_iter();
+    for evaluation in evaluations {
+        if let Ok(candidate) = &evaluation.reason
+            && !answers.next().unwrap_or_default()
+        {
+            evaluation.reason = Err(SkipReason::Declined {
+                reason: candidate.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Per-cycle counts behind the candidate gauges
+#[derive(Debug, Default, PartialEq, Eq)]
+struct CandidateGauges {
+    matching: usize,
+    pending_consumer: usize,
+    candidates: usize,
+}
+
+impl CandidateGauges {
+    /// Count a PVC that passed `matches_storage_criteria`
+    fn observe(
+        &mut self,
+        state: &State,
+        pvc: &PersistentVolumeClaim,
+        decision: &Result<DeleteReason, SkipReason>,
+    ) {
+        self.matching += 1;
+        if state
+            .pods
+            .iter()
+            .any(|pod| pod_uses_pvc(pod, pvc) && pod_is_pending(pod))
+        {
+            self.pending_consumer += 1;
+        }
+        if decision.is_ok() {
+            self.candidates += 1;
+        }
+    }
+
+    fn publish(&self) {
+        telemetry::candidate_gauges(self.matching, self.pending_consumer, self.candidates);
+    }
+}
+
+/// Whether `candidates` out of `matching` PVCs is too large a share to delete in one cycle.
+/// Up to `--max-deletion-fraction-floor` candidates are always allowed, so small clusters can
+/// still reap.
+fn circuit_breaker_trips(config: &ReaperConfig, candidates: usize, matching: usize) -> bool {
+    config.max_deletion_fraction.is_some_and(|fraction| {
+        candidates > config.max_deletion_fraction_floor
+            && candidates as f64 > fraction * matching as f64
+    })
+}
+
+/// `namespace/name` of a PVC, which outlives its UID when the claim is re-created
+fn claim_key(pvc: &PersistentVolumeClaim) -> String {
+    format!("{}/{}", pvc.namespace().unwrap_or_default(), pvc.name_any())
+}
+
+/// The protection annotation key if the PVC carries it with a truthy value
+fn protected_by<'a>(pvc: &PersistentVolumeClaim, config: &'a ReaperConfig) -> Option<&'a str> {
+    get_pvc_annotation(pvc, &config.protection_annotation)
+        .is_some_and(is_truthy)
+        .then_some(config.protection_annotation.as_str())
+}
+
+/// The field manager (e.g. `kubectl-annotate`) that last set `annotation` on the PVC, from its
+/// managed fields
+fn annotation_manager<'a>(pvc: &'a PersistentVolumeClaim, annotation: &str) -> Option<&'a str> {
+    let field = format!("f:{}", annotation);
+    pvc.metadata
+        .managed_fields
+        .iter()
+        .flatten()
+        .rev()
+        .find(|entry| {
+            entry.fields_v1.as_ref().is_some_and(|fields| {
+                fields.0["f:metadata"]["f:annotations"]
+                    .get(&field)
+                    .is_some()
+            })
+        })
+        .and_then(|entry| entry.manager.as_deref())
+}
+
+/// Whether an annotation value means "yes" (`true`, `yes`, `on` or `1`, case-insensitive)
+fn is_truthy(value: &str) -> bool {
+    ["true", "yes", "on", "1"]
+        .iter()
+        .any(|truthy| value.trim().eq_ignore_ascii_case(truthy))
+}
+
+/// Whether an annotation value means "no" (`false`, `no`, `off` or `0`, case-insensitive)
+fn is_falsy(value: &str) -> bool {
+    ["false", "no", "off", "0"]
+        .iter()
+        .any(|falsy| value.trim().eq_ignore_ascii_case(falsy))
+}
+
+/// Whether a namespace's owner lets the reaper in: not opted out with `--namespace-annotation`,
+/// and opted in if `--require-namespace-opt-in` is set
+fn namespace_enabled(namespace: &Namespace, config: &ReaperConfig) -> bool {
+    match namespace.annotations().get(&config.namespace_annotation) {
+        Some(value) if is_falsy(value) => false,
+        Some(value) if is_truthy(value) => true,
+        _ => !config.require_namespace_opt_in,
+    }
+}
+
+/// The namespaces [`namespace_enabled`] lets in, from the namespace list or, in
+/// namespace-scoped mode without cluster-wide access, by fetching each `--namespaces` entry.
+/// A namespace that can't be fetched is left out rather than reaped unchecked.
+async fn enabled_namespaces(
+    client: &Client,
+    config: &ReaperConfig,
+    listed: Option<&[Namespace]>,
+) -> Option<HashSet<String>> {
+    if config.namespace_annotation.is_empty() {
+        return None;
+    }
+    if let Some(namespaces) = listed {
+        return Some(
+            namespaces
+                .iter()
+                .filter(|namespace| namespace_enabled(namespace, config))
+                .map(ResourceExt::name_any)
+                .collect(),
+        );
+    }
+
+    let api = Api::<Namespace>::all(client.clone());
+    let mut enabled = HashSet::new();
+    for name in &config.namespaces {
+        match api.get(name).await {
+            Ok(namespace) if namespace_enabled(&namespace, config) => {
+                enabled.insert(name.clone());
+            }
+            Ok(_) => {}
+            Err(e) => {
+                telemetry::api_error("namespaces", "get");
+                warn!(
+                    namespace = %name,
+                    "Failed to get namespace, treating it as opted out: {}",
+                    e
+                );
+            }
+        }
+    }
+    Some(enabled)
+}
+
+/// The first owner reference whose kind is listed in `--skip-owner-kinds`. Entries are either a
+/// bare `Kind` or `group/Kind`, where the group is compared against the owner's apiVersion.
+fn skipped_owner<'a>(
+    pvc: &'a PersistentVolumeClaim,
+    config: &ReaperConfig,
+) -> Option<&'a OwnerReference> {
+    pvc.owner_references()
+        .iter()
+        .find(|owner| owner_kind_matches(owner, &config.skip_owner_kinds))
+}
+
+/// Whether `owner` is one of `kinds`, each given as `Kind` or `group/Kind`
+fn owner_kind_matches(owner: &OwnerReference, kinds: &[String]) -> bool {
+    let group = owner
+        .api_version
+        .rsplit_once('/')
+        .map_or("", |(group, _)| group);
+    kinds.iter().any(|entry| match entry.rsplit_once('/') {
+        Some((entry_group, kind)) => entry_group == group && kind == owner.kind,
+        None => *entry == owner.kind,
+    })
+}
+
+/// Whether the pod's controller owner is one of `--controller-owner-kinds`
+fn pod_is_controller_owned(pod: &Pod, config: &ReaperConfig) -> bool {
+    pod.owner_references().iter().any(|owner| {
+        owner.controller == Some(true) && owner_kind_matches(owner, &config.controller_owner_kinds)
+    })
+}
+
+/// The PVC's `status.phase`, treating a missing status as `Pending`
+fn pvc_phase(pvc: &PersistentVolumeClaim) -> &str {
+    pvc.status
+        .as_ref()
+        .and_then(|status| status.phase.as_deref())
+        .unwrap_or("Pending")
+}
+
+/// Reasons to leave a matching PVC alone without evaluating its consumers
+fn pre_evaluation_skip(
+    pvc: &PersistentVolumeClaim,
+    config: &ReaperConfig,
+    tracker: &ReapTracker,
+) -> Option<SkipReason> {
+    let namespace = pvc.namespace().unwrap_or_default();
+    if let Some(annotation) = protected_by(pvc, config) {
+        info!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            annotation,
+            "PVC is protected by annotation {}, skipping",
+            annotation
+        );
+        return Some(SkipReason::Protected {
+            annotation: annotation.to_string(),
+        });
+    }
+
+    if is_system_namespace(&namespace, config) {
+        debug!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            "PVC is in a system namespace, skipping (see --include-system-namespaces)"
+        );
+        return Some(SkipReason::SystemNamespace { namespace });
+    }
+
+    if !namespace_allowed(&namespace, config) {
+        return Some(SkipReason::NamespaceExcluded { namespace });
+    }
+
+    if let Some(owner) = skipped_owner(pvc, config) {
+        return Some(SkipReason::OwnedBy {
+            kind: owner.kind.clone(),
+            name: owner.name.clone(),
+        });
+    }
+
+    let phase = pvc_phase(pvc);
+    if !config.pvc_phases.iter().any(|allowed| allowed == phase) {
+        info!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            phase,
+            "PVC phase {} is not in --pvc-phases, skipping",
+            phase
+        );
+        return Some(SkipReason::PhaseNotAllowed {
+            phase: phase.to_string(),
+        });
+    }
+
+    let size_bytes = requested_storage_bytes(pvc);
+    if config.min_pvc_size.is_some_and(|min| size_bytes < min)
+        || config.max_pvc_size.is_some_and(|max| size_bytes > max)
+    {
+        debug!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            size_bytes,
+            "PVC size outside --min-pvc-size/--max-pvc-size, skipping"
+        );
+        return Some(SkipReason::SizeOutOfRange { size_bytes });
+    }
+
+    if config.require_opt_in
+        && !get_pvc_annotation(pvc, &config.opt_in_annotation).is_some_and(is_truthy)
+    {
+        return Some(SkipReason::NotOptedIn {
+            annotation: config.opt_in_annotation.clone(),
+        });
+    }
+
+    if tracker.delete_in_flight(pvc) {
+        debug!(
+            namespace = %namespace,
+            pvc = %pvc.name_any(),
+            "PVC deletion already in progress, not re-issuing"
+        );
+        return Some(SkipReason::DeleteInFlight);
+    }
+
+    None
+}
+
+/// Whether `namespace` is one of the built-in system namespaces or the reaper's own, unless
+/// `--include-system-namespaces` is set
+fn is_system_namespace(namespace: &str, config: &ReaperConfig) -> bool {
+    !config.include_system_namespaces
+        && (SYSTEM_NAMESPACES.contains(&namespace)
+            || config.reaper_namespace.as_deref() == Some(namespace))
+}
+
+/// Whether the namespace filters allow reaping in `namespace`; exclusion wins over inclusion and
+/// an empty include list allows every namespace
+pub fn namespace_allowed(namespace: &str, config: &ReaperConfig) -> bool {
+    if config.exclude_namespaces.iter().any(|ns| ns == namespace) {
+        return false;
+    }
+
+    config.include_namespaces.is_empty()
+        || config.include_namespaces.iter().any(|ns| ns == namespace)
+}
+
+/// Record the outcome for the PVC whose span is current
+fn record_decision(decision: &str, reason: &str) {
+    let span = Span::current();
+    span.record("decision", decision);
+    span.record("reason", reason);
+}
+
+/// Log the `--explain` decision line for a PVC that matched the storage criteria
+fn explain(namespace: &str, pvc: &str, decision: &Result<DeleteReason, SkipReason>) {
+    let (decision, reason) = match decision {
+        Ok(reason) => ("delete", reason.describe()),
+        Err(skip) => ("skip", skip.describe()),
+    };
+    info!(
+        namespace = %namespace,
+        pvc = %pvc,
+        decision,
+        "{}/{}: {} ({})",
+        namespace,
+        pvc,
+        decision,
+        reason
+    );
+}
+
+/// Build the Warning Event recorded once when a PVC enters its pending-deletion window
+fn pending_event(cause: &str, delete_at: DateTime<Utc>) -> Event {
+    Event {
+        type_: EventType::Warning,
+        reason: PENDING_EVENT_REASON.to_string(),
+        note: Some(format!(
+            "{}; pvc-reaper will delete this PVC at {} if it still qualifies then",
+            cause,
+            delete_at.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        )),
+        action: "PendingDelete".to_string(),
+        secondary: None,
+    }
+}
+
+/// Get annotation value from PVC metadata
+fn get_pvc_annotation<'a>(pvc: &'a PersistentVolumeClaim, key: &str) -> Option<&'a str> {
+    pvc.metadata
+        .annotations
+        .as_ref()?
+        .get(key)
+        .map(String::as_str)
+}
+
+/// Get the provisioner from the GA annotation, falling back to the beta one, along with the
+/// annotation key it came from
+fn get_pvc_provisioner(pvc: &PersistentVolumeClaim) -> Option<(&'static str, &str)> {
+    [PROVISIONER_ANNOTATION, BETA_PROVISIONER_ANNOTATION]
+        .into_iter()
+        .find_map(|key| get_pvc_annotation(pvc, key).map(|prov| (key, prov)))
+}
+
+/// Get the storage class name from a PVC spec
+fn get_storage_class(pvc: &PersistentVolumeClaim) -> Option<&str> {
+    pvc.spec.as_ref()?.storage_class_name.as_deref()
+}
+
+/// The StorageClass annotated as the cluster default. With several defaults the newest wins,
+/// as it does for the admission controller.
+fn default_storage_class(classes: &[StorageClass]) -> Option<String> {
+    let defaults: Vec<&StorageClass> = classes
+        .iter()
+        .filter(|class| {
+            class
+                .annotations()
+                .get(DEFAULT_CLASS_ANNOTATION)
+                .is_some_and(|value| value == "true")
+        })
+        .collect();
+    if defaults.len() > 1 {
+        warn!(
+            "{} StorageClasses are marked as default, using the newest",
+            defaults.len()
+        );
+    }
+
+    defaults
+        .into_iter()
+        .max_by_key(|class| class.creation_timestamp())
+        .map(ResourceExt::name_any)
+}
+
+/// Get the selected node from the first `--selected-node-annotation` key set on the PVC
+fn get_selected_node<'a>(pvc: &'a PersistentVolumeClaim, config: &ReaperConfig) -> Option<&'a str> {
+    config
+        .selected_node_annotation
+        .iter()
+        .find_map(|key| get_pvc_annotation(pvc, key))
+}
+
+/// Requested storage of a PVC, as written in its spec
+fn requested_storage(pvc: &PersistentVolumeClaim) -> Option<&str> {
+    pvc.spec
+        .as_ref()?
+        .resources
+        .as_ref()?
+        .requests
+        .as_ref()?
+        .get("storage")
+        .map(|quantity| quantity.0.as_str())
+}
+
+/// Requested storage of a PVC in bytes, counting a missing or malformed request as zero
+fn requested_storage_bytes(pvc: &PersistentVolumeClaim) -> u64 {
+    requested_storage(pvc)
+        .and_then(quantity_to_bytes)
+        .unwrap_or_default()
+}
+
+/// Convert a Kubernetes quantity such as `10Gi`, `500M` or `1.5e9` to bytes, rounding up
+pub fn quantity_to_bytes(quantity: &str) -> Option<u64> {
+    const BINARY: [(&str, f64); 6] = [
+        ("Ki", 1024.0),
+        ("Mi", 1048576.0),
+        ("Gi", 1073741824.0),
+        ("Ti", 1099511627776.0),
+        ("Pi", 1125899906842624.0),
+        ("Ei", 1152921504606846976.0),
+    ];
+    const DECIMAL: [(&str, f64); 7] = [
+        ("m", 1e-3),
+        ("k", 1e3),
+        ("M", 1e6),
+        ("G", 1e9),
+        ("T", 1e12),
+        ("P", 1e15),
+        ("E", 1e18),
+    ];
+
+    let quantity = quantity.trim();
+    let (number, multiplier) = BINARY
+        .iter()
+        .chain(DECIMAL.iter())
+        .find_map(|(suffix, multiplier)| {
+            quantity
+                .strip_suffix(suffix)
+                .filter(|number| number.parse::<f64>().is_ok())
+                .map(|number| (number, *multiplier))
+        })
+        .unwrap_or((quantity, 1.0));
+
+    let bytes = number.parse::<f64>().ok()? * multiplier;
+    (bytes.is_finite() && bytes >= 0.0).then(|| bytes.ceil() as u64)
+}
+
+#[instrument(name = "reap", level = "debug", skip_all)]
+pub async fn reap(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &mut ReapTracker,
+) -> Result<ReapResult> {
+    let start = Instant::now();
+    let result = reap_cycle(client, config, tracker, None).await;
+    telemetry::reconcile_duration(start.elapsed());
+    telemetry::cycle_completed(result.is_ok());
+    result
+}
+
+/// Evaluate only the PVCs whose selected node is one of `nodes`, e.g. right after those nodes
+/// were deleted. PVCs whose pods haven't gone unschedulable yet are left to the periodic loop.
+pub async fn reap_nodes(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &mut ReapTracker,
+    nodes: HashSet<String>,
+) -> Result<ReapResult> {
+    reap_cycle(client, config, tracker, Some(Target::Nodes(nodes))).await
+}
+
+/// Evaluate only these `(namespace, name)` PVCs, e.g. when a pod using them turns
+/// unschedulable
+pub async fn reap_claims(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &mut ReapTracker,
+    claims: HashSet<(String, String)>,
+) -> Result<ReapResult> {
+    reap_cycle(client, config, tracker, Some(Target::Claims(claims))).await
+}
+
+/// Evaluate every matching PVC as a cycle would and return the ones that would be deleted,
+/// without deleting anything
+pub async fn candidates(client: &Client, config: &ReaperConfig) -> Result<CandidateList> {
+    let state = State::new(client, config).await?;
+    Ok(state.candidates(config))
+}
+
+/// Every decision behind `pvc-reaper list`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CandidateList {
+    pub candidates: Vec<Candidate>,
+    /// Matching PVCs that would be left alone, with why
+    pub skipped: Vec<SkippedPvc>,
+}
+
+/// A PVC that would be deleted right now, as printed by `pvc-reaper list`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Candidate {
+    pub namespace: String,
+    pub name: String,
+    pub storage_class: Option<String>,
+    pub node: Option<String>,
+    pub pod: Option<String>,
+    pub reason: DeleteReason,
+    /// How long the consuming pod has been unschedulable, if it is
+    pub pending_secs: Option<i64>,
+}
+
+/// Fetch the cluster state and explain the reaper's decision for one PVC
+pub async fn explain_pvc(
+    client: &Client,
+    config: &ReaperConfig,
+    namespace: &str,
+    name: &str,
+) -> Result<Explanation> {
+    let state = State::new(client, config).await?;
+    let pvc = state
+        .pvcs
+        .iter()
+        .find(|pvc| pvc.namespace().as_deref() == Some(namespace) && pvc.name_any() == name)
+        .with_context(|| format!("PVC {}/{} not found", namespace, name))?;
+    Ok(state.explanation(pvc, config))
+}
+
+/// One check walked by `pvc-reaper explain`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Check {
+    pub name: &'static str,
+    /// Whether the check points towards deletion
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Every check for one PVC and the resulting decision, as printed by `pvc-reaper explain`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Explanation {
+    pub namespace: String,
+    pub name: String,
+    pub checks: Vec<Check>,
+    /// `None` when the PVC doesn't match the storage criteria and is never evaluated
+    pub decision: Option<Result<DeleteReason, SkipReason>>,
+}
+
+impl Explanation {
+    /// Whether the PVC would be deleted right now
+    pub fn is_candidate(&self) -> bool {
+        matches!(self.decision, Some(Ok(_)))
+    }
+}
+
+/// The PVCs a targeted reap evaluates
+#[derive(Debug)]
+enum Target {
+    /// PVCs pinned to one of these nodes
+    Nodes(HashSet<String>),
+    /// PVCs by `(namespace, name)`
+    Claims(HashSet<(String, String)>),
+}
+
+async fn reap_cycle(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &mut ReapTracker,
+    target: Option<Target>,
+) -> Result<ReapResult> {
+    let paused = is_paused(client, config, tracker).await?;
+    if paused && config.pause_mode == PauseMode::Skip {
+        info!(
+            "Reaping paused via ConfigMap {}/{}, skipping cycle",
+            config.pause_configmap_namespace,
+            config.pause_configmap_name.as_deref().unwrap_or_default()
+        );
+        return Ok(ReapResult {
+            paused: true,
+            ..Default::default()
+        });
+    }
+
+    let kill_switch = paused;
+    telemetry::kill_switch(kill_switch);
+    let forced;
+    let config = if kill_switch {
+        warn!(
+            "Reaping paused via ConfigMap {}/{}, evaluating PVCs without deleting any",
+            config.pause_configmap_namespace,
+            config.pause_configmap_name.as_deref().unwrap_or_default()
+        );
+        forced = kill_switch_config(config);
+        &forced
+    } else {
+        config
+    };
+
+    let mut state = State::new(client, config).await?;
+    // Listed before counting missing nodes, so an LVMVolume's owner is counted like a PVC's node
+    if config.cleanup_openebs_lvmvolumes && !tracker.lvmvolume_crd_missing && target.is_none() {
+        state.lvmvolumes = list_lvmvolumes(client, tracker).await;
+    }
+    if config.reap_on_cordoned_nodes {
+        state.cordoned_since = tracker.observe_cordoned(&state.nodes, state.now);
+    }
+    state.target = target;
+    state.track_missing_nodes(config, tracker);
+    let loaded = LoadedCounts {
+        nodes: state.nodes.len(),
+        pods: state.pods.len(),
+        pvcs: state.pvcs.len(),
+    };
+    info!(
+        "Loaded state: {} nodes, {} pods, {} PVCs",
+        loaded.nodes, loaded.pods, loaded.pvcs
+    );
+
+    let result = state.reap(client, config, tracker).await?;
+    Ok(ReapResult {
+        loaded,
+        kill_switch,
+        ..result
+    })
+}
+
+/// List `K` in each of `--namespaces`, or cluster-wide when none are set
+async fn list_namespaced<K>(
+    client: &Client,
+    config: &ReaperConfig,
+    resource: &'static str,
+) -> Result<Vec<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + fmt::Debug,
+    K::DynamicType: Default,
+{
+    if config.namespaces.is_empty() {
+        let list = Api::<K>::all(client.clone())
+            .list(&ListParams::default())
+            .await
+            .inspect_err(|_| telemetry::api_error(resource, "list"))?;
+        return complete_list(list, resource);
+    }
+
+    let mut items = Vec::new();
+    for namespace in &config.namespaces {
+        let list = Api::<K>::namespaced(client.clone(), namespace)
+            .list(&ListParams::default())
+            .await
+            .inspect_err(|_| telemetry::api_error(resource, "list"))
+            .with_context(|| format!("in namespace {}", namespace))?;
+        items.extend(
+            complete_list(list, resource).with_context(|| format!("in namespace {}", namespace))?,
+        );
+    }
+    Ok(items)
+}
+
+/// The items of a list, which must be the whole collection. A continue token means the API
+/// server returned only part of it, and acting on part would make the rest look missing.
+fn complete_list<K: Clone>(list: ObjectList<K>, resource: &str) -> Result<Vec<K>> {
+    if list
+        .metadata
+        .continue_
+        .as_deref()
+        .is_some_and(|token| !token.is_empty())
+    {
+        anyhow::bail!(
+            "Listing {} returned a partial result with a continue token; refusing to act on it",
+            resource
+        );
+    }
+    Ok(list.items)
+}
+
+/// List a cluster-scoped resource. With `--namespaces` set, a 403 yields `None` and a warning
+/// instead of failing every cycle.
+async fn list_cluster<K>(
+    client: &Client,
+    config: &ReaperConfig,
+    resource: &'static str,
+    params: &ListParams,
+) -> Result<Option<Vec<K>>>
+where
+    K: Resource + Clone + DeserializeOwned + fmt::Debug,
+    K::DynamicType: Default,
+{
+    match Api::<K>::all(client.clone()).list(params).await {
+        Ok(list) => complete_list(list, resource).map(Some),
+        Err(kube::Error::Api(e)) if e.code == 403 && !config.namespaces.is_empty() => {
+            warn!(
+                "Not allowed to list {} cluster-wide, continuing without them in namespace-scoped mode",
+                resource
+            );
+            Ok(None)
+        }
+        Err(e) => {
+            telemetry::api_error(resource, "list");
+            Err(e.into())
+        }
+    }
+}
+
+/// Read the pause ConfigMap, if one is configured. A missing ConfigMap means "not paused" and
+/// is warned about once, not every cycle.
+async fn is_paused(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &mut ReapTracker,
+) -> Result<bool> {
+    let Some(name) = config.pause_configmap_name.as_deref() else {
+        return Ok(false);
+    };
+
+    let namespace = &config.pause_configmap_namespace;
+    let configmap = Api::<ConfigMap>::namespaced(client.clone(), namespace)
+        .get_opt(name)
+        .await
+        .inspect_err(|_| telemetry::api_error("configmaps", "get"))
+        .context("Failed to get pause ConfigMap")?;
+    match &configmap {
+        None if !tracker.pause_configmap_missing => {
+            warn!(
+                "Pause ConfigMap {}/{} not found, treating reaping as not paused",
+                namespace, name
+            );
+            tracker.pause_configmap_missing = true;
+        }
+        None => {}
+        Some(_) => tracker.pause_configmap_missing = false,
+    }
+    Ok(configmap_is_paused(configmap.as_ref()))
+}
+
+/// The configuration a cycle paused with `--pause-mode=dry-run` runs with. Client-side, so not
+/// even a dry-run delete reaches the API server or its admission webhooks.
+fn kill_switch_config(config: &ReaperConfig) -> ReaperConfig {
+    ReaperConfig {
+        dry_run: true,
+        dry_run_mode: DryRunMode::Client,
+        ..config.clone()
+    }
+}
+
+fn configmap_is_paused(configmap: Option<&ConfigMap>) -> bool {
+    configmap
+        .and_then(|cm| cm.data.as_ref())
+        .and_then(|data| data.get(PAUSED_KEY))
+        .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+}
+
+/// Whether the PVC's storage class, provisioner annotation and annotations match the
+/// configured filters
+pub fn matches_storage_criteria(pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> bool {
+    storage_criteria_match(
+        get_storage_class(pvc),
+        get_pvc_provisioner(pvc).map(|(_, prov)| prov),
+        config,
+    ) && matches_annotation_selector(pvc, config)
+}
+
+/// Whether the PVC's annotations satisfy every `--pvc-annotation-selector` term
+pub fn matches_annotation_selector(pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> bool {
+    config
+        .pvc_annotation_selector
+        .iter()
+        .all(|term| term.matches(|key| get_pvc_annotation(pvc, key)))
+}
+
+fn storage_criteria_match(
+    storage_class: Option<&str>,
+    provisioner: Option<&str>,
+    config: &ReaperConfig,
+) -> bool {
+    matches!(
+        (storage_class, provisioner),
+        (Some(sc), Some(prov)) if config.storage_class_patterns().any(|p| p.matches(sc)) && config.storage_provisioner.iter().any(|p| p == prov)
+    )
+}
+
+/// Whether `pod` mounts `pvc`; claim references only resolve within the pod's own namespace
+fn pod_uses_pvc(pod: &Pod, pvc: &PersistentVolumeClaim) -> bool {
+    if pod.namespace() != pvc.namespace() {
+        return false;
+    }
+
+    get_pod_pvc_names(pod).contains(&pvc.name_any())
+}
+
+fn pod_phase(pod: &Pod) -> Option<&str> {
+    pod.status.as_ref()?.phase.as_deref()
+}
+
+fn pod_is_pending(pod: &Pod) -> bool {
+    pod_phase(pod).is_some_and(|phase| phase == "Pending")
+}
+
+fn pod_is_scheduled(pod: &Pod) -> bool {
+    pod.spec
+        .as_ref()
+        .is_some_and(|spec| spec.node_name.is_some())
+}
+
+/// When a finished pod completed: its last container termination, falling back to the latest
+/// condition transition. Never the creation time.
+fn pod_finished_at(pod: &Pod) -> Option<DateTime<Utc>> {
+    let status = pod.status.as_ref()?;
+    let terminated = status
+        .container_statuses
+        .iter()
+        .flatten()
+        .filter_map(|container| {
+            container
+                .state
+                .as_ref()?
+                .terminated
+                .as_ref()?
+                .finished_at
+                .as_ref()
+        })
+        .map(|time| time.0)
+        .max();
+
+    terminated.or_else(|| {
+        status
+            .conditions
+            .iter()
+            .flatten()
+            .filter_map(|condition| condition.last_transition_time.as_ref())
+            .map(|time| time.0)
+            .max()
+    })
+}
+
+fn node_is_cordoned(node: &Node) -> bool {
+    node.spec
+        .as_ref()
+        .and_then(|spec| spec.unschedulable)
+        .unwrap_or(false)
+}
+
+/// Whether the node's `Ready` condition is `True`
+fn node_is_ready(node: &Node) -> bool {
+    node.status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| {
+            conditions
+                .iter()
+                .find(|condition| condition.type_ == "Ready")
+        })
+        .is_some_and(|ready| ready.status == "True")
+}
+
+/// When the node's `Ready` condition last turned `False` or `Unknown`, if it isn't `Ready` now.
+/// Nodes without a transition time are never considered.
+fn node_not_ready_since(node: &Node) -> Option<DateTime<Utc>> {
+    let ready = node
+        .status
+        .as_ref()?
+        .conditions
+        .as_ref()?
+        .iter()
+        .find(|condition| condition.type_ == "Ready")?;
+    if ready.status == "True" {
+        return None;
+    }
+    ready.last_transition_time.as_ref().map(|time| time.0)
+}
+
+/// Seconds since the pod became unschedulable: the `lastTransitionTime` of its
+/// `PodScheduled=False/Unschedulable` condition, or its creation time if that is absent
+fn pod_unschedulable_secs(pod: &Pod, now: DateTime<Utc>) -> Option<i64> {
+    let since = unschedulable_condition(pod)
+        .and_then(|cond| cond.last_transition_time.as_ref())
+        .or(pod.metadata.creation_timestamp.as_ref())?;
+    Some(now.signed_duration_since(since.0).num_seconds())
+}
+
+/// How long from `now` until an unschedulable pod crosses each configured threshold it hasn't
+/// reached yet, soonest first
+fn threshold_delays(pod: &Pod, config: &ReaperConfig, now: DateTime<Utc>) -> Vec<Duration> {
+    let Some(unschedulable_secs) = pod_unschedulable_secs(pod, now) else {
+        return Vec::new();
+    };
+    config
+        .unschedulable_thresholds()
+        .into_iter()
+        .filter_map(|threshold| {
+            let remaining = threshold as i64 - unschedulable_secs;
+            (remaining > 0).then(|| Duration::from_secs(remaining as u64))
+        })
+        .collect()
+}
+
+fn pod_exceeds_unschedulable_thresh(pod: &Pod, threshold: Duration, now: DateTime<Utc>) -> bool {
+    if !pod_is_pending(pod) {
+        return false;
+    }
+
+    pod_unschedulable_secs(pod, now).is_some_and(|secs| secs >= threshold.as_secs() as i64)
+}
+
+fn unschedulable_condition(pod: &Pod) -> Option<&PodCondition> {
+    pod.status
+        .as_ref()?
+        .conditions
+        .as_ref()?
+        .iter()
+        .find(|cond| {
+            cond.type_ == "PodScheduled"
+                && cond.status == "False"
+                && cond.reason.as_deref() == Some("Unschedulable")
+        })
+}
+
+fn pod_is_unschedulable(pod: &Pod) -> bool {
+    unschedulable_condition(pod).is_some()
+}
+
+/// The scheduler's explanation on the pod's `PodScheduled=False/Unschedulable` condition
+fn scheduling_message(pod: &Pod) -> Option<&str> {
+    unschedulable_condition(pod)?.message.as_deref()
+}
+
+/// When the scheduler last reported `FailedScheduling` for a pod, from the Events scoped to it
+async fn last_failed_scheduling(
+    client: &Client,
+    namespace: &str,
+    pod: &str,
+) -> Result<Option<DateTime<Utc>>> {
+    let params = ListParams::default().fields(&format!(
+        "involvedObject.kind=Pod,involvedObject.name={},reason=FailedScheduling",
+        pod
+    ));
+    let events = Api::<CoreEvent>::namespaced(client.clone(), namespace)
+        .list(&params)
+        .await
+        .inspect_err(|_| telemetry::api_error("events", "list"))
+        .context("Failed to list events")?;
+    Ok(events
+        .items
+        .iter()
+        .filter(|event| event.reason.as_deref() == Some("FailedScheduling"))
+        .filter_map(event_time)
+        .max())
+}
+
+/// When an Event last happened, from whichever of its timestamps the reporter filled in
+fn event_time(event: &CoreEvent) -> Option<DateTime<Utc>> {
+    let series = event
+        .series
+        .as_ref()
+        .and_then(|series| series.last_observed_time.as_ref())
+        .map(|time| time.0);
+    series
+        .or_else(|| event.last_timestamp.as_ref().map(|time| time.0))
+        .or_else(|| event.event_time.as_ref().map(|time| time.0))
+        .or_else(|| {
+            event
+                .metadata
+                .creation_timestamp
+                .as_ref()
+                .map(|time| time.0)
+        })
+}
+
+/// Whether the scheduling failure message points at the pod's volumes, per
+/// `--volume-failure-messages`. Pods without a message are not assumed to be volume-bound.
+fn is_volume_failure(pod: &Pod, config: &ReaperConfig) -> bool {
+    let Some(message) = scheduling_message(pod) else {
+        return false;
+    };
+
+    let message = message.to_lowercase();
+    config
+        .volume_failure_messages
+        .iter()
+        .any(|needle| message.contains(&needle.to_lowercase()))
+}
+
+/// Skip reason for a pod whose scheduling message doesn't blame its volumes, by
+/// `--pod-message-regex` when set and `--volume-failure-messages` otherwise
+fn unrelated_scheduling_failure(pod: &Pod, config: &ReaperConfig) -> Option<SkipReason> {
+    let message = scheduling_message(pod).unwrap_or_default();
+    let regex = config
+        .pod_message_regex
+        .as_ref()
+        .filter(|regex| !regex.as_str().is_empty());
+    let (pod_name, message) = (pod.name_any(), message.to_string());
+    match regex {
+        Some(regex) if !regex.is_match(&message) => Some(SkipReason::MessageNotMatched {
+            pod: pod_name,
+            message,
+        }),
+        None if !is_volume_failure(pod, config) => Some(SkipReason::NotVolumeRelated {
+            pod: pod_name,
+            message,
+        }),
+        _ => None,
+    }
+}
+
+/// Whether the pod's own scheduling constraints (nodeName, nodeSelector, required node
+/// affinity) allow it to land on `node`. Pods without hostname constraints target any node.
+fn pod_targets_node(pod: &Pod, node: &str) -> bool {
+    let Some(spec) = pod.spec.as_ref() else {
+        return true;
+    };
+
+    if spec.node_name.as_deref().is_some_and(|name| name != node) {
+        return false;
+    }
+
+    if spec
+        .node_selector
+        .as_ref()
+        .and_then(|selector| selector.get(HOSTNAME_LABEL))
+        .is_some_and(|hostname| hostname != node)
+    {
+        return false;
+    }
+
+    let terms = spec
+        .affinity
+        .as_ref()
+        .and_then(|a| a.node_affinity.as_ref())
+        .and_then(|na| {
+            na.required_during_scheduling_ignored_during_execution
+                .as_ref()
+        })
+        .map(|selector| selector.node_selector_terms.as_slice())
+        .unwrap_or_default();
+
+    // Terms are ORed; expressions within a term are ANDed
+    terms.is_empty()
+        || terms.iter().any(|term| {
+            term.match_expressions
+                .iter()
+                .flatten()
+                .filter(|expr| expr.key == HOSTNAME_LABEL)
+                .all(|expr| {
+                    let listed = expr.values.iter().flatten().any(|value| value == node);
+                    match expr.operator.as_str() {
+                        "In" => listed,
+                        "NotIn" => !listed,
+                        _ => true,
+                    }
+                })
+        })
+}
+
+/// The hostname a PV's required nodeAffinity pins it to. Expressions on other topology keys
+/// are ignored, and PVs allowing more than one hostname aren't pinned to any single node.
+fn pv_pinned_hostname(pv: &PersistentVolume) -> Option<&str> {
+    let terms = &pv
+        .spec
+        .as_ref()?
+        .node_affinity
+        .as_ref()?
+        .required
+        .as_ref()?
+        .node_selector_terms;
+    let hostnames: BTreeSet<&str> = terms
+        .iter()
+        .flat_map(|term| term.match_expressions.iter().flatten())
+        .filter(|expr| expr.key == HOSTNAME_LABEL && expr.operator == "In")
+        .flat_map(|expr| expr.values.iter().flatten().map(String::as_str))
+        .collect();
+
+    let mut hostnames = hostnames.into_iter();
+    match (hostnames.next(), hostnames.next()) {
+        (Some(hostname), None) => Some(hostname),
+        (Some(_), Some(_)) => {
+            debug!(
+                pv = %pv.name_any(),
+                "PV nodeAffinity allows several hostnames, not treating it as pinned"
+            );
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Names of the PVCs a pod mounts, including the `<pod>-<volume>` claims created for generic
+/// ephemeral volumes
+fn get_pod_pvc_names(pod: &Pod) -> Vec<String> {
+    let pod_name = pod.name_any();
+    pod.spec
+        .as_ref()
+        .and_then(|s| s.volumes.as_ref())
+        .map(|volumes| {
+            volumes
+                .iter()
+                .filter_map(|v| match (&v.persistent_volume_claim, &v.ephemeral) {
+                    (Some(pvc), _) => Some(pvc.claim_name.clone()),
+                    (None, Some(_)) => Some(format!("{}-{}", pod_name, v.name)),
+                    (None, None) => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// What a delete request found on the API server
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteOutcome {
+    Deleted,
+    /// The PVC was gone before the request arrived, e.g. deleted by hand since it was listed
+    AlreadyGone,
+    /// The name now belongs to a different object than the one evaluated, e.g. a claim its
+    /// StatefulSet re-created since it was listed, so the UID precondition refused the delete
+    Replaced,
+}
+
+/// What a PVC fetched again right before its delete turned out to be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Revalidation {
+    /// The claim that was evaluated, still qualifying
+    Current,
+    /// Deleted or terminating since it was listed
+    Gone,
+    /// A different claim under the same name
+    Replaced,
+    /// The same claim, but it no longer qualifies, e.g. it was annotated as protected since it
+    /// was listed
+    Changed,
+}
+
+/// Delete options for a PVC; with the UID it was evaluated under as a precondition, the API
+/// server refuses to delete a claim re-created under the same name in the meantime
+fn pvc_delete_params(uid: Option<String>, dry_run: bool) -> DeleteParams {
+    DeleteParams {
+        dry_run,
+        preconditions: uid.map(|uid| Preconditions {
+            uid: Some(uid),
+            resource_version: None,
+        }),
+        ..Default::default()
+    }
+}
+
+#[instrument(level = "debug", skip(client))]
+pub async fn delete_pvc(
+    client: &Client,
+    namespace: &str,
+    name: &str,
+    uid: Option<String>,
+    dry_run: bool,
+) -> Result<DeleteOutcome> {
+    let guarded = uid.is_some();
+    let start = Instant::now();
+    let result = Api::<PersistentVolumeClaim>::namespaced(client.clone(), namespace)
+        .delete(name, &pvc_delete_params(uid, dry_run))
+        .await;
+    telemetry::delete_duration(start.elapsed());
+
+    match result {
+        Ok(_) => Ok(DeleteOutcome::Deleted),
+        Err(kube::Error::Api(e)) if e.code == 404 => Ok(DeleteOutcome::AlreadyGone),
+        Err(kube::Error::Api(e)) if e.code == 409 && guarded => Ok(DeleteOutcome::Replaced),
+        Err(e) => {
+            telemetry::api_error("persistentvolumeclaims", "delete");
+            Err(e).context("Failed to delete PVC")
+        }
+    }
+}
+
+/// A new claim with the reaped PVC's name, labels, owners and spec, minus anything tying it to
+/// its old node or volume
+fn fresh_claim(pvc: &PersistentVolumeClaim, config: &ReaperConfig) -> PersistentVolumeClaim {
+    let mut annotations = pvc.annotations().clone();
+    annotations.retain(|key, _| {
+        !config.selected_node_annotation.contains(key)
+            && key != PROVISIONER_ANNOTATION
+            && key != BETA_PROVISIONER_ANNOTATION
+            && !key.starts_with("pv.kubernetes.io/")
+    });
+    let mut spec = pvc.spec.clone();
+    if let Some(spec) = spec.as_mut() {
+        spec.volume_name = None;
+    }
+
+    PersistentVolumeClaim {
+        metadata: ObjectMeta {
+            name: pvc.metadata.name.clone(),
+            namespace: pvc.metadata.namespace.clone(),
+            labels: pvc.metadata.labels.clone(),
+            annotations: (!annotations.is_empty()).then_some(annotations),
+            owner_references: pvc.metadata.owner_references.clone(),
+            ..Default::default()
+        },
+        spec,
+        status: None,
+    }
+}
+
+/// Wait up to `timeout` for the deleted `old` claim to disappear, then create `fresh`. A claim
+/// its controller re-created in the meantime counts as success.
+pub async fn recreate_pvc(
+    client: &Client,
+    old: &PersistentVolumeClaim,
+    fresh: &PersistentVolumeClaim,
+    timeout: Duration,
+) -> Result<()> {
+    let api = Api::<PersistentVolumeClaim>::namespaced(
+        client.clone(),
+        &old.namespace().unwrap_or_default(),
+    );
+    let name = old.name_any();
+    let deadline = Instant::now() + timeout;
+    loop {
+        let current = api
+            .get_opt(&name)
+            .await
+            .inspect_err(|_| telemetry::api_error("persistentvolumeclaims", "get"))
+            .context("Failed to get PVC")?;
+        match current {
+            None => break,
+            Some(current) if current.uid() != old.uid() => return Ok(()),
+            Some(_) if Instant::now() >= deadline => {
+                anyhow::bail!("PVC still exists after {}s", timeout.as_secs())
+            }
+            Some(_) => tokio::time::sleep(RECREATE_POLL_INTERVAL).await,
+        }
+    }
+
+    match api.create(&PostParams::default(), fresh).await {
+        Ok(_) => Ok(()),
+        Err(kube::Error::Api(e)) if e.code == 409 => Ok(()),
+        Err(e) => {
+            telemetry::api_error("persistentvolumeclaims", "create");
+            Err(e).context("Failed to create PVC")
+        }
+    }
+}
+
+/// Delete the PV a reaped PVC was bound to, logging and counting failures; they aren't retried
+/// since the PVC is gone
+async fn delete_reaped_pv(
+    client: &Client,
+    config: &ReaperConfig,
+    pvc: &PersistentVolumeClaim,
+    result: &mut ReapResult,
+) {
+    let (Some(volume), Some(claim_uid)) = (
+        pvc.spec
+            .as_ref()
+            .and_then(|spec| spec.volume_name.as_deref()),
+        pvc.uid(),
+    ) else {
+        return;
+    };
+    if config.dry_run {
+        info!(pv = %volume, pvc = %pvc.name_any(), dry_run = true, "[DRY RUN] Would delete bound PV");
+        return;
+    }
+
+    match delete_bound_pv(client, volume, &claim_uid).await {
+        Ok(DeleteOutcome::Deleted) => {
+            info!(pv = %volume, pvc = %pvc.name_any(), "Deleted bound PV");
+            result.bound_pvs_deleted += 1;
+        }
+        Ok(DeleteOutcome::AlreadyGone | DeleteOutcome::Replaced) => {}
+        Err(e) => {
+            error!(pv = %volume, pvc = %pvc.name_any(), "Failed to delete bound PV: {:#}", e);
+            result.bound_pv_failures += 1;
+        }
+    }
+}
+
+/// Delete PV `name` if its claimRef is still the claim with UID `claim_uid`. The delete is
+/// conditional on the resourceVersion that was checked, so a concurrent rebind makes it fail.
+#[instrument(level = "debug", skip(client))]
+pub async fn delete_bound_pv(
+    client: &Client,
+    name: &str,
+    claim_uid: &str,
+) -> Result<DeleteOutcome> {
+    let api = Api::<PersistentVolume>::all(client.clone());
+    let Some(pv) = api
+        .get_opt(name)
+        .await
+        .inspect_err(|_| telemetry::api_error("persistentvolumes", "get"))
+        .context("Failed to get PV")?
+    else {
+        return Ok(DeleteOutcome::AlreadyGone);
+    };
+
+    let bound_uid = pv
+        .spec
+        .as_ref()
+        .and_then(|spec| spec.claim_ref.as_ref())
+        .and_then(|claim| claim.uid.as_deref());
+    if bound_uid != Some(claim_uid) {
+        anyhow::bail!(
+            "PV {} is bound to claim {}, not the reaped claim {}",
+            name,
+            bound_uid.unwrap_or("<none>"),
+            claim_uid
+        );
+    }
+
+    let params = DeleteParams {
+        preconditions: Some(Preconditions {
+            resource_version: pv.resource_version(),
+            uid: pv.uid(),
+        }),
+        ..Default::default()
+    };
+    match api.delete(name, &params).await {
+        Ok(_) => Ok(DeleteOutcome::Deleted),
+        Err(kube::Error::Api(e)) if e.code == 404 => Ok(DeleteOutcome::AlreadyGone),
+        Err(e) => {
+            telemetry::api_error("persistentvolumes", "delete");
+            Err(e).context("Failed to delete PV")
+        }
+    }
+}
+
+fn lvmvolume_resource() -> ApiResource {
+    ApiResource::from_gvk(&GroupVersionKind::gvk(
+        LVMVOLUME_GROUP,
+        LVMVOLUME_VERSION,
+        LVMVOLUME_KIND,
+    ))
+}
+
+/// A non-empty `spec.ownerNodeID` of an OpenEBS LVMVolume
+fn lvmvolume_owner(volume: &DynamicObject) -> Option<&str> {
+    let node = volume.data.get("spec")?.get("ownerNodeID")?.as_str()?;
+    (!node.is_empty()).then_some(node)
+}
+
+/// List the OpenEBS LVMVolumes for `--cleanup-openebs-lvmvolumes`. A missing CRD is logged once
+/// and disables the pass; other failures leave the list empty until the next cycle.
+async fn list_lvmvolumes(client: &Client, tracker: &mut ReapTracker) -> Vec<DynamicObject> {
+    match Api::<DynamicObject>::all_with(client.clone(), &lvmvolume_resource())
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(list) => list.items,
+        Err(kube::Error::Api(e)) if e.code == 404 => {
+            warn!(
+                "{}/{} CRD is not installed, disabling --cleanup-openebs-lvmvolumes",
+                LVMVOLUME_GROUP, LVMVOLUME_KIND
+            );
+            tracker.lvmvolume_crd_missing = true;
+            vec![]
+        }
+        Err(e) => {
+            telemetry::api_error("lvmvolumes", "list");
+            error!("Failed to list LVMVolumes: {:#}", e);
+            vec![]
+        }
+    }
+}
+
+/// Delete object `name` through `api`; one that is already gone counts as `AlreadyGone`
+#[instrument(level = "debug", skip(api))]
+async fn delete_object<K>(api: Api<K>, resource: &'static str, name: &str) -> Result<DeleteOutcome>
+where
+    K: Resource + Clone + DeserializeOwned + fmt::Debug,
+    K::DynamicType: Default,
+{
+    match api.delete(name, &DeleteParams::default()).await {
+        Ok(_) => Ok(DeleteOutcome::Deleted),
+        Err(kube::Error::Api(e)) if e.code == 404 => Ok(DeleteOutcome::AlreadyGone),
+        Err(e) => {
+            telemetry::api_error(resource, "delete");
+            Err(e).with_context(|| format!("Failed to delete {}", K::kind(&Default::default())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::{
+        api::core::v1::{
+            NodeCondition, NodeSpec, NodeStatus, PersistentVolumeClaimVolumeSource, PodStatus,
+            Volume,
+        },
+        apimachinery::pkg::apis::meta::v1::Time,
+    };
+
+    fn test_pvc(
+        name: &str,
+        storage_class: &str,
+        provisioner: &str,
+        selected_node: Option<&str>,
+    ) -> PersistentVolumeClaim {
         let mut annotations = std::collections::BTreeMap::new();
-        annotations.insert(PROVISIONER_ANNOTATION.to_string(), provisioner.to_string());
+        annotations.insert(
+            BETA_PROVISIONER_ANNOTATION.to_string(),
+            provisioner.to_string(),
+        );
         if let Some(node) = selected_node {
             annotations.insert(SELECTED_NODE_ANNOTATION.to_string(), node.to_string());
         }
 
-        PersistentVolumeClaim {
-            metadata: ObjectMeta {
-                name: Some(name.to_string()),
-                namespace: Some("default".to_string()),
-                annotations: Some(annotations),
-                ..Default::default()
-            },
-            spec: Some(k8s_openapi::api::core::v1::PersistentVolumeClaimSpec {
-                storage_class_name: Some(storage_class.to_string()),
-                ..Default::default()
-            }),
-            ..Default::default()
-        }
+        PersistentVolumeClaim {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                annotations: Some(annotations),
+                ..Default::default()
+            },
+            spec: Some(k8s_openapi::api::core::v1::PersistentVolumeClaimSpec {
+                storage_class_name: Some(storage_class.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn with_storage_request(
+        mut pvc: PersistentVolumeClaim,
+        storage: &str,
+    ) -> PersistentVolumeClaim {
+        pvc.spec.as_mut().unwrap().resources =
+            Some(k8s_openapi::api::core::v1::VolumeResourceRequirements {
+                requests: Some(std::collections::BTreeMap::from([(
+                    "storage".to_string(),
+                    k8s_openapi::apimachinery::pkg::api::resource::Quantity(storage.to_string()),
+                )])),
+                ..Default::default()
+            });
+        pvc
+    }
+
+    fn test_config() -> ReaperConfig {
+        ReaperConfig {
+            storage_classes: vec![StorageClassPattern::glob("openebs-lvm").unwrap()],
+            storage_class_regex: vec![],
+            storage_provisioner: vec!["local.csi.openebs.io".to_string()],
+            selected_node_annotation: vec![SELECTED_NODE_ANNOTATION.to_string()],
+            pvc_annotation_selector: vec![],
+            reap_interval_secs: 60,
+            confirmation_cycles: 1,
+            redelete_cooldown_secs: 600,
+            node_missing_cycles: 1,
+            max_deletions_per_cycle: 0,
+            max_deletions_per_namespace: 0,
+            deletions_per_minute: None,
+            max_deletion_fraction: None,
+            max_deletion_fraction_floor: 3,
+            min_ready_nodes: 1,
+            min_ready_node_fraction: None,
+            interactive: false,
+            soft_delete_delay_secs: None,
+            lookup_storage_classes: true,
+            match_default_storage_class: false,
+            pvc_phases: vec!["Pending".to_string(), "Bound".to_string()],
+            include_system_namespaces: false,
+            reaper_namespace: Some("pvc-reaper".to_string()),
+            namespace_label_selector: None,
+            min_pvc_size: None,
+            max_pvc_size: None,
+            skip_owner_kinds: vec![],
+            controller_owned_pods_only: true,
+            controller_owner_kinds: ["StatefulSet", "ReplicaSet", "Job", "DaemonSet"]
+                .map(String::from)
+                .to_vec(),
+            include_namespaces: vec![],
+            namespaces: vec![],
+            contexts: vec![],
+            exclude_namespaces: vec![],
+            protection_annotation: DEFAULT_PROTECTION_ANNOTATION.to_string(),
+            require_opt_in: false,
+            opt_in_annotation: DEFAULT_OPT_IN_ANNOTATION.to_string(),
+            namespace_annotation: DEFAULT_OPT_IN_ANNOTATION.to_string(),
+            require_namespace_opt_in: false,
+            dry_run: false,
+            dry_run_mode: DryRunMode::Client,
+            output: OutputFormat::Text,
+            explain: false,
+            check_unschedulable_pods: true,
+            scheduling_event_max_age_secs: 0,
+            unschedulable_pod_threshold_secs: 120,
+            class_thresholds: vec![],
+            volume_failure_messages: [
+                "volume node affinity conflict",
+                "persistentvolumeclaim",
+                "persistent volumes to bind",
+            ]
+            .map(String::from)
+            .to_vec(),
+            pod_message_regex: None,
+            node_not_ready_threshold_secs: None,
+            reap_on_cordoned_nodes: false,
+            cordoned_threshold_secs: 3600,
+            reap_node_taints: vec![],
+            watch_nodes: true,
+            watch_pods: false,
+            reap_unreferenced_missing_node_pvcs: false,
+            unreferenced_missing_node_min_age_secs: 21600,
+            require_both_conditions: false,
+            max_consecutive_failures: 10,
+            reap_scaled_down_statefulsets: false,
+            scaled_down_grace_secs: 3600,
+            reap_orphaned_workload_pvcs: false,
+            orphaned_workload_min_age_secs: 86400,
+            reap_finished_consumer_pvcs: false,
+            finished_pod_threshold_secs: 86400,
+            delete_stuck_pods: false,
+            delete_bound_pv: false,
+            recreate_pvc: false,
+            recreate_pvc_timeout_secs: 60,
+            force_remove_finalizers_after_secs: None,
+            cleanup_released_pvs: false,
+            released_pv_min_age_secs: 3600,
+            cleanup_volume_attachments: false,
+            volume_attachment_min_age_secs: 900,
+            cleanup_openebs_lvmvolumes: false,
+            pause_configmap_name: None,
+            pause_configmap_namespace: "pvc-reaper".to_string(),
+            pause_mode: PauseMode::Skip,
+            metrics_addr: "0.0.0.0:9090".parse().unwrap(),
+            metrics_max_namespaces: 100,
+            reconcile_token: None,
+            log_format: LogFormat::Text,
+            otlp_endpoint: None,
+            audit_log_path: None,
+            backup_dir: None,
+            backup_configmap_namespace: None,
+            backup_configmap_name: "pvc-reaper-backups".to_string(),
+            backup_configmap_max_entries: 200,
+            backup_failure_policy: BackupFailurePolicy::Block,
+            notify_webhook_url: None,
+            slack_webhook_url: None,
+            once: false,
+            pushgateway_url: None,
+            pushgateway_job: "pvc-reaper".to_string(),
+            pushgateway_instance: None,
+            command: None,
+        }
+    }
+
+    fn pause_configmap(paused: &str) -> ConfigMap {
+        ConfigMap {
+            data: Some(std::collections::BTreeMap::from([(
+                PAUSED_KEY.to_string(),
+                paused.to_string(),
+            )])),
+            ..Default::default()
+        }
+    }
+
+    fn state_with(node_names: &[&str], pods: Vec<Pod>, pvcs: Vec<PersistentVolumeClaim>) -> State {
+        let nodes = node_names
+            .iter()
+            .map(|name| Node {
+                metadata: ObjectMeta {
+                    name: Some((*name).to_string()),
+                    ..Default::default()
+                },
+                status: Some(k8s_openapi::api::core::v1::NodeStatus {
+                    conditions: Some(vec![k8s_openapi::api::core::v1::NodeCondition {
+                        type_: "Ready".to_string(),
+                        status: "True".to_string(),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        State {
+            node_names: node_names.iter().map(|s| s.to_string()).collect(),
+            nodes_listed: true,
+            nodes,
+            pods,
+            pvcs,
+            pvs: vec![],
+            volume_attachments: vec![],
+            lvmvolumes: vec![],
+            storage_class_provisioners: HashMap::new(),
+            wait_for_first_consumer_classes: HashSet::new(),
+            default_storage_class: None,
+            statefulsets: vec![],
+            selected_namespaces: None,
+            terminating_namespaces: HashSet::new(),
+            enabled_namespaces: None,
+            cordoned_since: HashMap::new(),
+            missing_nodes: HashMap::new(),
+            target: None,
+            now: Utc::now(),
+        }
+    }
+
+    fn pod_with_pvc(
+        pod_name: &str,
+        pvc_name: &str,
+        phase: &str,
+        condition_reason: Option<&str>,
+        creation_offset_secs: i64,
+    ) -> Pod {
+        let created = Time(chrono::Utc::now() - chrono::Duration::seconds(creation_offset_secs));
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(pod_name.to_string()),
+                namespace: Some("default".to_string()),
+                creation_timestamp: Some(created.clone()),
+                owner_references: Some(vec![OwnerReference {
+                    api_version: "apps/v1".to_string(),
+                    kind: "StatefulSet".to_string(),
+                    name: "sts".to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            spec: Some(k8s_openapi::api::core::v1::PodSpec {
+                volumes: Some(vec![Volume {
+                    name: "data".to_string(),
+                    persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
+                        claim_name: pvc_name.to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some(phase.to_string()),
+                conditions: condition_reason.map(|reason| {
+                    vec![PodCondition {
+                        type_: "PodScheduled".to_string(),
+                        status: "False".to_string(),
+                        reason: Some(reason.to_string()),
+                        message: Some(
+                            "0/3 nodes are available: 3 node(s) had volume node affinity conflict."
+                                .to_string(),
+                        ),
+                        last_transition_time: Some(created.clone()),
+                        ..Default::default()
+                    }]
+                }),
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Set (or clear) when the pod's PodScheduled condition last transitioned
+    fn with_unschedulable_since(mut pod: Pod, offset_secs: Option<i64>) -> Pod {
+        let condition = &mut pod.status.as_mut().unwrap().conditions.as_mut().unwrap()[0];
+        condition.last_transition_time =
+            offset_secs.map(|offset| Time(chrono::Utc::now() - chrono::Duration::seconds(offset)));
+        pod
+    }
+
+    #[test]
+    fn test_matches_storage_criteria() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        assert!(matches_storage_criteria(&pvc, &test_config()));
+    }
+
+    #[test]
+    fn test_matches_storage_criteria_multiple_classes() {
+        let pvc = test_pvc(
+            "test",
+            "local-storage",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let mut config = test_config();
+        config.storage_classes = vec![
+            StorageClassPattern::glob("openebs-lvm").unwrap(),
+            StorageClassPattern::glob("local-storage").unwrap(),
+        ];
+        assert!(matches_storage_criteria(&pvc, &config));
+    }
+
+    #[test]
+    fn test_pod_unschedulable_long_enough_with_unschedulable_condition() {
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+        assert!(pod_exceeds_unschedulable_thresh(
+            &pod,
+            Duration::from_secs(120),
+            Utc::now()
+        ));
+    }
+
+    #[test]
+    fn test_unschedulable_window_starts_at_condition_transition() {
+        // Ran for days, only became unschedulable a minute ago when its node died
+        let pod = with_unschedulable_since(
+            pod_with_pvc(
+                "old-pod",
+                "test",
+                "Pending",
+                Some("Unschedulable"),
+                86400 * 3,
+            ),
+            Some(60),
+        );
+        assert!(!pod_exceeds_unschedulable_thresh(
+            &pod,
+            Duration::from_secs(120),
+            Utc::now()
+        ));
+        let secs = pod_unschedulable_secs(&pod, Utc::now()).unwrap();
+        assert!((60..120).contains(&secs), "{secs}");
+
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let state = state_with(&["node-1"], vec![pod.clone()], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        // Without a transition time the creation timestamp is used
+        let pod = with_unschedulable_since(pod, None);
+        assert!(pod_exceeds_unschedulable_thresh(
+            &pod,
+            Duration::from_secs(120),
+            Utc::now()
+        ));
+
+        // A pod created long ago that only just reached scheduling isn't stale either
+        let backoff = with_unschedulable_since(
+            pod_with_pvc("backoff-pod", "test", "Pending", Some("Unschedulable"), 600),
+            Some(30),
+        );
+        assert!(!pod_exceeds_unschedulable_thresh(
+            &backoff,
+            Duration::from_secs(120),
+            Utc::now()
+        ));
+    }
+
+    #[test]
+    fn test_pod_unschedulable_not_long_enough() {
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 60);
+        assert!(!pod_exceeds_unschedulable_thresh(
+            &pod,
+            Duration::from_secs(120),
+            Utc::now()
+        ));
+    }
+
+    #[test]
+    fn test_deletion_reason_when_node_missing() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("missing-node"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+
+        let state = state_with(&[], vec![pod], vec![pvc.clone()]);
+
+        let reason = state
+            .deletion_reason(&pvc, &test_config())
+            .expect("expected deletion reason");
+
+        match reason {
+            DeleteReason::MissingNode { node, pod, .. } => {
+                assert_eq!(node, "missing-node");
+                assert_eq!(pod, "pending-pod");
+            }
+            _ => panic!("expected missing node reason"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_failed_scheduling_event() {
+        let config = ReaperConfig {
+            dry_run: true,
+            scheduling_event_max_age_secs: 600,
+            ..test_config()
+        };
+        let state = || {
+            state_with(
+                &["node-1"],
+                vec![pod_with_pvc(
+                    "pending-pod",
+                    "test",
+                    "Pending",
+                    Some("Unschedulable"),
+                    3600,
+                )],
+                vec![test_pvc(
+                    "test",
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("node-1"),
+                )],
+            )
+        };
+        let reap = |events: Vec<serde_json::Value>| {
+            let state = state();
+            let config = config.clone();
+            async move {
+                let (client, _) = mock_api(move |method, path| match (method, path) {
+                    ("GET", "/api/v1/namespaces/default/events") => (
+                        200,
+                        serde_json::json!({
+                            "apiVersion": "v1",
+                            "kind": "EventList",
+                            "metadata": {},
+                            "items": events,
+                        }),
+                    ),
+                    _ => (200, serde_json::json!({})),
+                });
+                state
+                    .reap(&client, &config, &mut ReapTracker::default())
+                    .await
+                    .unwrap()
+            }
+        };
+        let event = |reason: &str, age_secs: i64| {
+            serde_json::json!({
+                "metadata": {"name": format!("pending-pod.{}", age_secs), "namespace": "default"},
+                "involvedObject": {"kind": "Pod", "name": "pending-pod", "namespace": "default"},
+                "reason": reason,
+                "lastTimestamp": (chrono::Utc::now() - chrono::Duration::seconds(age_secs))
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            })
+        };
+
+        let result = reap(vec![
+            event("FailedScheduling", 3000),
+            event("FailedScheduling", 60),
+        ])
+        .await;
+        assert_eq!(result.would_delete_count, 1);
+
+        let result = reap(vec![event("FailedScheduling", 3000)]).await;
+        assert_eq!(result.would_delete_count, 0);
+        assert!(matches!(
+            result.skipped[0].reason,
+            SkipReason::StaleSchedulingEvent {
+                last_seen: Some(_),
+                ..
+            }
+        ));
+
+        // Events of other kinds don't count
+        let result = reap(vec![event("Scheduled", 60)]).await;
+        assert!(matches!(
+            result.skipped[0].reason,
+            SkipReason::StaleSchedulingEvent {
+                last_seen: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_deletion_reason_when_unschedulable_too_long() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 601);
+
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+
+        let reason = state
+            .deletion_reason(&pvc, &test_config())
+            .expect("expected deletion reason");
+
+        match reason {
+            DeleteReason::UnschedulableTooLong { pod, .. } => assert_eq!(pod, "pending-pod"),
+            _ => panic!("expected pending too long reason"),
+        }
+    }
+
+    #[test]
+    fn test_deletion_reason_skips_when_pod_not_unschedulable() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("OtherReason"), 600);
+
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+
+        assert!(matches!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::PodNotUnschedulable { .. })
+        ));
+    }
+
+    #[test]
+    fn test_configmap_paused() {
+        assert!(configmap_is_paused(Some(&pause_configmap("true"))));
+        assert!(configmap_is_paused(Some(&pause_configmap(" TRUE "))));
+    }
+
+    #[test]
+    fn test_configmap_unpaused() {
+        assert!(!configmap_is_paused(Some(&pause_configmap("false"))));
+        assert!(!configmap_is_paused(Some(&ConfigMap::default())));
+    }
+
+    #[tokio::test]
+    async fn test_is_paused() {
+        let mut config = test_config();
+        let mut tracker = ReapTracker::default();
+        let (client, requests) = mock_api(|_, _| (200, serde_json::json!({})));
+        assert!(!is_paused(&client, &config, &mut tracker).await.unwrap());
+        assert!(requests.lock().unwrap().is_empty());
+
+        config.pause_configmap_name = Some("kill-switch".to_string());
+        config.pause_configmap_namespace = "ops".to_string();
+        let (client, requests) = mock_api(|_, _| {
+            (
+                404,
+                serde_json::json!({"kind": "Status", "apiVersion": "v1", "status": "Failure", "reason": "NotFound", "code": 404}),
+            )
+        });
+        assert!(!is_paused(&client, &config, &mut tracker).await.unwrap());
+        assert!(tracker.pause_configmap_missing);
+        assert_eq!(
+            requests.lock().unwrap()[0],
+            "GET /api/v1/namespaces/ops/configmaps/kill-switch"
+        );
+
+        let (client, _) =
+            mock_api(|_, _| (200, serde_json::to_value(pause_configmap("true")).unwrap()));
+        assert!(is_paused(&client, &config, &mut tracker).await.unwrap());
+        assert!(!tracker.pause_configmap_missing);
+    }
+
+    #[test]
+    fn test_kill_switch_config() {
+        let config = ReaperConfig {
+            dry_run_mode: DryRunMode::Server,
+            ..test_config()
+        };
+        let forced = kill_switch_config(&config);
+        assert!(forced.dry_run);
+        assert_eq!(forced.dry_run_mode, DryRunMode::Client);
+    }
+
+    #[test]
+    fn test_configmap_missing_is_not_paused() {
+        assert!(!configmap_is_paused(None));
+    }
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("5m"), Ok(300));
+        assert_eq!(parse_duration_secs("1h30m"), Ok(5400));
+        assert_eq!(parse_duration_secs("90s"), Ok(90));
+        assert_eq!(parse_duration_secs("300"), Ok(300));
+        assert!(parse_duration_secs("five minutes").is_err());
+    }
+
+    fn pin_pod_to_node(pod: &mut Pod, node: &str) {
+        pod.spec.as_mut().unwrap().node_selector = Some(std::collections::BTreeMap::from([(
+            HOSTNAME_LABEL.to_string(),
+            node.to_string(),
+        )]));
+    }
+
+    #[test]
+    fn test_deletion_reason_skips_when_pod_pinned_elsewhere() {
+        // The pod wants node-2, which is out of CPU; the claim's node being gone is not
+        // what keeps it pending.
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("missing-node"),
+        );
+        let mut pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+        pin_pod_to_node(&mut pod, "node-2");
+
+        let state = state_with(&["node-2"], vec![pod], vec![pvc.clone()]);
+
+        assert!(matches!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::PodTargetsOtherNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_deletion_reason_when_pod_pinned_to_missing_node() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("missing-node"),
+        );
+        let mut pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+        pin_pod_to_node(&mut pod, "missing-node");
+
+        let state = state_with(&["node-2"], vec![pod], vec![pvc.clone()]);
+
+        assert!(matches!(
+            state.deletion_reason(&pvc, &test_config()),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_pod_targets_node_affinity() {
+        use k8s_openapi::api::core::v1::{
+            Affinity, NodeAffinity, NodeSelector, NodeSelectorRequirement, NodeSelectorTerm,
+        };
+
+        let mut pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+        pod.spec.as_mut().unwrap().affinity = Some(Affinity {
+            node_affinity: Some(NodeAffinity {
+                required_during_scheduling_ignored_during_execution: Some(NodeSelector {
+                    node_selector_terms: vec![NodeSelectorTerm {
+                        match_expressions: Some(vec![NodeSelectorRequirement {
+                            key: HOSTNAME_LABEL.to_string(),
+                            operator: "In".to_string(),
+                            values: Some(vec!["node-1".to_string(), "node-2".to_string()]),
+                        }]),
+                        ..Default::default()
+                    }],
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        assert!(pod_targets_node(&pod, "node-1"));
+        assert!(!pod_targets_node(&pod, "node-3"));
+    }
+
+    fn with_uid(mut pvc: PersistentVolumeClaim, uid: &str) -> PersistentVolumeClaim {
+        pvc.metadata.uid = Some(uid.to_string());
+        pvc
+    }
+
+    #[test]
+    fn test_tracker_suppresses_in_flight_delete() {
+        let pvc = with_uid(
+            test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let mut tracker = ReapTracker::default();
+        tracker.record_delete(&pvc, chrono::Utc::now());
+
+        tracker.prune(std::slice::from_ref(&pvc));
+        assert!(tracker.delete_in_flight(&pvc));
+    }
+
+    #[test]
+    fn test_tracker_treats_recreated_pvc_fresh() {
+        let old = with_uid(
+            test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let new = with_uid(old.clone(), "uid-2");
+        let mut tracker = ReapTracker::default();
+        tracker.record_delete(&old, chrono::Utc::now());
+
+        assert!(!tracker.delete_in_flight(&new));
+
+        tracker.prune(std::slice::from_ref(&new));
+        assert!(tracker.deletes_in_flight.is_empty());
+    }
+
+    #[test]
+    fn test_redelete_cooldown() {
+        let old = with_uid(
+            test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let new = with_uid(old.clone(), "uid-2");
+        let deleted_at = chrono::Utc::now();
+        let mut tracker = ReapTracker::default();
+        tracker.record_delete(&old, deleted_at);
+
+        let until = deleted_at + chrono::Duration::seconds(600);
+        assert_eq!(tracker.cooldown_until(&new, 600, deleted_at), Some(until));
+        // The same object is DeleteInFlight's business, not the cooldown's
+        assert_eq!(tracker.cooldown_until(&old, 600, deleted_at), None);
+        assert_eq!(tracker.cooldown_until(&new, 600, until), None);
+        assert_eq!(tracker.cooldown_until(&new, 0, deleted_at), None);
+
+        tracker.prune_cooldowns(600, deleted_at);
+        assert_eq!(tracker.recently_deleted.len(), 1);
+        tracker.prune_cooldowns(600, until);
+        assert!(tracker.recently_deleted.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_recreated_pvc_skipped_during_cooldown() {
+        let config = test_config();
+        let state = |uid: &str| {
+            state_with(
+                &["node-1"],
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![with_uid(
+                    test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone")),
+                    uid,
+                )],
+            )
+        };
+        let mut tracker = ReapTracker::default();
+
+        let (client, _) = mock_api(serving_pvc(&state("uid-1").pvcs[0]));
+        let result = state("uid-1")
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        assert_eq!(result.deleted_count, 1);
+
+        // Its StatefulSet re-created it, and the new pod is no better off yet
+        let result = state("uid-2")
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        assert_eq!(result.deleted_count, 0);
+        assert_eq!(result.skipped[0].reason.variant(), "RedeleteCooldown");
+
+        let mut later = state("uid-2");
+        later.now += chrono::Duration::seconds(601);
+        let (client, _) = mock_api(serving_pvc(&later.pvcs[0]));
+        let result = later.reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!(result.deleted_count, 1);
+    }
+
+    #[test]
+    fn test_deletion_event() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let reason = DeleteReason::UnschedulableTooLong {
+            pod: "pending-pod".to_string(),
+            matched: None,
+        };
+
+        let mut config = test_config();
+        let event = deletion_event(&pvc, &reason, &config);
+        assert_eq!(event.type_, EventType::Warning);
+        assert_eq!(event.reason, REAPED_EVENT_REASON);
+        let note = event.note.unwrap();
+        assert!(note.contains("pending-pod"));
+        assert!(note.contains("node-1"));
+
+        config.dry_run = true;
+        let event = deletion_event(&pvc, &reason, &config);
+        assert_eq!(event.type_, EventType::Normal);
+        assert_eq!(event.reason, CANDIDATE_EVENT_REASON);
+    }
+
+    #[test]
+    fn test_reap_result_counts_by_reason() {
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None);
+        let config = test_config();
+        let dry_run = ReaperConfig {
+            dry_run: true,
+            ..test_config()
+        };
+        let mut result = ReapResult::default();
+        result.record_deletion(
+            &pvc,
+            DeleteReason::MissingNode {
+                node: "node-1".to_string(),
+                pod: "pod-a".to_string(),
+                missing_secs: 0,
+            },
+            &config,
+        );
+        result.record_deletion(
+            &pvc,
+            DeleteReason::UnschedulableTooLong {
+                pod: "pod-b".to_string(),
+                matched: None,
+            },
+            &config,
+        );
+        result.record_deletion(
+            &pvc,
+            DeleteReason::UnschedulableTooLong {
+                pod: "pod-c".to_string(),
+                matched: None,
+            },
+            &dry_run,
+        );
+
+        assert_eq!(result.deleted_count, 2);
+        assert_eq!(result.deleted_missing_node, 1);
+        assert_eq!(result.deleted_unschedulable, 1);
+        assert_eq!(result.would_delete_count, 1);
+        assert_eq!(result.deleted.len(), 3);
+    }
+
+    #[test]
+    fn test_reap_result_records_deleted_pvcs() {
+        let pvc = with_storage_request(
+            with_uid(
+                test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+                "uid-1",
+            ),
+            "10Gi",
+        );
+        let reason = DeleteReason::UnschedulableTooLong {
+            pod: "pending-pod".to_string(),
+            matched: None,
+        };
+        let config = ReaperConfig {
+            dry_run: true,
+            ..test_config()
+        };
+        let mut result = ReapResult::default();
+        result.record_deletion(&pvc, reason.clone(), &config);
+
+        assert_eq!(
+            result.deleted,
+            vec![DeletedPvc {
+                namespace: "default".to_string(),
+                name: "test".to_string(),
+                uid: Some("uid-1".to_string()),
+                storage_class: Some("openebs-lvm".to_string()),
+                node: None,
+                size: Some("10Gi".to_string()),
+                size_bytes: 10 * 1024 * 1024 * 1024,
+                reason,
+                dry_run: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_deletion_reason_skip_reasons() {
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+
+        let state = state_with(&["node-1"], vec![], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::NoConsumingPod)
+        );
+
+        let pod = pod_with_pvc("running-pod", "test", "Running", None, 600);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::InUse {
+                pod: "running-pod".to_string(),
+            })
+        );
+
+        let pod = pod_with_pvc("done-pod", "test", "Succeeded", None, 600);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &test_config()),
+            Err(SkipReason::PodNotPending {
+                pod: "done-pod".to_string(),
+                phase: "Succeeded".to_string(),
+            })
+        );
+
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 87);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let skip = state
+            .deletion_reason(&pvc, &test_config())
+            .expect_err("expected skip reason");
+        assert!(matches!(
+            skip,
+            SkipReason::BelowThreshold {
+                threshold_secs: 120,
+                ..
+            }
+        ));
+
+        let mut config = test_config();
+        config.check_unschedulable_pods = false;
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::NodeExists {
+                node: Some("node-1".to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn test_decision_summary_groups_and_ranks_upcoming() {
+        let below = |pending_secs| SkipReason::BelowThreshold {
+            pod: "pod".to_string(),
+            pending_secs,
+            threshold_secs: 120,
+        };
+
+        let mut summary = DecisionSummary::default();
+        summary.record_delete(&DeleteReason::UnschedulableTooLong {
+            pod: "pod".to_string(),
+            matched: None,
+        });
+        summary.record_skip("default", "a", &SkipReason::NoConsumingPod);
+        summary.record_skip("default", "b", &SkipReason::NoConsumingPod);
+        summary.record_skip("default", "young", &below(10));
+        summary.record_skip("default", "old", &below(100));
+
+        assert_eq!(summary.counts["deleted/UnschedulableTooLong"], 1);
+        assert_eq!(summary.counts["skipped/NoConsumingPod"], 2);
+        assert_eq!(summary.counts["skipped/BelowThreshold"], 2);
+
+        let upcoming: Vec<_> = summary
+            .top_upcoming()
+            .into_iter()
+            .map(|(pvc, _, _)| pvc.as_str())
+            .collect();
+        assert_eq!(upcoming, vec!["default/old", "default/young"]);
+    }
+
+    #[test]
+    fn test_tracker_warns_once_per_window() {
+        let pvc = with_uid(
+            test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let pending = HashSet::from(["uid-1".to_string()]);
+        let mut tracker = ReapTracker::default();
+
+        assert!(tracker.mark_warned(&pvc));
+        tracker.retain_warned(&pending);
+        assert!(!tracker.mark_warned(&pvc));
+
+        // The pod got scheduled, ending the window; a later window warns again
+        tracker.retain_warned(&HashSet::new());
+        assert!(tracker.mark_warned(&pvc));
+    }
+
+    #[tokio::test]
+    async fn test_pending_event_while_awaiting_confirmation() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.confirmation_cycles = 3;
+        let (client, requests) = mock_api(|_, _| (200, serde_json::json!({})));
+        let state = || {
+            state_with(
+                &["node-1"],
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![with_uid(
+                    test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone")),
+                    "uid-1",
+                )],
+            )
+        };
+        let pending = state();
+        let skip = SkipReason::AwaitingConfirmation {
+            reason: DeleteReason::OperatorRequested { requested_by: None },
+            seen: 1,
+            required: 3,
+        };
+        assert_eq!(
+            pending.pending_deletion(&skip, &config).map(|(_, at)| at),
+            Some(pending.now + chrono::Duration::seconds(120))
+        );
+
+        // Announced once for the whole window
+        let mut tracker = ReapTracker::default();
+        for _ in 0..2 {
+            state().reap(&client, &config, &mut tracker).await.unwrap();
+        }
+        let events = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.starts_with("POST") && r.contains("/events"))
+            .count();
+        assert_eq!(events, 1);
+    }
+
+    #[test]
+    fn test_pending_event() {
+        use chrono::TimeZone;
+
+        let event = pending_event(
+            "pod 'pending-pod' is unschedulable",
+            Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+        );
+        assert_eq!(event.type_, EventType::Warning);
+        assert_eq!(event.reason, PENDING_EVENT_REASON);
+        let note = event.note.unwrap();
+        assert!(note.contains("pending-pod"));
+        assert!(note.contains("2025-01-02T03:04:05Z"));
+    }
+
+    #[test]
+    fn test_candidate_gauges() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        let pvcs = vec![
+            test_pvc(
+                "gone",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("node-2"),
+            ),
+            test_pvc(
+                "waiting",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("node-1"),
+            ),
+            test_pvc(
+                "running",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("node-1"),
+            ),
+            test_pvc("other", "standard", "local.csi.openebs.io", Some("node-2")),
+        ];
+        let pods = vec![
+            pod_with_pvc("gone-pod", "gone", "Pending", Some("Unschedulable"), 10),
+            pod_with_pvc(
+                "waiting-pod",
+                "waiting",
+                "Pending",
+                Some("Unschedulable"),
+                10,
+            ),
+            pod_with_pvc("running-pod", "running", "Running", None, 10),
+            pod_with_pvc("other-pod", "other", "Pending", Some("Unschedulable"), 10),
+        ];
+        let state = state_with(&["node-1"], pods, pvcs);
+        let config = test_config();
+
+        let mut gauges = CandidateGauges::default();
+        for pvc in &state.pvcs {
+            if matches_storage_criteria(pvc, &config) {
+                gauges.observe(&state, pvc, &state.deletion_reason(pvc, &config));
+            }
+        }
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        metrics::with_local_recorder(&recorder, || gauges.publish());
+
+        let snapshot = snapshotter.snapshot().into_hashmap();
+        let gauge = |name: &str| {
+            snapshot
+                .iter()
+                .find(|(key, _)| key.key().name() == name)
+                .map(|(_, (_, _, value))| value)
+        };
+        assert_eq!(
+            gauge(telemetry::MATCHING_PVCS),
+            Some(&DebugValue::Gauge(3.0.into()))
+        );
+        assert_eq!(
+            gauge(telemetry::PENDING_CONSUMER_PVCS),
+            Some(&DebugValue::Gauge(2.0.into()))
+        );
+        assert_eq!(
+            gauge(telemetry::CANDIDATE_PVCS),
+            Some(&DebugValue::Gauge(1.0.into()))
+        );
+    }
+
+    #[test]
+    fn test_quantity_to_bytes() {
+        assert_eq!(quantity_to_bytes("10Gi"), Some(10 * 1024 * 1024 * 1024));
+        assert_eq!(quantity_to_bytes("1.5Gi"), Some(1610612736));
+        assert_eq!(quantity_to_bytes("512Mi"), Some(512 * 1024 * 1024));
+        assert_eq!(quantity_to_bytes("1Ki"), Some(1024));
+        assert_eq!(quantity_to_bytes("2Ti"), Some(2 * 1024u64.pow(4)));
+        assert_eq!(quantity_to_bytes("10G"), Some(10_000_000_000));
+        assert_eq!(quantity_to_bytes("500M"), Some(500_000_000));
+        assert_eq!(quantity_to_bytes("1k"), Some(1000));
+        assert_eq!(quantity_to_bytes("1E"), Some(1_000_000_000_000_000_000));
+        assert_eq!(quantity_to_bytes("1e9"), Some(1_000_000_000));
+        assert_eq!(quantity_to_bytes("1E3"), Some(1000));
+        assert_eq!(quantity_to_bytes("123456"), Some(123456));
+        assert_eq!(quantity_to_bytes(" 1Gi "), Some(1024 * 1024 * 1024));
+        assert_eq!(quantity_to_bytes("1500m"), Some(2));
+        assert_eq!(quantity_to_bytes(""), None);
+        assert_eq!(quantity_to_bytes("Gi"), None);
+        assert_eq!(quantity_to_bytes("ten"), None);
+        assert_eq!(quantity_to_bytes("-1Gi"), None);
+    }
+
+    #[test]
+    fn test_quantity_suffix_edge_cases() {
+        // Binary and decimal suffixes differ by case and the trailing `i`
+        assert_eq!(quantity_to_bytes("1Gi"), Some(1_073_741_824));
+        assert_eq!(quantity_to_bytes("1G"), Some(1_000_000_000));
+        assert_eq!(quantity_to_bytes("1Mi"), Some(1_048_576));
+        assert_eq!(quantity_to_bytes("1M"), Some(1_000_000));
+        // Lower-case `m` is milli, not mega, and rounds up to whole bytes
+        assert_eq!(quantity_to_bytes("1m"), Some(1));
+        assert_eq!(quantity_to_bytes("1000m"), Some(1));
+        assert_eq!(quantity_to_bytes("2001m"), Some(3));
+        assert_eq!(quantity_to_bytes("0m"), Some(0));
+        // Upper-case `K` and lower-case `gi` are not valid suffixes
+        assert_eq!(quantity_to_bytes("1K"), None);
+        assert_eq!(quantity_to_bytes("1gi"), None);
+        assert_eq!(quantity_to_bytes("0.5Ki"), Some(512));
+
+        assert_eq!(parse_quantity_bytes("100Gi"), Ok(100 * 1_073_741_824));
+        assert_eq!(
+            parse_quantity_bytes("lots"),
+            Err("invalid quantity 'lots'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_pvc_size_filter() {
+        let mut config = test_config();
+        let tracker = ReapTracker::default();
+        let pvc = |size: Option<&str>| {
+            let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+            match size {
+                Some(size) => with_storage_request(pvc, size),
+                None => pvc,
+            }
+        };
+
+        config.max_pvc_size = Some(100 * 1_073_741_824);
+        assert_eq!(
+            pre_evaluation_skip(&pvc(Some("100Gi")), &config, &tracker),
+            None
+        );
+        assert_eq!(
+            pre_evaluation_skip(&pvc(Some("101Gi")), &config, &tracker),
+            Some(SkipReason::SizeOutOfRange {
+                size_bytes: 101 * 1_073_741_824
+            })
+        );
+        assert_eq!(pre_evaluation_skip(&pvc(None), &config, &tracker), None);
+
+        // No storage request counts as zero, so a minimum excludes it
+        config.min_pvc_size = Some(1_073_741_824);
+        assert_eq!(
+            pre_evaluation_skip(&pvc(None), &config, &tracker),
+            Some(SkipReason::SizeOutOfRange { size_bytes: 0 })
+        );
+        assert_eq!(
+            pre_evaluation_skip(&pvc(Some("512Mi")), &config, &tracker),
+            Some(SkipReason::SizeOutOfRange {
+                size_bytes: 536_870_912
+            })
+        );
+        assert_eq!(
+            pre_evaluation_skip(&pvc(Some("1Gi")), &config, &tracker),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reclaimed_bytes_only_counts_real_deletions() {
+        let reason = DeleteReason::UnschedulableTooLong {
+            pod: "pending-pod".to_string(),
+            matched: None,
+        };
+        let sized = with_storage_request(
+            test_pvc("sized", "openebs-lvm", "local.csi.openebs.io", None),
+            "1Gi",
+        );
+        let no_request = test_pvc("unsized", "openebs-lvm", "local.csi.openebs.io", None);
+        let malformed = with_storage_request(
+            test_pvc("malformed", "openebs-lvm", "local.csi.openebs.io", None),
+            "lots",
+        );
+
+        let config = test_config();
+        let dry_run = ReaperConfig {
+            dry_run: true,
+            ..test_config()
+        };
+        let mut result = ReapResult::default();
+        result.record_deletion(&sized, reason.clone(), &config);
+        result.record_deletion(&sized, reason.clone(), &dry_run);
+        result.record_deletion(&no_request, reason.clone(), &config);
+        result.record_deletion(&malformed, reason, &config);
+
+        assert_eq!(result.reclaimed_bytes, 1024 * 1024 * 1024);
+        assert_eq!(result.deleted[2].size, None);
+        assert_eq!(result.deleted[3].size.as_deref(), Some("lots"));
+        assert_eq!(result.deleted[3].size_bytes, 0);
+    }
+
+    #[test]
+    fn test_namespace_allowed() {
+        let mut config = test_config();
+        assert!(namespace_allowed("default", &config));
+
+        config.include_namespaces = vec!["team-a".to_string(), "team-b".to_string()];
+        assert!(namespace_allowed("team-a", &config));
+        assert!(!namespace_allowed("default", &config));
+
+        config.exclude_namespaces = vec!["team-b".to_string(), "kube-system".to_string()];
+        assert!(!namespace_allowed("team-b", &config));
+
+        config.include_namespaces.clear();
+        assert!(namespace_allowed("default", &config));
+        assert!(!namespace_allowed("kube-system", &config));
+    }
+
+    #[test]
+    fn test_excluded_namespace_skipped_before_evaluation() {
+        let mut config = test_config();
+        config.exclude_namespaces = vec!["default".to_string()];
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+
+        let skip = pre_evaluation_skip(&pvc, &config, &ReapTracker::default());
+        assert_eq!(
+            skip,
+            Some(SkipReason::NamespaceExcluded {
+                namespace: "default".to_string()
+            })
+        );
+
+        let mut result = ReapResult::default();
+        result.record_skip("kube-system", "data", &skip.unwrap());
+        result.record_skip("default", "data", &SkipReason::NoConsumingPod);
+        assert_eq!(result.skipped_count, 2);
+        assert_eq!(result.skipped_namespace, 1);
+    }
+
+    fn with_annotation(
+        mut pvc: PersistentVolumeClaim,
+        key: &str,
+        value: &str,
+    ) -> PersistentVolumeClaim {
+        pvc.metadata
+            .annotations
+            .get_or_insert_with(Default::default)
+            .insert(key.to_string(), value.to_string());
+        pvc
+    }
+
+    #[test]
+    fn test_reap_now_annotation() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::{FieldsV1, ManagedFieldsEntry};
+
+        let config = test_config();
+        let mut pvc = with_annotation(
+            test_pvc(
+                "forced",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("node-1"),
+            ),
+            REAP_NOW_ANNOTATION,
+            "true",
+        );
+        pvc.metadata.managed_fields = Some(vec![ManagedFieldsEntry {
+            manager: Some("kubectl-annotate".to_string()),
+            fields_v1: Some(FieldsV1(serde_json::json!({
+                "f:metadata": {"f:annotations": {"f:pvc-reaper.io/reap-now": {}}}
+            }))),
+            ..Default::default()
+        }]);
+
+        // Node present and the pod merely pending: nothing else would delete it
+        let state = state_with(
+            &["node-1"],
+            vec![pod_with_pvc("forced-0", "forced", "Pending", None, 10)],
+            vec![pvc.clone()],
+        );
+        let list = state.candidates(&config);
+        assert_eq!(list.candidates.len(), 1);
+        assert_eq!(
+            list.candidates[0].reason,
+            DeleteReason::OperatorRequested {
+                requested_by: Some("kubectl-annotate".to_string()),
+            }
+        );
+
+        // A running pod mounting the claim still wins
+        let state = state_with(
+            &["node-1"],
+            vec![pod_with_pvc("forced-0", "forced", "Running", None, 10)],
+            vec![pvc.clone()],
+        );
+        let list = state.candidates(&config);
+        assert!(list.candidates.is_empty());
+        assert_eq!(
+            list.skipped[0].reason,
+            SkipReason::InUse {
+                pod: "forced-0".to_string()
+            }
+        );
+
+        // And so does the protection annotation
+        let protected = with_annotation(pvc, DEFAULT_PROTECTION_ANNOTATION, "true");
+        // A protected PVC of another class isn't reported at all
+        let foreign = with_annotation(
+            test_pvc("foreign", "gp3", "ebs.csi.aws.com", Some("gone")),
+            DEFAULT_PROTECTION_ANNOTATION,
+            "true",
+        );
+        let state = state_with(&["node-1"], vec![], vec![protected, foreign]);
+        let list = state.candidates(&config);
+        assert!(list.candidates.is_empty());
+        assert_eq!(list.skipped.len(), 1);
+        assert_eq!(
+            list.skipped[0].reason,
+            SkipReason::Protected {
+                annotation: DEFAULT_PROTECTION_ANNOTATION.to_string()
+            }
+        );
+
+        let unattributed = with_annotation(
+            test_pvc("plain", "openebs-lvm", "local.csi.openebs.io", None),
+            REAP_NOW_ANNOTATION,
+            "false",
+        );
+        assert_eq!(annotation_manager(&unattributed, REAP_NOW_ANNOTATION), None);
+        let state = state_with(&[], vec![], vec![unattributed]);
+        assert!(state.candidates(&config).candidates.is_empty());
+    }
+
+    #[test]
+    fn test_protection_annotation() {
+        let config = test_config();
+        let pvc = || test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+
+        assert_eq!(protected_by(&pvc(), &config), None);
+        for truthy in ["true", "True", "yes", "1", " on "] {
+            let protected = with_annotation(pvc(), DEFAULT_PROTECTION_ANNOTATION, truthy);
+            assert_eq!(
+                protected_by(&protected, &config),
+                Some(DEFAULT_PROTECTION_ANNOTATION),
+                "{truthy:?} should protect"
+            );
+        }
+        for falsey in ["false", "no", "0", ""] {
+            let unprotected = with_annotation(pvc(), DEFAULT_PROTECTION_ANNOTATION, falsey);
+            assert_eq!(
+                protected_by(&unprotected, &config),
+                None,
+                "{falsey:?} should not protect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_protection_annotation_key_overridable() {
+        let mut config = test_config();
+        config.protection_annotation = "example.com/keep".to_string();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+
+        let default_key = with_annotation(pvc.clone(), DEFAULT_PROTECTION_ANNOTATION, "true");
+        assert_eq!(protected_by(&default_key, &config), None);
+
+        let custom_key = with_annotation(pvc, "example.com/keep", "true");
+        assert_eq!(protected_by(&custom_key, &config), Some("example.com/keep"));
+    }
+
+    #[test]
+    fn test_default_on_flags_can_be_turned_off() {
+        let parse = |args: &[&str]| {
+            ReaperConfig::try_parse_from(["pvc-reaper"].iter().chain(args)).unwrap()
+        };
+
+        let config = parse(&[]);
+        assert!(config.lookup_storage_classes && config.check_unschedulable_pods);
+        assert!(config.controller_owned_pods_only && config.watch_nodes);
+        let config = parse(&["--lookup-storage-classes", "--check-unschedulable-pods"]);
+        assert!(config.lookup_storage_classes && config.check_unschedulable_pods);
+        let config = parse(&[
+            "--lookup-storage-classes=false",
+            "--check-unschedulable-pods=false",
+            "--controller-owned-pods-only=false",
+            "--watch-nodes=false",
+        ]);
+        assert!(!config.lookup_storage_classes && !config.check_unschedulable_pods);
+        assert!(!config.controller_owned_pods_only && !config.watch_nodes);
+    }
+
+    #[test]
+    fn test_require_opt_in() {
+        let mut config = test_config();
+        let tracker = ReapTracker::default();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let opted_in = with_annotation(pvc.clone(), DEFAULT_OPT_IN_ANNOTATION, "true");
+        let opted_out = with_annotation(pvc.clone(), DEFAULT_OPT_IN_ANNOTATION, "false");
+
+        // Opt-out behavior by default
+        assert_eq!(pre_evaluation_skip(&pvc, &config, &tracker), None);
+
+        config.require_opt_in = true;
+        let not_opted_in = SkipReason::NotOptedIn {
+            annotation: DEFAULT_OPT_IN_ANNOTATION.to_string(),
+        };
+        assert_eq!(
+            pre_evaluation_skip(&pvc, &config, &tracker).as_ref(),
+            Some(&not_opted_in)
+        );
+        assert_eq!(
+            pre_evaluation_skip(&opted_out, &config, &tracker).as_ref(),
+            Some(&not_opted_in)
+        );
+        assert_eq!(pre_evaluation_skip(&opted_in, &config, &tracker), None);
+
+        let mut result = ReapResult::default();
+        result.record_skip("default", "test", &not_opted_in);
+        assert_eq!(result.skipped_not_opted_in, 1);
+        assert_eq!(result.skipped_count, 1);
+    }
+
+    #[test]
+    fn test_storage_class_globs() {
+        let pattern = StorageClassPattern::glob("openebs-lvm-*").unwrap();
+        assert!(pattern.matches("openebs-lvm-a"));
+        assert!(pattern.matches("openebs-lvm-"));
+        assert!(!pattern.matches("openebs-lvm"));
+        assert!(!pattern.matches("x-openebs-lvm-a"));
+
+        let pattern = StorageClassPattern::glob("lvm-?").unwrap();
+        assert!(pattern.matches("lvm-a"));
+        assert!(!pattern.matches("lvm-ab"));
+
+        // Regex metacharacters in literal names are not special
+        let literal = StorageClassPattern::glob("openebs.lvm").unwrap();
+        assert!(literal.matches("openebs.lvm"));
+        assert!(!literal.matches("openebs-lvm"));
+    }
+
+    #[test]
+    fn test_storage_class_regex() {
+        let pattern = StorageClassPattern::regex("openebs-(lvm|zfs)-[a-c]").unwrap();
+        assert!(pattern.matches("openebs-zfs-b"));
+        assert!(!pattern.matches("openebs-zfs-d"));
+        // Anchored to the whole name
+        assert!(!pattern.matches("openebs-lvm-a-extra"));
+
+        assert!(StorageClassPattern::regex("openebs-(").is_err());
+    }
+
+    #[test]
+    fn test_storage_class_literal_and_pattern_overlap() {
+        let config = ReaperConfig::parse_from([
+            "pvc-reaper",
+            "--storage-classes",
+            "openebs-lvm,openebs-lvm-*",
+            "--storage-class-regex",
+            "local-(a|b)",
+        ]);
+        let matches = |class: &str| {
+            matches_storage_criteria(
+                &test_pvc("test", class, "local.csi.openebs.io", None),
+                &config,
+            )
+        };
+
+        assert!(matches("openebs-lvm"));
+        assert!(matches("openebs-lvm-a"));
+        assert!(matches("local-b"));
+        assert!(!matches("openebs-zfs"));
+        assert!(!matches("local-c"));
+
+        assert!(
+            ReaperConfig::try_parse_from(["pvc-reaper", "--storage-class-regex", "("]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_matches_storage_criteria_multiple_provisioners() {
+        let config = ReaperConfig::parse_from([
+            "pvc-reaper",
+            "--storage-provisioner",
+            "local.csi.openebs.io,topolvm.io",
+        ]);
+
+        let topolvm = test_pvc("test", "openebs-lvm", "topolvm.io", Some("node-1"));
+        assert!(matches_storage_criteria(&topolvm, &config));
+
+        let other = test_pvc("test", "openebs-lvm", "ebs.csi.aws.com", Some("node-1"));
+        assert!(!matches_storage_criteria(&other, &config));
+
+        // A single value keeps working
+        let config =
+            ReaperConfig::parse_from(["pvc-reaper", "--storage-provisioner", "topolvm.io"]);
+        assert_eq!(config.storage_provisioner, vec!["topolvm.io".to_string()]);
+        assert!(matches_storage_criteria(&topolvm, &config));
+    }
+
+    #[test]
+    fn test_matches_storage_criteria_falls_back_to_storage_class() {
+        let config = test_config();
+        let mut state = state_with(&[], vec![], vec![]);
+        state.storage_class_provisioners = HashMap::from([
+            (
+                "openebs-lvm".to_string(),
+                "local.csi.openebs.io".to_string(),
+            ),
+            ("lvm-other".to_string(), "ebs.csi.aws.com".to_string()),
+        ]);
+
+        // Annotation present: used as-is
+        let annotated = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None);
+        assert!(state.matches_storage_criteria(&annotated, &config));
+        let annotated_other = test_pvc("test", "openebs-lvm", "ebs.csi.aws.com", None);
+        assert!(!state.matches_storage_criteria(&annotated_other, &config));
+
+        // Annotation absent but the class is known
+        let mut unannotated = annotated.clone();
+        unannotated.metadata.annotations = None;
+        assert!(!matches_storage_criteria(&unannotated, &config));
+        assert!(state.matches_storage_criteria(&unannotated, &config));
+
+        // Annotation absent and the class unknown
+        let mut unknown = unannotated.clone();
+        unknown.spec.as_mut().unwrap().storage_class_name = Some("mystery".to_string());
+        assert!(!state.matches_storage_criteria(&unknown, &config));
+    }
+
+    #[test]
+    fn test_pvc_phase_filter() {
+        let mut config = test_config();
+        let tracker = ReapTracker::default();
+        let with_phase = |phase: Option<&str>| {
+            let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+            pvc.status =
+                phase.map(
+                    |phase| k8s_openapi::api::core::v1::PersistentVolumeClaimStatus {
+                        phase: Some(phase.to_string()),
+                        ..Default::default()
+                    },
+                );
+            pvc
+        };
+
+        // Default keeps both Pending and Bound eligible
+        assert_eq!(
+            pre_evaluation_skip(&with_phase(Some("Bound")), &config, &tracker),
+            None
+        );
+
+        config.pvc_phases = vec!["Pending".to_string()];
+        assert_eq!(
+            pre_evaluation_skip(&with_phase(Some("Bound")), &config, &tracker),
+            Some(SkipReason::PhaseNotAllowed {
+                phase: "Bound".to_string()
+            })
+        );
+        assert_eq!(
+            pre_evaluation_skip(&with_phase(Some("Pending")), &config, &tracker),
+            None
+        );
+        // No status is treated as Pending
+        assert_eq!(
+            pre_evaluation_skip(&with_phase(None), &config, &tracker),
+            None
+        );
+    }
+
+    #[test]
+    fn test_skip_owner_kinds() {
+        let owner = |api_version: &str, kind: &str, name: &str| OwnerReference {
+            api_version: api_version.to_string(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        pvc.metadata.owner_references = Some(vec![
+            owner("apps/v1", "StatefulSet", "db"),
+            owner("cdi.kubevirt.io/v1beta1", "DataVolume", "disk"),
+        ]);
+        let mut config = test_config();
+        let tracker = ReapTracker::default();
+
+        assert_eq!(pre_evaluation_skip(&pvc, &config, &tracker), None);
+
+        // Only the second owner matches
+        config.skip_owner_kinds = vec!["DataVolume".to_string()];
+        assert_eq!(
+            pre_evaluation_skip(&pvc, &config, &tracker),
+            Some(SkipReason::OwnedBy {
+                kind: "DataVolume".to_string(),
+                name: "disk".to_string()
+            })
+        );
+
+        config.skip_owner_kinds = vec!["cdi.kubevirt.io/DataVolume".to_string()];
+        assert!(pre_evaluation_skip(&pvc, &config, &tracker).is_some());
+
+        config.skip_owner_kinds = vec!["example.com/DataVolume".to_string()];
+        assert_eq!(pre_evaluation_skip(&pvc, &config, &tracker), None);
+
+        config.skip_owner_kinds = vec!["VolumeSnapshot".to_string()];
+        assert_eq!(pre_evaluation_skip(&pvc, &config, &tracker), None);
+    }
+
+    fn statefulset(name: &str, replicas: i32, templates: &[&str]) -> StatefulSet {
+        StatefulSet {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: Some(k8s_openapi::api::apps::v1::StatefulSetSpec {
+                replicas: Some(replicas),
+                volume_claim_templates: Some(
+                    templates
+                        .iter()
+                        .map(|template| PersistentVolumeClaim {
+                            metadata: ObjectMeta {
+                                name: Some(template.to_string()),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_excess_ordinal() {
+        let mut state = state_with(&[], vec![], vec![]);
+        state.statefulsets = vec![statefulset("foo", 2, &["data", "logs"])];
+        let pvc = |name: &str| test_pvc(name, "openebs-lvm", "local.csi.openebs.io", None);
+
+        assert_eq!(state.excess_ordinal(&pvc("data-foo-1")), None);
+        assert_eq!(
+            state.excess_ordinal(&pvc("data-foo-2")),
+            Some(("foo".to_string(), 2))
+        );
+        assert_eq!(
+            state.excess_ordinal(&pvc("logs-foo-4")),
+            Some(("foo".to_string(), 4))
+        );
+        // Different StatefulSet, template or a non-ordinal suffix
+        assert_eq!(state.excess_ordinal(&pvc("data-foobar-3")), None);
+        assert_eq!(state.excess_ordinal(&pvc("cache-foo-3")), None);
+        assert_eq!(state.excess_ordinal(&pvc("data-foo-x")), None);
+
+        // Same names in another namespace don't count
+        let mut other_ns = pvc("data-foo-3");
+        other_ns.metadata.namespace = Some("other".to_string());
+        assert_eq!(state.excess_ordinal(&other_ns), None);
+    }
+
+    #[test]
+    fn test_scaled_down_decision_grace_period() {
+        let mut config = test_config();
+        config.reap_scaled_down_statefulsets = true;
+        config.scaled_down_grace_secs = 300;
+        let pvc = with_uid(
+            test_pvc("data-foo-2", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let mut state = state_with(&["node-1"], vec![], vec![pvc.clone()]);
+        state.statefulsets = vec![statefulset("foo", 2, &["data"])];
+        let mut tracker = ReapTracker::default();
+        let mut seen = HashSet::new();
+
+        assert_eq!(
+            state.scaled_down_decision(&pvc, &config, &mut tracker, &mut seen),
+            Some(Err(SkipReason::ScaleDownGrace {
+                sts: "foo".to_string(),
+                ordinal: 2,
+                remaining_secs: 300
+            }))
+        );
+        assert!(seen.contains("uid-1"));
+
+        state.now += chrono::Duration::seconds(301);
+        assert_eq!(
+            state.scaled_down_decision(&pvc, &config, &mut tracker, &mut seen),
+            Some(Ok(DeleteReason::ScaledDownStatefulSet {
+                sts: "foo".to_string(),
+                ordinal: 2
+            }))
+        );
+
+        // Scaling back up restarts the grace period
+        tracker.retain_excess(&HashSet::new());
+        assert!(matches!(
+            state.scaled_down_decision(&pvc, &config, &mut tracker, &mut seen),
+            Some(Err(SkipReason::ScaleDownGrace { .. }))
+        ));
+
+        // A pod still using the claim sends it through the regular checks
+        state.pods = vec![pod_with_pvc("foo-2", "data-foo-2", "Running", None, 10)];
+        assert_eq!(
+            state.scaled_down_decision(&pvc, &config, &mut tracker, &mut seen),
+            None
+        );
+
+        config.reap_scaled_down_statefulsets = false;
+        state.pods.clear();
+        assert_eq!(
+            state.scaled_down_decision(&pvc, &config, &mut tracker, &mut seen),
+            None
+        );
+    }
+
+    #[test]
+    fn test_running_pod_blocks_deletion() {
+        let config = test_config();
+        let pvc = test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let unschedulable = pod_with_pvc("new", "data", "Pending", Some("Unschedulable"), 10);
+        let running = pod_with_pvc("old", "data", "Running", None, 10);
+
+        let state = state_with(&[], vec![unschedulable.clone(), running], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::InUse {
+                pod: "old".to_string()
+            })
+        );
+
+        // A pending pod already bound to a node may be mounting the volume too
+        let mut scheduled = pod_with_pvc("starting", "data", "Pending", None, 10);
+        scheduled.spec.as_mut().unwrap().node_name = Some("node-2".to_string());
+        let state = state_with(
+            &[],
+            vec![unschedulable.clone(), scheduled],
+            vec![pvc.clone()],
+        );
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::InUse { .. })
+        ));
+
+        // A running pod with a same-named claim in another namespace doesn't block
+        let mut elsewhere = pod_with_pvc("other", "data", "Running", None, 10);
+        elsewhere.metadata.namespace = Some("other".to_string());
+        let state = state_with(&[], vec![unschedulable, elsewhere], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_ga_provisioner_annotation() {
+        let config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "other-provisioner", None);
+        assert_eq!(
+            get_pvc_provisioner(&pvc),
+            Some((BETA_PROVISIONER_ANNOTATION, "other-provisioner"))
+        );
+        assert!(!matches_storage_criteria(&pvc, &config));
+
+        // GA key takes precedence over the beta key when both are set
+        pvc = with_annotation(pvc, PROVISIONER_ANNOTATION, "local.csi.openebs.io");
+        assert_eq!(
+            get_pvc_provisioner(&pvc),
+            Some((PROVISIONER_ANNOTATION, "local.csi.openebs.io"))
+        );
+        assert!(matches_storage_criteria(&pvc, &config));
+
+        // GA key alone is enough
+        pvc.metadata
+            .annotations
+            .as_mut()
+            .unwrap()
+            .remove(BETA_PROVISIONER_ANNOTATION);
+        assert_eq!(
+            get_pvc_annotation(&pvc, PROVISIONER_ANNOTATION),
+            Some("local.csi.openebs.io")
+        );
+        assert!(matches_storage_criteria(&pvc, &config));
+    }
+
+    #[test]
+    fn test_namespace_label_selector() {
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let mut state = state_with(&[], vec![], vec![pvc.clone()]);
+        assert_eq!(state.namespace_unselected(&pvc), None);
+
+        state.selected_namespaces = Some(HashSet::from(["preview-1".to_string()]));
+        assert_eq!(
+            state.namespace_unselected(&pvc),
+            Some(SkipReason::NamespaceExcluded {
+                namespace: "default".to_string()
+            })
+        );
+
+        state.selected_namespaces = Some(HashSet::from(["default".to_string()]));
+        assert_eq!(state.namespace_unselected(&pvc), None);
+    }
+
+    #[tokio::test]
+    async fn test_namespace_annotation() {
+        let mut config = test_config();
+        let namespace = |annotation: Option<&str>| Namespace {
+            metadata: ObjectMeta {
+                name: Some("default".to_string()),
+                annotations: annotation.map(|value| {
+                    [(DEFAULT_OPT_IN_ANNOTATION.to_string(), value.to_string())].into()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(namespace_enabled(&namespace(None), &config));
+        assert!(!namespace_enabled(&namespace(Some("false")), &config));
+        config.require_namespace_opt_in = true;
+        assert!(!namespace_enabled(&namespace(None), &config));
+        assert!(namespace_enabled(&namespace(Some("true")), &config));
+        config.require_namespace_opt_in = false;
+
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let mut state = state_with(&[], vec![], vec![pvc.clone()]);
+        let client = mock_client(200);
+        state.enabled_namespaces =
+            enabled_namespaces(&client, &config, Some(&[namespace(Some("false"))])).await;
+        assert_eq!(
+            state.namespace_opted_out(&pvc, &config),
+            Some(SkipReason::NamespaceOptedOut {
+                namespace: "default".to_string(),
+                annotation: DEFAULT_OPT_IN_ANNOTATION.to_string(),
+            })
+        );
+        state.enabled_namespaces =
+            enabled_namespaces(&client, &config, Some(&[namespace(None)])).await;
+        assert_eq!(state.namespace_opted_out(&pvc, &config), None);
+
+        // Without list access each namespace is fetched, and one that can't be is opted out
+        config.namespaces = vec!["default".to_string()];
+        let enabled = enabled_namespaces(&mock_client(403), &config, None).await;
+        assert_eq!(enabled, Some(HashSet::new()));
+
+        config.namespace_annotation = String::new();
+        assert_eq!(enabled_namespaces(&client, &config, None).await, None);
+    }
+
+    #[test]
+    fn test_system_namespaces_excluded() {
+        let mut config = test_config();
+        let tracker = ReapTracker::default();
+        let in_namespace = |namespace: &str| {
+            let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+            pvc.metadata.namespace = Some(namespace.to_string());
+            pvc
+        };
+
+        for namespace in [
+            "kube-system",
+            "kube-public",
+            "kube-node-lease",
+            "pvc-reaper",
+        ] {
+            assert_eq!(
+                pre_evaluation_skip(&in_namespace(namespace), &config, &tracker),
+                Some(SkipReason::SystemNamespace {
+                    namespace: namespace.to_string()
+                })
+            );
+        }
+        assert_eq!(
+            pre_evaluation_skip(&in_namespace("openebs"), &config, &tracker),
+            None
+        );
+
+        config.include_system_namespaces = true;
+        assert_eq!(
+            pre_evaluation_skip(&in_namespace("kube-system"), &config, &tracker),
+            None
+        );
+    }
+
+    #[test]
+    fn test_class_thresholds() {
+        let mut config = test_config();
+        config.class_thresholds = vec![
+            ClassThreshold::parse("local-nvme-db=30m").unwrap(),
+            ClassThreshold::parse("openebs-*=300").unwrap(),
+        ];
+
+        assert_eq!(
+            config.unschedulable_threshold_secs(Some("local-nvme-db")),
+            1800
+        );
+        assert_eq!(
+            config.unschedulable_threshold_secs(Some("openebs-lvm")),
+            300
+        );
+        // Classes without an override and PVCs without a class use the global threshold
+        assert_eq!(config.unschedulable_threshold_secs(Some("standard")), 120);
+        assert_eq!(config.unschedulable_threshold_secs(None), 120);
+
+        assert!(ClassThreshold::parse("openebs-lvm").is_err());
+        assert!(ClassThreshold::parse("openebs-lvm=soon").is_err());
+
+        // deletion_reason applies the class override
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 200);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold {
+                threshold_secs: 300,
+                ..
+            })
+        ));
+
+        config.class_thresholds.clear();
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::UnschedulableTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_require_both_conditions() {
+        let mut config = test_config();
+        config.require_both_conditions = true;
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+
+        // Node missing but the pod only just became pending
+        let fresh = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 5);
+        let state = state_with(&["node-1"], vec![fresh], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        // Both conditions hold
+        let stale = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+        let state = state_with(&["node-1"], vec![stale.clone()], vec![pvc.clone()]);
+        let reason = state.deletion_reason(&pvc, &config).unwrap();
+        assert!(matches!(
+            &reason,
+            DeleteReason::MissingNodeAndUnschedulable { node, pod, pending_secs }
+                if node == "gone" && pod == "pending-pod" && *pending_secs >= 600
+        ));
+        assert!(reason.describe().contains("missing node 'gone'"));
+
+        // Unschedulable past the threshold but the node still exists
+        let state = state_with(&["gone"], vec![stale.clone()], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::NodeExists {
+                node: Some("gone".to_string())
+            })
+        );
+
+        // Default either/or behavior deletes on the missing node alone
+        config.require_both_conditions = false;
+        let fresh = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 5);
+        let state = state_with(&["node-1"], vec![fresh], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_controller_owned_pods_only() {
+        let mut config = test_config();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let sts_pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+
+        let state = state_with(&[], vec![sts_pod.clone()], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+
+        let mut bare_pod = sts_pod.clone();
+        bare_pod.metadata.owner_references = None;
+        let state = state_with(&[], vec![bare_pod], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::UnmanagedPod {
+                pod: "pending-pod".to_string()
+            })
+        );
+
+        // An owner reference that isn't the controller doesn't count
+        let mut non_controller = sts_pod.clone();
+        non_controller.metadata.owner_references.as_mut().unwrap()[0].controller = None;
+        let state = state_with(&[], vec![non_controller], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::UnmanagedPod { .. })
+        ));
+
+        // Nor does a controller kind that isn't configured
+        config.controller_owner_kinds = vec!["ReplicaSet".to_string()];
+        let state = state_with(&[], vec![sts_pod.clone()], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::UnmanagedPod { .. })
+        ));
+
+        config.controller_owned_pods_only = false;
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_keep_until_annotation() {
+        let mut config = test_config();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+        let mut state = state_with(&[], vec![pod], vec![pvc.clone()]);
+        state.now = "2026-01-01T12:00:00Z".parse().unwrap();
+        let decide = |state: &State, pvc: &PersistentVolumeClaim, config: &ReaperConfig| {
+            state.decide(
+                pvc,
+                config,
+                &mut ReapTracker::default(),
+                &mut HashSet::new(),
+            )
+        };
+
+        let before = with_annotation(pvc.clone(), KEEP_UNTIL_ANNOTATION, "2026-01-01T13:00:00Z");
+        assert_eq!(
+            decide(&state, &before, &config),
+            Err(SkipReason::KeepUntil {
+                until: "2026-01-01T13:00:00Z".to_string()
+            })
+        );
+
+        // Offsets are honored: 12:30+01:00 is 11:30Z, already past
+        let after = with_annotation(
+            pvc.clone(),
+            KEEP_UNTIL_ANNOTATION,
+            "2026-01-01T12:30:00+01:00",
+        );
+        assert!(matches!(
+            decide(&state, &after, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+
+        let invalid = with_annotation(pvc.clone(), KEEP_UNTIL_ANNOTATION, "next tuesday");
+        assert_eq!(
+            decide(&state, &invalid, &config),
+            Err(SkipReason::KeepUntil {
+                until: "next tuesday".to_string()
+            })
+        );
+
+        // Dry-run reports the skip rather than a would-be deletion
+        config.dry_run = true;
+        assert!(matches!(
+            decide(&state, &before, &config),
+            Err(SkipReason::KeepUntil { .. })
+        ));
+        let mut result = ReapResult::default();
+        result.record_skip(
+            "default",
+            "test",
+            &SkipReason::KeepUntil {
+                until: "2026-01-01T13:00:00Z".to_string(),
+            },
+        );
+        assert_eq!(result.skipped_protected, 1);
+        assert_eq!(result.would_delete_count, 0);
+
+        // Once the timestamp passes normal behavior resumes
+        state.now = "2026-01-01T13:00:00Z".parse().unwrap();
+        assert!(decide(&state, &before, &config).is_ok());
+    }
+
+    #[test]
+    fn test_selected_node_annotation_keys() {
+        let mut config = test_config();
+        config.selected_node_annotation = vec![
+            SELECTED_NODE_ANNOTATION.to_string(),
+            "local.path.provisioner/selected-node".to_string(),
+        ];
+        let pvc = with_annotation(
+            test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+            "local.path.provisioner/selected-node",
+            "gone",
+        );
+        assert_eq!(get_selected_node(&pvc, &config), Some("gone"));
+
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode {
+                node: "gone".to_string(),
+                pod: "pending-pod".to_string(),
+                missing_secs: 0,
+            })
+        );
+
+        // Earlier keys take priority
+        let both = with_annotation(pvc.clone(), SELECTED_NODE_ANNOTATION, "node-1");
+        assert_eq!(get_selected_node(&both, &config), Some("node-1"));
+
+        // The default only reads the standard key
+        assert_eq!(get_selected_node(&pvc, &test_config()), None);
+    }
+
+    #[test]
+    fn test_consumer_pods_match_within_namespace() {
+        let config = test_config();
+        let in_namespace = |namespace: &str, pvc: PersistentVolumeClaim| {
+            let mut pvc = pvc;
+            pvc.metadata.namespace = Some(namespace.to_string());
+            pvc
+        };
+        let pod_in = |namespace: &str, pod: Pod| {
+            let mut pod = pod;
+            pod.metadata.namespace = Some(namespace.to_string());
+            pod
+        };
+        let pvc_a = in_namespace(
+            "team-a",
+            test_pvc(
+                "data",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("node-1"),
+            ),
+        );
+        let pvc_b = in_namespace(
+            "team-b",
+            test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone")),
+        );
+        let pod_a = pod_in(
+            "team-a",
+            pod_with_pvc("app", "data", "Pending", Some("Unschedulable"), 600),
+        );
+        let pod_b = pod_in("team-b", pod_with_pvc("app", "data", "Running", None, 600));
+
+        assert!(pod_uses_pvc(&pod_a, &pvc_a));
+        assert!(!pod_uses_pvc(&pod_a, &pvc_b));
+
+        // team-a's pending pod must not be attributed to team-b's claim on a missing node
+        let state = state_with(
+            &["node-1"],
+            vec![pod_a.clone()],
+            vec![pvc_a.clone(), pvc_b.clone()],
+        );
+        assert_eq!(
+            state.deletion_reason(&pvc_b, &config),
+            Err(SkipReason::NoConsumingPod)
+        );
+
+        // and team-b's running pod doesn't shield team-a's claim
+        let state = state_with(&["node-1"], vec![pod_b, pod_a], vec![pvc_a.clone(), pvc_b]);
+        assert!(matches!(
+            state.deletion_reason(&pvc_a, &config),
+            Ok(DeleteReason::UnschedulableTooLong { .. })
+        ));
+    }
+
+    #[test]
+    fn test_all_consumers_are_evaluated() {
+        let config = test_config();
+        let pvc = test_pvc(
+            "data",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let finished_job = pod_with_pvc("migrate", "data", "Succeeded", None, 3600);
+        let stuck = pod_with_pvc("db-0", "data", "Pending", Some("Unschedulable"), 600);
+        let running = pod_with_pvc("db-1", "data", "Running", None, 600);
+
+        // The stuck pod is found even though a completed pod comes first
+        let state = state_with(
+            &["node-1"],
+            vec![finished_job.clone(), stuck.clone()],
+            vec![pvc.clone()],
+        );
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::UnschedulableTooLong {
+                pod: "db-0".to_string(),
+                matched: None
+            })
+        );
+
+        // A running consumer later in the list still blocks deletion
+        let state = state_with(&["node-1"], vec![stuck, running], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::InUse {
+                pod: "db-1".to_string()
+            })
+        );
+
+        // A merely pending consumer is reported over a completed one
+        let pending = pod_with_pvc("db-2", "data", "Pending", None, 600);
+        let state = state_with(&["node-1"], vec![finished_job, pending], vec![pvc.clone()]);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::PodNotUnschedulable {
+                pod: "db-2".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_only_volume_related_failures_are_reaped() {
+        let config = test_config();
+        let with_message = |message: Option<&str>| {
+            let mut pod =
+                pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+            pod.status.as_mut().unwrap().conditions.as_mut().unwrap()[0].message =
+                message.map(str::to_string);
+            pod
+        };
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let decide = |pod: Pod| {
+            state_with(&["node-1"], vec![pod], vec![pvc.clone()]).deletion_reason(&pvc, &config)
+        };
+
+        for message in [
+            "0/3 nodes are available: 3 node(s) had volume node affinity conflict.",
+            "persistentvolumeclaim \"data\" not found",
+            "0/3 nodes are available: 3 node(s) didn't find available persistent volumes to bind.",
+        ] {
+            assert!(
+                matches!(
+                    decide(with_message(Some(message))),
+                    Ok(DeleteReason::UnschedulableTooLong { .. })
+                ),
+                "{message}"
+            );
+        }
+
+        let cpu = "0/3 nodes are available: 3 Insufficient cpu.";
+        assert_eq!(
+            decide(with_message(Some(cpu))),
+            Err(SkipReason::NotVolumeRelated {
+                pod: "pending-pod".to_string(),
+                message: cpu.to_string()
+            })
+        );
+        assert!(matches!(
+            decide(with_message(None)),
+            Err(SkipReason::NotVolumeRelated { .. })
+        ));
+
+        // A missing node doesn't explain a pod that is short of CPU
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let state =
+            |message| state_with(&["node-1"], vec![with_message(message)], vec![pvc.clone()]);
+        assert!(matches!(
+            state(Some(cpu)).deletion_reason(&pvc, &config),
+            Err(SkipReason::NotVolumeRelated { .. })
+        ));
+        let both = "0/3 nodes are available: 1 Insufficient cpu, 2 node(s) had volume node affinity conflict.";
+        assert!(matches!(
+            state(Some(both)).deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode { .. })
+        ));
+    }
+
+    #[test]
+    fn test_generic_ephemeral_volumes() {
+        let config = test_config();
+        let mut pod = pod_with_pvc("worker-0", "data", "Pending", Some("Unschedulable"), 600);
+        pod.spec
+            .as_mut()
+            .unwrap()
+            .volumes
+            .as_mut()
+            .unwrap()
+            .push(Volume {
+                name: "scratch".to_string(),
+                ephemeral: Some(k8s_openapi::api::core::v1::EphemeralVolumeSource {
+                    volume_claim_template: Some(
+                        k8s_openapi::api::core::v1::PersistentVolumeClaimTemplate {
+                            spec: k8s_openapi::api::core::v1::PersistentVolumeClaimSpec {
+                                storage_class_name: Some("openebs-lvm".to_string()),
+                                ..Default::default()
+                            },
+                            ..Default::default()
+                        },
+                    ),
+                }),
+                ..Default::default()
+            });
+
+        assert_eq!(
+            get_pod_pvc_names(&pod),
+            vec!["data".to_string(), "worker-0-scratch".to_string()]
+        );
+
+        // The template-created claim carries the template's class and is gated on it
+        let ephemeral = test_pvc(
+            "worker-0-scratch",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("gone"),
+        );
+        assert!(matches_storage_criteria(&ephemeral, &config));
+        let other_class = test_pvc("worker-0-scratch", "standard", "local.csi.openebs.io", None);
+        assert!(!matches_storage_criteria(&other_class, &config));
+
+        let state = state_with(&["node-1"], vec![pod], vec![ephemeral.clone()]);
+        assert_eq!(
+            state.deletion_reason(&ephemeral, &config),
+            Ok(DeleteReason::MissingNode {
+                node: "gone".to_string(),
+                pod: "worker-0".to_string(),
+                missing_secs: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_terminating_pvcs_are_not_deleted_again() {
+        let config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        pvc.metadata.finalizers = Some(vec!["example.com/hold".to_string()]);
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+        let mut state = state_with(&[], vec![pod], vec![pvc.clone()]);
+        let mut tracker = ReapTracker::default();
+        assert!(
+            state
+                .decide(&pvc, &config, &mut tracker, &mut HashSet::new())
+                .is_ok()
+        );
+
+        // The finalizer holds the PVC in Terminating across several cycles
+        pvc.metadata.deletion_timestamp = Some(Time(state.now - chrono::Duration::seconds(90)));
+        let mut result = ReapResult::default();
+        for _ in 0..3 {
+            match state.decide(&pvc, &config, &mut tracker, &mut HashSet::new()) {
+                Ok(reason) => result.record_deletion(&pvc, reason, &config),
+                Err(skip) => {
+                    assert_eq!(skip.variant(), "Terminating");
+                    result.record_skip("default", "test", &skip);
+                }
+            }
+            state.now += chrono::Duration::seconds(60);
+        }
+
+        assert_eq!(result.deleted_count, 0);
+        assert_eq!(result.skipped_count, 3);
+        assert_eq!(
+            state.terminating(&pvc),
+            Some(SkipReason::Terminating {
+                terminating_secs: 270
+            })
+        );
+    }
+
+    /// A client whose every request is answered with `status` and a matching `Status` body
+    fn mock_client(status: u16) -> Client {
+        let body = serde_json::json!({
+            "kind": "Status",
+            "apiVersion": "v1",
+            "status": "Failure",
+            "message": "mocked",
+            "reason": if status == 404 { "NotFound" } else { "InternalError" },
+            "code": status,
+        });
+        mock_api(move |_, _| (status, body.clone())).0
+    }
+
+    /// A client answering each request with `respond(method, path)`, recording every
+    /// `METHOD path` it served
+    fn mock_api(
+        respond: impl Fn(&str, &str) -> (u16, serde_json::Value) + Send + 'static,
+    ) -> (Client, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+        let (service, mut handle) = tower_test::mock::pair::<
+            http::Request<kube::client::Body>,
+            http::Response<kube::client::Body>,
+        >();
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let served = requests.clone();
+        tokio::spawn(async move {
+            while let Some((request, send)) = handle.next_request().await {
+                let (method, path) = (
+                    request.method().to_string(),
+                    request.uri().path().to_string(),
+                );
+                let (status, body) = respond(&method, &path);
+                served.lock().unwrap().push(format!("{} {}", method, path));
+                send.send_response(
+                    http::Response::builder()
+                        .status(status)
+                        .body(kube::client::Body::from(body.to_string().into_bytes()))
+                        .unwrap(),
+                );
+            }
+        });
+        (Client::new(service, "default"), requests)
+    }
+
+    #[tokio::test]
+    async fn test_reap_all_survives_one_failing_cluster() {
+        use cluster::{Cluster, reap_all};
+
+        let empty = || {
+            mock_api(|_, _| {
+                (
+                    200,
+                    serde_json::json!({
+                        "kind": "List",
+                        "apiVersion": "v1",
+                        "metadata": {"resourceVersion": "1"},
+                        "items": [],
+                    }),
+                )
+            })
+            .0
+        };
+        let config = test_config();
+
+        let clusters = [
+            Cluster::new(Some("edge-1".to_string()), empty()),
+            Cluster::new(Some("edge-2".to_string()), mock_client(500)),
+        ];
+        let result = reap_all(&clusters, &config).await.unwrap();
+        assert_eq!(result.failed_clusters, ["edge-2"]);
+
+        let clusters = [
+            Cluster::new(Some("edge-1".to_string()), mock_client(500)),
+            Cluster::new(Some("edge-2".to_string()), mock_client(500)),
+        ];
+        let error = reap_all(&clusters, &config).await.unwrap_err();
+        assert!(format!("{:#}", error).contains("every cluster"));
+    }
+
+    #[test]
+    fn test_reap_result_merge() {
+        let mut total = ReapResult {
+            deleted_count: 1,
+            reclaimed_bytes: 100,
+            loaded: LoadedCounts {
+                nodes: 2,
+                pods: 3,
+                pvcs: 4,
+            },
+            ..Default::default()
+        };
+        total.merge(ReapResult {
+            deleted_count: 2,
+            skipped_count: 1,
+            reclaimed_bytes: 50,
+            paused: true,
+            loaded: LoadedCounts {
+                nodes: 1,
+                pods: 1,
+                pvcs: 1,
+            },
+            failed_clusters: vec!["edge-2".to_string()],
+            ..Default::default()
+        });
+
+        assert_eq!(total.deleted_count, 3);
+        assert_eq!(total.skipped_count, 1);
+        assert_eq!(total.reclaimed_bytes, 150);
+        assert!(total.paused);
+        assert_eq!(
+            total.loaded,
+            LoadedCounts {
+                nodes: 3,
+                pods: 4,
+                pvcs: 5,
+            }
+        );
+        assert_eq!(total.failed_clusters, ["edge-2"]);
+    }
+
+    #[test]
+    fn test_pvc_delete_params_precondition() {
+        let params = pvc_delete_params(Some("uid-1".to_string()), false);
+        assert_eq!(
+            params.preconditions.and_then(|p| p.uid).as_deref(),
+            Some("uid-1")
+        );
+        assert!(!params.dry_run);
+        assert!(pvc_delete_params(None, true).preconditions.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_delete_pvc_treats_replaced_claim_as_benign() {
+        let conflict = |_: &str, _: &str| {
+            (
+                409,
+                serde_json::json!({
+                    "kind": "Status",
+                    "apiVersion": "v1",
+                    "status": "Failure",
+                    "message": "Precondition failed: UID in precondition: uid-1, UID in object meta: uid-2",
+                    "reason": "Conflict",
+                    "code": 409,
+                }),
+            )
+        };
+        let (client, _) = mock_api(conflict);
+        assert_eq!(
+            delete_pvc(&client, "default", "data", Some("uid-1".to_string()), false)
+                .await
+                .unwrap(),
+            DeleteOutcome::Replaced
+        );
+        // Without a precondition a conflict is a real error
+        let (client, _) = mock_api(conflict);
+        assert!(
+            delete_pvc(&client, "default", "data", None, false)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_pvc_treats_not_found_as_already_gone() {
+        assert_eq!(
+            delete_pvc(&mock_client(404), "default", "data", None, false)
+                .await
+                .unwrap(),
+            DeleteOutcome::AlreadyGone
+        );
+        assert!(
+            delete_pvc(&mock_client(500), "default", "data", None, false)
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_annotation_selector_parse() {
+        assert_eq!(
+            AnnotationSelector::parse("team=data"),
+            Ok(AnnotationSelector::Equals {
+                key: "team".to_string(),
+                value: "data".to_string()
+            })
+        );
+        assert_eq!(
+            AnnotationSelector::parse(" example.com/tier == scratch "),
+            Ok(AnnotationSelector::Equals {
+                key: "example.com/tier".to_string(),
+                value: "scratch".to_string()
+            })
+        );
+        assert_eq!(
+            AnnotationSelector::parse("team!=infra"),
+            Ok(AnnotationSelector::NotEquals {
+                key: "team".to_string(),
+                value: "infra".to_string()
+            })
+        );
+        assert_eq!(
+            AnnotationSelector::parse("helm.sh/resource-policy"),
+            Ok(AnnotationSelector::Exists {
+                key: "helm.sh/resource-policy".to_string()
+            })
+        );
+        // Empty values are allowed, empty keys are not
+        assert_eq!(
+            AnnotationSelector::parse("team="),
+            Ok(AnnotationSelector::Equals {
+                key: "team".to_string(),
+                value: String::new()
+            })
+        );
+        for bad in ["", "=data", "!=data", "my team=data", "team!"] {
+            let err = AnnotationSelector::parse(bad).unwrap_err();
+            assert!(err.contains(&format!("'{}'", bad.trim())), "{err}");
+        }
+    }
+
+    #[test]
+    fn test_matches_annotation_selector() {
+        let mut config = test_config();
+        let pvc = with_annotation(
+            with_annotation(
+                test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None),
+                "team",
+                "data",
+            ),
+            "scratch",
+            "",
+        );
+        let selector = |terms: &[&str]| -> Vec<AnnotationSelector> {
+            terms
+                .iter()
+                .map(|term| AnnotationSelector::parse(term).unwrap())
+                .collect()
+        };
+
+        // No selector matches everything
+        assert!(matches_annotation_selector(&pvc, &config));
+
+        for (terms, expected) in [
+            (&["team=data"][..], true),
+            (&["team=infra"][..], false),
+            (&["team!=infra"][..], true),
+            (&["team!=data"][..], false),
+            (&["owner!=infra"][..], true),
+            (&["scratch"][..], true),
+            (&["owner"][..], false),
+            (&["team=data", "scratch"][..], true),
+            (&["team=data", "owner"][..], false),
+        ] {
+            config.pvc_annotation_selector = selector(terms);
+            assert_eq!(
+                matches_annotation_selector(&pvc, &config),
+                expected,
+                "{terms:?}"
+            );
+        }
+
+        // The selector gates the overall storage criteria
+        config.pvc_annotation_selector = selector(&["team=infra"]);
+        assert!(!matches_storage_criteria(&pvc, &config));
+        let state = state_with(&[], vec![], vec![pvc.clone()]);
+        assert!(!state.matches_storage_criteria(&pvc, &config));
+        config.pvc_annotation_selector = selector(&["team=data"]);
+        assert!(matches_storage_criteria(&pvc, &config));
+    }
+
+    #[test]
+    fn test_pod_message_regex() {
+        let mut config = test_config();
+        config.pod_message_regex = Some(parse_regex(r"node decommissioned: \S+").unwrap());
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let with_message = |message: &str| {
+            let mut pod =
+                pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
+            pod.status.as_mut().unwrap().conditions.as_mut().unwrap()[0].message =
+                Some(message.to_string());
+            pod
+        };
+
+        let pod = with_message("0/3 nodes are available: node decommissioned: node-7");
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let reason = state.deletion_reason(&pvc, &config).unwrap();
+        assert_eq!(
+            reason,
+            DeleteReason::UnschedulableTooLong {
+                pod: "pending-pod".to_string(),
+                matched: Some("node decommissioned: node-7".to_string())
+            }
+        );
+        assert!(reason.describe().contains("'node decommissioned: node-7'"));
+
+        // The regex replaces the built-in volume heuristics
+        let pod =
+            with_message("0/3 nodes are available: 3 node(s) had volume node affinity conflict.");
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::MessageNotMatched { .. })
+        ));
+
+        // An empty pattern keeps the built-in heuristics
+        config.pod_message_regex = Some(parse_regex("").unwrap());
+        assert!(state.deletion_reason(&pvc, &config).is_ok());
+
+        assert!(parse_regex("node (decommissioned").is_err());
+    }
+
+    #[test]
+    fn test_namespace_terminating() {
+        let config = test_config();
+        let mut tracker = ReapTracker::default();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let mut state = state_with(&[], vec![], vec![pvc.clone()]);
+        assert!(!matches!(
+            state.decide(&pvc, &config, &mut tracker, &mut HashSet::new()),
+            Err(SkipReason::NamespaceTerminating { .. })
+        ));
+
+        state.terminating_namespaces = HashSet::from(["default".to_string()]);
+        let skip = state
+            .decide(&pvc, &config, &mut tracker, &mut HashSet::new())
+            .unwrap_err();
+        assert_eq!(
+            skip,
+            SkipReason::NamespaceTerminating {
+                namespace: "default".to_string()
+            }
+        );
+
+        let mut result = ReapResult::default();
+        result.record_skip("default", "test", &skip);
+        assert_eq!(result.skipped_namespace, 1);
+    }
+
+    #[test]
+    fn test_default_storage_class() {
+        let class = |name: &str, default: Option<&str>, age_secs: i64| StorageClass {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                annotations: default.map(|value| {
+                    BTreeMap::from([(DEFAULT_CLASS_ANNOTATION.to_string(), value.to_string())])
+                }),
+                creation_timestamp: Some(Time(Utc::now() - chrono::Duration::seconds(age_secs))),
+                ..Default::default()
+            },
+            provisioner: "local.csi.openebs.io".to_string(),
+            ..Default::default()
+        };
+
+        // No default
+        assert_eq!(default_storage_class(&[]), None);
+        assert_eq!(
+            default_storage_class(&[
+                class("openebs-lvm", None, 60),
+                class("standard", Some("false"), 60)
+            ]),
+            None
+        );
+
+        assert_eq!(
+            default_storage_class(&[
+                class("standard", None, 60),
+                class("openebs-lvm", Some("true"), 60)
+            ]),
+            Some("openebs-lvm".to_string())
+        );
+
+        // Several defaults: the newest wins
+        assert_eq!(
+            default_storage_class(&[
+                class("standard", Some("true"), 60),
+                class("openebs-lvm", Some("true"), 30),
+                class("legacy", Some("true"), 600)
+            ]),
+            Some("openebs-lvm".to_string())
+        );
+    }
+
+    #[test]
+    fn test_matches_default_storage_class() {
+        let config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None);
+        pvc.spec.as_mut().unwrap().storage_class_name = None;
+        let mut state = state_with(&[], vec![], vec![]);
+        assert!(!state.matches_storage_criteria(&pvc, &config));
+
+        state.default_storage_class = Some("openebs-lvm".to_string());
+        assert!(state.matches_storage_criteria(&pvc, &config));
+
+        // An explicit class is never overridden
+        pvc.spec.as_mut().unwrap().storage_class_name = Some("standard".to_string());
+        assert!(!state.matches_storage_criteria(&pvc, &config));
+
+        state.default_storage_class = Some("standard".to_string());
+        pvc.spec.as_mut().unwrap().storage_class_name = None;
+        assert!(!state.matches_storage_criteria(&pvc, &config));
+    }
+
+    #[test]
+    fn test_node_not_ready() {
+        fn with_ready<'a>(state: &'a mut State, status: &str, offset_secs: i64) -> &'a State {
+            state.nodes[0].status = Some(NodeStatus {
+                conditions: Some(vec![NodeCondition {
+                    type_: "Ready".to_string(),
+                    status: status.to_string(),
+                    last_transition_time: Some(Time(
+                        Utc::now() - chrono::Duration::seconds(offset_secs),
+                    )),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            });
+            state
+        }
+
+        let mut config = test_config();
+        config.node_not_ready_threshold_secs = Some(600);
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 30);
+        let mut state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+
+        // Down past the threshold
+        let reason = with_ready(&mut state, "Unknown", 900)
+            .deletion_reason(&pvc, &config)
+            .unwrap();
+        assert!(matches!(reason, DeleteReason::NodeNotReady { ref node, .. } if node == "node-1"));
+        assert!(matches!(
+            with_ready(&mut state, "False", 900).deletion_reason(&pvc, &config),
+            Ok(DeleteReason::NodeNotReady { .. })
+        ));
+
+        // Down, but not for long enough
+        assert!(matches!(
+            with_ready(&mut state, "Unknown", 60).deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        // Flapped back to Ready before the threshold
+        assert!(matches!(
+            with_ready(&mut state, "True", 30).deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        // Disabled by default
+        config.node_not_ready_threshold_secs = None;
+        assert!(matches!(
+            with_ready(&mut state, "Unknown", 900).deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+    }
+
+    #[test]
+    fn test_node_cordoned() {
+        let mut config = test_config();
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 30);
+        let mut state = state_with(&["node-1", "node-2"], vec![pod], vec![pvc.clone()]);
+        state.nodes[0].spec = Some(NodeSpec {
+            unschedulable: Some(true),
+            ..Default::default()
+        });
+
+        let mut tracker = ReapTracker::default();
+        let first_seen = state.now - chrono::Duration::seconds(7200);
+        state.cordoned_since = tracker.observe_cordoned(&state.nodes, first_seen);
+        assert_eq!(
+            state.cordoned_since.keys().collect::<Vec<_>>(),
+            vec!["node-1"]
+        );
+
+        // Off by default
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        config.reap_on_cordoned_nodes = true;
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::NodeCordoned { ref node, .. }) if node == "node-1"
+        ));
+
+        // Cordoned, but not for long enough
+        config.cordoned_threshold_secs = 3 * 3600;
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        // Uncordoning resets the clock
+        state.nodes[0].spec = None;
+        assert!(tracker.observe_cordoned(&state.nodes, state.now).is_empty());
+    }
+
+    #[test]
+    fn test_taint_matcher_parse() {
+        assert_eq!(
+            TaintMatcher::parse("node.kubernetes.io/out-of-service:NoExecute").unwrap(),
+            TaintMatcher {
+                key: "node.kubernetes.io/out-of-service".to_string(),
+                value: None,
+                effect: "NoExecute".to_string(),
+            }
+        );
+        let with_value = TaintMatcher::parse("lifecycle=dead:NoSchedule").unwrap();
+        assert_eq!(with_value.value.as_deref(), Some("dead"));
+        assert_eq!(with_value.to_string(), "lifecycle=dead:NoSchedule");
+
+        assert!(TaintMatcher::parse("node.kubernetes.io/out-of-service").is_err());
+        assert!(TaintMatcher::parse("lifecycle=dead:Evict").is_err());
+        assert!(TaintMatcher::parse(":NoExecute").is_err());
+    }
+
+    #[test]
+    fn test_node_tainted() {
+        let mut config = test_config();
+        let pvc = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 30);
+        let mut state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let tainted = |key: &str, value: Option<&str>, effect: &str| {
+            Some(NodeSpec {
+                taints: Some(vec![Taint {
+                    key: key.to_string(),
+                    value: value.map(str::to_string),
+                    effect: effect.to_string(),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            })
+        };
+        state.nodes[0].spec = tainted("node.kubernetes.io/out-of-service", None, "NoExecute");
+
+        // Off by default
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::BelowThreshold { .. })
+        ));
+
+        config.reap_node_taints = vec![
+            TaintMatcher::parse("node.kubernetes.io/out-of-service:NoExecute").unwrap(),
+            TaintMatcher::parse("lifecycle=dead:NoSchedule").unwrap(),
+        ];
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::NodeTainted {
+                node: "node-1".to_string(),
+                pod: "pending-pod".to_string(),
+                taint: "node.kubernetes.io/out-of-service:NoExecute".to_string(),
+            })
+        );
+
+        // Mismatched effect
+        state.nodes[0].spec = tainted("node.kubernetes.io/out-of-service", None, "NoSchedule");
+        assert!(state.deletion_reason(&pvc, &config).is_err());
+
+        // Value must match when configured
+        state.nodes[0].spec = tainted("lifecycle", Some("draining"), "NoSchedule");
+        assert!(state.deletion_reason(&pvc, &config).is_err());
+        state.nodes[0].spec = tainted("lifecycle", Some("dead"), "NoSchedule");
+        assert!(matches!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::NodeTainted { .. })
+        ));
+    }
+
+    #[test]
+    fn test_targeted_nodes() {
+        let config = test_config();
+        let on_node =
+            |node: Option<&str>| test_pvc("test", "openebs-lvm", "local.csi.openebs.io", node);
+        let mut state = state_with(&[], vec![], vec![]);
+        assert!(state.is_targeted(&on_node(Some("node-1")), &config));
+        assert!(state.is_targeted(&on_node(None), &config));
+
+        state.target = Some(Target::Nodes(HashSet::from(["node-1".to_string()])));
+        assert!(state.is_targeted(&on_node(Some("node-1")), &config));
+        assert!(!state.is_targeted(&on_node(Some("node-2")), &config));
+        assert!(!state.is_targeted(&on_node(None), &config));
+
+        state.target = Some(Target::Claims(HashSet::from([(
+            "default".to_string(),
+            "test".to_string(),
+        )])));
+        assert!(state.is_targeted(&on_node(None), &config));
+        let mut other = on_node(None);
+        other.metadata.name = Some("other".to_string());
+        assert!(!state.is_targeted(&other, &config));
+    }
+
+    #[test]
+    fn test_missing_workload() {
+        let named = |name: &str| {
+            let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None);
+            pvc.metadata.name = Some(name.to_string());
+            pvc
+        };
+        let mut state = state_with(&[], vec![], vec![]);
+        state.statefulsets = vec![statefulset("web", 1, &["data"])];
+
+        // Claimed by an existing StatefulSet, even beyond its replicas
+        assert_eq!(state.missing_workload(&named("data-web-0")), None);
+        assert_eq!(state.missing_workload(&named("data-web-5")), None);
+        // Not StatefulSet-style names
+        assert_eq!(state.missing_workload(&named("scratch")), None);
+        assert_eq!(state.missing_workload(&named("backup-1")), None);
+        assert_eq!(
+            state.missing_workload(&named("data-db-0")),
+            Some("StatefulSet of 'data-db-<ordinal>'".to_string())
+        );
+
+        // An ownerReference is authoritative
+        let owned_by = |name: &str, owner: &str| {
+            let mut pvc = named(name);
+            pvc.metadata.owner_references = Some(vec![OwnerReference {
+                kind: "StatefulSet".to_string(),
+                name: owner.to_string(),
+                ..Default::default()
+            }]);
+            pvc
+        };
+        assert_eq!(state.missing_workload(&owned_by("data-db-0", "web")), None);
+        assert_eq!(
+            state.missing_workload(&owned_by("data-web-0", "db")),
+            Some("StatefulSet/db".to_string())
+        );
+    }
+
+    #[test]
+    fn test_orphaned_workload_decision() {
+        let mut config = test_config();
+        let mut pvc = test_pvc("data-db-0", "openebs-lvm", "local.csi.openebs.io", None);
+        pvc.metadata.creation_timestamp = Some(Time(Utc::now() - chrono::Duration::hours(2)));
+        let mut state = state_with(&[], vec![], vec![pvc.clone()]);
+        assert_eq!(state.orphaned_workload_decision(&pvc, &config), None);
+
+        config.reap_orphaned_workload_pvcs = true;
+        assert!(matches!(
+            state.orphaned_workload_decision(&pvc, &config),
+            Some(Err(SkipReason::OrphanTooYoung { .. }))
+        ));
+
+        config.orphaned_workload_min_age_secs = 3600;
+        let reason = state
+            .orphaned_workload_decision(&pvc, &config)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            reason,
+            DeleteReason::WorkloadDeleted {
+                owner: "StatefulSet of 'data-db-<ordinal>'".to_string()
+            }
+        );
+        assert!(reason.describe().contains("data-db-<ordinal>"));
+
+        // Never while any pod references the claim
+        state.pods = vec![pod_with_pvc("db-0", "data-db-0", "Succeeded", None, 60)];
+        assert_eq!(state.orphaned_workload_decision(&pvc, &config), None);
+    }
+
+    #[test]
+    fn test_released_pv_age() {
+        use k8s_openapi::api::core::v1::{
+            CSIPersistentVolumeSource, ObjectReference, PersistentVolumeSpec,
+            PersistentVolumeStatus,
+        };
+
+        let mut config = test_config();
+        let pv =
+            |phase: &str, driver: &str, claim_uid: &str, released_secs: i64| PersistentVolume {
+                metadata: ObjectMeta {
+                    name: Some("pvc-1234".to_string()),
+                    ..Default::default()
+                },
+                spec: Some(PersistentVolumeSpec {
+                    csi: Some(CSIPersistentVolumeSource {
+                        driver: driver.to_string(),
+                        ..Default::default()
+                    }),
+                    claim_ref: Some(ObjectReference {
+                        namespace: Some("default".to_string()),
+                        name: Some("data".to_string()),
+                        uid: Some(claim_uid.to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                status: Some(PersistentVolumeStatus {
+                    phase: Some(phase.to_string()),
+                    last_phase_transition_time: Some(Time(
+                        Utc::now() - chrono::Duration::seconds(released_secs),
+                    )),
+                    ..Default::default()
+                }),
+            };
+        let claim = with_uid(
+            test_pvc("data", "openebs-lvm", "local.csi.openebs.io", None),
+            "uid-1",
+        );
+        let state = state_with(&[], vec![], vec![claim]);
+
+        let released = pv("Released", "local.csi.openebs.io", "uid-gone", 7200);
+        assert!(
+            state
+                .released_pv_age(&released, &config)
+                .is_some_and(|secs| secs >= 7100)
+        );
+
+        // Released too recently
+        config.released_pv_min_age_secs = 3 * 3600;
+        assert_eq!(state.released_pv_age(&released, &config), None);
+        config.released_pv_min_age_secs = 3600;
+
+        // Not released, another driver, or the claim still exists
+        for other in [
+            pv("Bound", "local.csi.openebs.io", "uid-gone", 7200),
+            pv("Released", "ebs.csi.aws.com", "uid-gone", 7200),
+            pv("Released", "local.csi.openebs.io", "uid-1", 7200),
+        ] {
+            assert_eq!(state.released_pv_age(&other, &config), None);
+        }
+
+        // In-tree PVs fall back to the provisioned-by annotation
+        let mut annotated = released.clone();
+        annotated.spec.as_mut().unwrap().csi = None;
+        assert_eq!(state.released_pv_age(&annotated, &config), None);
+        annotated.metadata.annotations = Some(BTreeMap::from([(
+            PROVISIONED_BY_ANNOTATION.to_string(),
+            "local.csi.openebs.io".to_string(),
+        )]));
+        assert!(state.released_pv_age(&annotated, &config).is_some());
+    }
+
+    #[test]
+    fn test_pv_pinned_node() {
+        use k8s_openapi::api::core::v1::{
+            NodeSelector, NodeSelectorRequirement, NodeSelectorTerm, PersistentVolumeSpec,
+            VolumeNodeAffinity,
+        };
+
+        let expr = |key: &str, values: &[&str]| NodeSelectorRequirement {
+            key: key.to_string(),
+            operator: "In".to_string(),
+            values: Some(values.iter().map(|v| v.to_string()).collect()),
+        };
+        let pv = |terms: Vec<Vec<NodeSelectorRequirement>>| PersistentVolume {
+            metadata: ObjectMeta {
+                name: Some("pvc-1234".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PersistentVolumeSpec {
+                node_affinity: Some(VolumeNodeAffinity {
+                    required: Some(NodeSelector {
+                        node_selector_terms: terms
+                            .into_iter()
+                            .map(|exprs| NodeSelectorTerm {
+                                match_expressions: Some(exprs),
+                                ..Default::default()
+                            })
+                            .collect(),
+                    }),
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let pinned = pv(vec![vec![
+            expr("topology.kubernetes.io/zone", &["eu-west-1a"]),
+            expr(HOSTNAME_LABEL, &["gone"]),
+        ]]);
+        assert_eq!(pv_pinned_hostname(&pinned), Some("gone"));
+        // Other topology keys are ignored
+        assert_eq!(
+            pv_pinned_hostname(&pv(vec![vec![expr(
+                "topology.openebs.io/nodename",
+                &["gone"]
+            )]])),
+            None
+        );
+        // Several hostnames pin nothing
+        assert_eq!(
+            pv_pinned_hostname(&pv(vec![vec![expr(HOSTNAME_LABEL, &["node-1", "node-2"])]])),
+            None
+        );
+        assert_eq!(
+            pv_pinned_hostname(&pv(vec![
+                vec![expr(HOSTNAME_LABEL, &["node-1"])],
+                vec![expr(HOSTNAME_LABEL, &["node-2"])]
+            ])),
+            None
+        );
+
+        // Bound PVCs without the annotation fall back to their PV
+        let config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", None);
+        let mut state = state_with(&["node-1"], vec![], vec![]);
+        state.pvs = vec![pinned];
+        assert_eq!(state.missing_node(&pvc, &config), None);
+        pvc.spec.as_mut().unwrap().volume_name = Some("pvc-1234".to_string());
+        assert_eq!(state.missing_node(&pvc, &config), Some("gone".to_string()));
+
+        // The annotation wins when present
+        let mut annotated = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        annotated.spec.as_mut().unwrap().volume_name = Some("pvc-1234".to_string());
+        assert_eq!(state.missing_node(&annotated, &config), None);
+    }
+
+    #[test]
+    fn test_node_missing_cycles() {
+        let config = ReaperConfig {
+            node_missing_cycles: 3,
+            ..test_config()
+        };
+        let pvc = test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let pod = pod_with_pvc("data-0", "data", "Pending", Some("Unschedulable"), 600);
+        let mut state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let mut tracker = ReapTracker::default();
+        let start = state.now;
+
+        for cycle in 1..=2 {
+            state.missing_nodes =
+                tracker.observe_missing_nodes(["gone".to_string()].into(), state.now);
+            assert_eq!(
+                state.deletion_reason(&pvc, &config),
+                Err(SkipReason::NodeRecentlyMissing {
+                    node: "gone".to_string(),
+                    cycles: cycle,
+                    required: 3,
+                })
+            );
+            state.now += chrono::Duration::seconds(60);
+        }
+        state.missing_nodes = tracker.observe_missing_nodes(["gone".to_string()].into(), state.now);
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Ok(DeleteReason::MissingNode {
+                node: "gone".to_string(),
+                pod: "data-0".to_string(),
+                missing_secs: 120,
+            })
+        );
+
+        // A node that reappears starts over
+        tracker.observe_missing_nodes(HashSet::new(), state.now);
+        state.missing_nodes = tracker.observe_missing_nodes(["gone".to_string()].into(), state.now);
+        assert_eq!(
+            state.missing_nodes["gone"],
+            (1, start + chrono::Duration::seconds(120))
+        );
+        assert_eq!(state.missing_node(&pvc, &config), None);
+
+        // Targeted runs triggered by the watches don't count as cycles
+        state.target = Some(Target::Nodes(["gone".to_string()].into()));
+        for _ in 0..3 {
+            state.track_missing_nodes(&config, &mut tracker);
+        }
+        assert_eq!(state.missing_nodes["gone"].0, 1);
+        state.target = None;
+        state.track_missing_nodes(&config, &mut tracker);
+        assert_eq!(state.missing_nodes["gone"].0, 2);
+    }
+
+    #[test]
+    fn test_evaluation_span_fields() {
+        use std::sync::{Arc, Mutex};
+        use tracing::{
+            field::{Field, Visit},
+            span::{Attributes, Id},
+        };
+        use tracing_subscriber::{layer::Context, prelude::*};
+
+        #[derive(Clone, Default)]
+        struct SpanFields(Arc<Mutex<Vec<(String, String)>>>);
+
+        impl Visit for SpanFields {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                let mut fields = self.0.lock().unwrap();
+                fields.push((field.name().to_string(), format!("{value:?}")));
+            }
+
+            fn record_str(&mut self, field: &Field, value: &str) {
+                let mut fields = self.0.lock().unwrap();
+                fields.push((field.name().to_string(), value.to_string()));
+            }
+        }
+
+        impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for SpanFields {
+            fn on_new_span(&self, attrs: &Attributes<'_>, _: &Id, _: Context<'_, S>) {
+                if attrs.metadata().name() == "pvc" {
+                    attrs.record(&mut self.clone());
+                }
+            }
+        }
+
+        let config = test_config();
+        let pvc = test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let pod = pod_with_pvc("data-0", "data", "Pending", Some("Unschedulable"), 600);
+        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let fields = SpanFields::default();
+        let subscriber = tracing_subscriber::registry().with(fields.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            let mut pass = EvaluationPass::default();
+            state.evaluate(&pvc, &config, &mut ReapTracker::default(), &mut pass);
+        });
+
+        let fields = fields.0.lock().unwrap();
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|(field, _)| field == name)
+                .map(|(_, value)| value.as_str())
+        };
+        assert_eq!(field("namespace"), Some("default"));
+        assert_eq!(field("pvc"), Some("data"));
+        assert_eq!(field("node"), Some("gone"));
+    }
+
+    #[test]
+    fn test_unreferenced_missing_node() {
+        let mut config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        pvc.metadata.creation_timestamp = Some(Time(Utc::now() - chrono::Duration::hours(12)));
+        let mut state = state_with(&["node-1"], vec![], vec![pvc.clone()]);
+
+        // Off by default: no consuming pod means no deletion
+        assert_eq!(
+            state.deletion_reason(&pvc, &config),
+            Err(SkipReason::NoConsumingPod)
+        );
+        assert_eq!(
+            state.unreferenced_missing_node_decision(&pvc, &config),
+            None
+        );
+
+        config.reap_unreferenced_missing_node_pvcs = true;
+        assert_eq!(
+            state.unreferenced_missing_node_decision(&pvc, &config),
+            Some(Ok(DeleteReason::MissingNodeUnreferenced {
+                node: "gone".to_string()
+            }))
+        );
+
+        config.unreferenced_missing_node_min_age_secs = 86400;
+        assert!(matches!(
+            state.unreferenced_missing_node_decision(&pvc, &config),
+            Some(Err(SkipReason::UnreferencedTooYoung { .. }))
+        ));
+
+        // The node exists
+        let on_node = test_pvc(
+            "test",
+            "openebs-lvm",
+            "local.csi.openebs.io",
+            Some("node-1"),
+        );
+        assert_eq!(
+            state.unreferenced_missing_node_decision(&on_node, &config),
+            None
+        );
+
+        // A consuming pod routes the PVC through the regular checks
+        state.pods = vec![pod_with_pvc(
+            "pending-pod",
+            "test",
+            "Pending",
+            Some("Unschedulable"),
+            30,
+        )];
+        assert_eq!(
+            state.unreferenced_missing_node_decision(&pvc, &config),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stuck_pod() {
+        let config = test_config();
+        let pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let mut bare = pod_with_pvc("bare-pod", "test", "Pending", Some("Unschedulable"), 30);
+        bare.metadata.owner_references = None;
+        let state = state_with(
+            &[],
+            vec![
+                pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 30),
+                bare,
+            ],
+            vec![pvc.clone()],
+        );
+        let missing_node = |pod: &str| DeleteReason::MissingNode {
+            node: "gone".to_string(),
+            pod: pod.to_string(),
+            missing_secs: 0,
+        };
+
+        assert_eq!(
+            state
+                .stuck_pod(&pvc, &missing_node("pending-pod"), &config)
+                .map(ResourceExt::name_any),
+            Some("pending-pod".to_string())
+        );
+        assert!(
+            state
+                .stuck_pod(&pvc, &missing_node("bare-pod"), &config)
+                .is_none()
+        );
+        assert!(
+            state
+                .stuck_pod(
+                    &pvc,
+                    &DeleteReason::ScaledDownStatefulSet {
+                        sts: "db".to_string(),
+                        ordinal: 3
+                    },
+                    &config
+                )
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_delete_bound_pv() {
+        let static_pv = |claim_uid: &str| {
+            serde_json::json!({
+                "apiVersion": "v1",
+                "kind": "PersistentVolume",
+                "metadata": {"name": "pv-static", "uid": "pv-uid", "resourceVersion": "42"},
+                "spec": {
+                    "capacity": {"storage": "1Gi"},
+                    "persistentVolumeReclaimPolicy": "Retain",
+                    "claimRef": {"namespace": "default", "name": "data", "uid": claim_uid},
+                    "csi": {"driver": "local.csi.openebs.io", "volumeHandle": "vol-1"}
+                },
+                "status": {"phase": "Released"}
+            })
+        };
+
+        // Still bound to the reaped claim: deleted
+        let pv = static_pv("uid-1");
+        let (client, requests) = mock_api(move |_, _| (200, pv.clone()));
+        assert_eq!(
+            delete_bound_pv(&client, "pv-static", "uid-1")
+                .await
+                .unwrap(),
+            DeleteOutcome::Deleted
+        );
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "GET /api/v1/persistentvolumes/pv-static",
+                "DELETE /api/v1/persistentvolumes/pv-static"
+            ]
+        );
+
+        // Rebound to another claim: left alone
+        let pv = static_pv("uid-2");
+        let (client, requests) = mock_api(move |_, _| (200, pv.clone()));
+        assert!(
+            delete_bound_pv(&client, "pv-static", "uid-1")
+                .await
+                .is_err()
+        );
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // Already gone
+        let (client, _) = mock_api(|_, _| {
+            (
+                404,
+                serde_json::json!({"kind": "Status", "apiVersion": "v1", "status": "Failure", "reason": "NotFound", "code": 404}),
+            )
+        });
+        assert_eq!(
+            delete_bound_pv(&client, "pv-static", "uid-1")
+                .await
+                .unwrap(),
+            DeleteOutcome::AlreadyGone
+        );
+    }
+
+    #[test]
+    fn test_fresh_claim() {
+        let config = test_config();
+        let mut pvc = with_uid(
+            test_pvc(
+                "data-db-0",
+                "openebs-lvm",
+                "local.csi.openebs.io",
+                Some("gone"),
+            ),
+            "uid-1",
+        );
+        pvc.metadata.resource_version = Some("42".to_string());
+        pvc.metadata.labels = Some(BTreeMap::from([("app".to_string(), "db".to_string())]));
+        let annotations = pvc.metadata.annotations.as_mut().unwrap();
+        annotations.insert(
+            "pv.kubernetes.io/bind-completed".to_string(),
+            "yes".to_string(),
+        );
+        annotations.insert("team".to_string(), "storage".to_string());
+        pvc.spec.as_mut().unwrap().volume_name = Some("pvc-1234".to_string());
+
+        let fresh = fresh_claim(&pvc, &config);
+        assert_eq!(fresh.metadata.name.as_deref(), Some("data-db-0"));
+        assert_eq!(fresh.metadata.namespace.as_deref(), Some("default"));
+        assert_eq!(fresh.metadata.labels, pvc.metadata.labels);
+        assert_eq!(fresh.metadata.uid, None);
+        assert_eq!(fresh.metadata.resource_version, None);
+        assert_eq!(
+            fresh.metadata.annotations,
+            Some(BTreeMap::from([(
+                "team".to_string(),
+                "storage".to_string()
+            )]))
+        );
+        let spec = fresh.spec.unwrap();
+        assert_eq!(spec.volume_name, None);
+        assert_eq!(spec.storage_class_name.as_deref(), Some("openebs-lvm"));
+        assert!(fresh.status.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_recreate_pvc() {
+        let config = test_config();
+        let old = with_uid(
+            test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone")),
+            "uid-1",
+        );
+        let fresh = fresh_claim(&old, &config);
+        let not_found = serde_json::json!({"kind": "Status", "apiVersion": "v1", "status": "Failure", "reason": "NotFound", "code": 404});
+        let created = serde_json::to_value(&fresh).unwrap();
+
+        // Deleted, then created
+        let (client, requests) = {
+            let (not_found, created) = (not_found.clone(), created.clone());
+            mock_api(move |method, _| match method {
+                "GET" => (404, not_found.clone()),
+                _ => (201, created.clone()),
+            })
+        };
+        recreate_pvc(&client, &old, &fresh, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![
+                "GET /api/v1/namespaces/default/persistentvolumeclaims/data",
+                "POST /api/v1/namespaces/default/persistentvolumeclaims"
+            ]
+        );
+
+        // The controller got there first
+        let conflict = serde_json::json!({"kind": "Status", "apiVersion": "v1", "status": "Failure", "reason": "AlreadyExists", "code": 409});
+        let (client, _) = mock_api(move |method, _| match method {
+            "GET" => (404, not_found.clone()),
+            _ => (409, conflict.clone()),
+        });
+        recreate_pvc(&client, &old, &fresh, Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        // Never disappears
+        let stuck = serde_json::to_value(&old).unwrap();
+        let (client, _) = mock_api(move |_, _| (200, stuck.clone()));
+        assert!(
+            recreate_pvc(&client, &old, &fresh, Duration::ZERO)
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_stuck_terminating() {
+        let mut config = test_config();
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        pvc.metadata.finalizers = Some(vec!["kubernetes.io/pvc-protection".to_string()]);
+        let mut state = state_with(&["node-1"], vec![], vec![]);
+        pvc.metadata.deletion_timestamp = Some(Time(state.now - chrono::Duration::seconds(900)));
+
+        // Off by default
+        assert_eq!(state.stuck_terminating(&pvc, &config), None);
+
+        config.force_remove_finalizers_after_secs = Some(600);
+        assert_eq!(
+            state.stuck_terminating(&pvc, &config),
+            Some(("gone".to_string(), 900))
+        );
+
+        // Not terminating for long enough
+        config.force_remove_finalizers_after_secs = Some(1800);
+        assert_eq!(state.stuck_terminating(&pvc, &config), None);
+        config.force_remove_finalizers_after_secs = Some(600);
+
+        // The node must be confirmed missing
+        state.node_names.insert("gone".to_string());
+        assert_eq!(state.stuck_terminating(&pvc, &config), None);
+        state.node_names.remove("gone");
+
+        // Nothing to remove, or protected
+        let mut no_finalizers = pvc.clone();
+        no_finalizers.metadata.finalizers = None;
+        assert_eq!(state.stuck_terminating(&no_finalizers, &config), None);
+        let mut protected = pvc.clone();
+        protected.metadata.annotations.as_mut().unwrap().insert(
+            DEFAULT_PROTECTION_ANNOTATION.to_string(),
+            "true".to_string(),
+        );
+        assert_eq!(state.stuck_terminating(&protected, &config), None);
+    }
+
+    #[test]
+    fn test_finalizer_patch() {
+        let mut pvc = test_pvc("test", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        pvc.metadata.resource_version = Some("42".to_string());
+        assert_eq!(
+            finalizer_patch(&pvc),
+            serde_json::json!({"metadata": {"finalizers": null, "resourceVersion": "42"}})
+        );
+
+        let event = finalizers_removed_event("kubernetes.io/pvc-protection", "gone", 900);
+        assert_eq!(event.type_, EventType::Warning);
+        assert_eq!(event.reason, FINALIZERS_REMOVED_EVENT_REASON);
+        assert!(event.note.unwrap().contains("'gone'"));
+    }
+
+    #[test]
+    fn test_stale_attachment_age() {
+        use k8s_openapi::api::storage::v1::VolumeAttachmentSpec;
+
+        let mut config = test_config();
+        let mut state = state_with(&["node-1"], vec![], vec![]);
+        let attachment = |attacher: &str, node: &str, age_secs: i64| VolumeAttachment {
+            metadata: ObjectMeta {
+                name: Some("csi-123".to_string()),
+                creation_timestamp: Some(Time(state.now - chrono::Duration::seconds(age_secs))),
+                ..Default::default()
+            },
+            spec: VolumeAttachmentSpec {
+                attacher: attacher.to_string(),
+                node_name: node.to_string(),
+                ..Default::default()
+            },
+            status: None,
+        };
+
+        assert_eq!(
+            state.stale_attachment_age(&attachment("local.csi.openebs.io", "gone", 1000), &config),
+            Some(1000)
+        );
+        // Node still exists
+        assert_eq!(
+            state
+                .stale_attachment_age(&attachment("local.csi.openebs.io", "node-1", 1000), &config),
+            None
+        );
+        // Another driver's attachment
+        assert_eq!(
+            state.stale_attachment_age(&attachment("ebs.csi.aws.com", "gone", 1000), &config),
+            None
+        );
+        // Too young
+        config.volume_attachment_min_age_secs = 3600;
+        assert_eq!(
+            state.stale_attachment_age(&attachment("local.csi.openebs.io", "gone", 1000), &config),
+            None
+        );
+
+        // The node must stay missing for --node-missing-cycles, like a PVC's
+        config.volume_attachment_min_age_secs = 0;
+        config.node_missing_cycles = 2;
+        state.volume_attachments = vec![attachment("local.csi.openebs.io", "gone", 1000)];
+        assert_eq!(state.absent_nodes(&config), ["gone".to_string()].into());
+        let mut tracker = ReapTracker::default();
+        state.missing_nodes = tracker.observe_missing_nodes(state.absent_nodes(&config), state.now);
+        assert_eq!(
+            state.stale_attachment_age(&attachment("local.csi.openebs.io", "gone", 1000), &config),
+            None
+        );
+        state.missing_nodes = tracker.observe_missing_nodes(state.absent_nodes(&config), state.now);
+        assert_eq!(
+            state.stale_attachment_age(&attachment("local.csi.openebs.io", "gone", 1000), &config),
+            Some(1000)
+        );
+    }
+
+    #[test]
+    fn test_finished_consumers_decision() {
+        use k8s_openapi::api::core::v1::{
+            ContainerState, ContainerStateTerminated, ContainerStatus,
+        };
+
+        let finished = |name: &str, phase: &str, finished_secs: i64| {
+            let mut pod = pod_with_pvc(name, "data", phase, None, 30 * 86400);
+            pod.status.as_mut().unwrap().container_statuses = Some(vec![ContainerStatus {
+                name: "main".to_string(),
+                state: Some(ContainerState {
+                    terminated: Some(ContainerStateTerminated {
+                        finished_at: Some(Time(
+                            Utc::now() - chrono::Duration::seconds(finished_secs),
+                        )),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }]);
+            pod
+        };
+
+        let mut config = test_config();
+        let pvc = test_pvc("data", "openebs-lvm", "local.csi.openebs.io", None);
+        let mut state = state_with(
+            &[],
+            vec![
+                finished("job-a", "Succeeded", 3 * 86400),
+                finished("job-b", "Failed", 2 * 86400),
+            ],
+            vec![pvc.clone()],
+        );
+        assert_eq!(state.finished_consumers_decision(&pvc, &config), None);
+
+        config.reap_finished_consumer_pvcs = true;
+        match state.finished_consumers_decision(&pvc, &config) {
+            Some(Ok(DeleteReason::ConsumersFinished { pod, finished_secs })) => {
+                assert_eq!(pod, "job-b");
+                assert!(finished_secs >= 2 * 86400);
+            }
+            other => panic!("unexpected decision {:?}", other),
+        }
+
+        // Measured from the newest completion, not the pods' creation
+        config.finished_pod_threshold_secs = 7 * 86400;
+        assert!(matches!(
+            state.finished_consumers_decision(&pvc, &config),
+            Some(Err(SkipReason::FinishedTooRecently { .. }))
+        ));
+        config.finished_pod_threshold_secs = 86400;
+
+        // Falls back to the conditions when no container reports a termination time
+        let mut by_condition = pod_with_pvc("job-c", "data", "Succeeded", None, 30 * 86400);
+        by_condition.status.as_mut().unwrap().conditions = Some(vec![PodCondition {
+            type_: "Ready".to_string(),
+            status: "False".to_string(),
+            reason: Some("PodCompleted".to_string()),
+            last_transition_time: Some(Time(Utc::now() - chrono::Duration::seconds(600))),
+            ..Default::default()
+        }]);
+        state.pods.push(by_condition);
+        assert!(matches!(
+            state.finished_consumers_decision(&pvc, &config),
+            Some(Err(SkipReason::FinishedTooRecently { ref pod, .. })) if pod == "job-c"
+        ));
+        state.pods.pop();
+
+        // Never while any consumer is pending or running
+        for phase in ["Pending", "Running"] {
+            state
+                .pods
+                .push(pod_with_pvc("job-d", "data", phase, None, 60));
+            assert_eq!(state.finished_consumers_decision(&pvc, &config), None);
+            state.pods.pop();
+        }
+
+        // Unknown completion time or no consumers at all
+        state
+            .pods
+            .push(pod_with_pvc("job-e", "data", "Succeeded", None, 30 * 86400));
+        assert_eq!(state.finished_consumers_decision(&pvc, &config), None);
+        state.pods.clear();
+        assert_eq!(state.finished_consumers_decision(&pvc, &config), None);
+    }
+
+    #[test]
+    fn test_lvmvolume_missing_owner() {
+        let mut config = test_config();
+        let mut state = state_with(&["node-1"], vec![], vec![]);
+        let volume = |owner: &str| -> DynamicObject {
+            serde_json::from_value(serde_json::json!({
+                "apiVersion": "local.openebs.io/v1alpha1",
+                "kind": "LVMVolume",
+                "metadata": {"name": "pvc-1234", "namespace": "openebs"},
+                "spec": {"ownerNodeID": owner, "volGroup": "lvmvg", "capacity": "1073741824"},
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(
+            state.lvmvolume_missing_owner(&volume("gone"), &config),
+            Some("gone".to_string())
+        );
+        assert_eq!(
+            state.lvmvolume_missing_owner(&volume("node-1"), &config),
+            None
+        );
+
+        let mut terminating = volume("gone");
+        terminating.metadata.deletion_timestamp = Some(Time(Utc::now()));
+        assert_eq!(state.lvmvolume_missing_owner(&terminating, &config), None);
+
+        // The owner must stay missing for --node-missing-cycles, like a PVC's node
+        config.node_missing_cycles = 2;
+        state.lvmvolumes = vec![volume("gone"), volume("node-1")];
+        assert_eq!(state.absent_nodes(&config), ["gone".to_string()].into());
+        let mut tracker = ReapTracker::default();
+        state.missing_nodes = tracker.observe_missing_nodes(state.absent_nodes(&config), state.now);
+        assert_eq!(
+            state.lvmvolume_missing_owner(&volume("gone"), &config),
+            None
+        );
+        state.missing_nodes = tracker.observe_missing_nodes(state.absent_nodes(&config), state.now);
+        assert_eq!(
+            state.lvmvolume_missing_owner(&volume("gone"), &config),
+            Some("gone".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_lvmvolumes_disabled_without_crd() {
+        let (client, requests) = mock_api(|_, _| {
+            (
+                404,
+                serde_json::json!({
+                    "kind": "Status",
+                    "apiVersion": "v1",
+                    "status": "Failure",
+                    "reason": "NotFound",
+                    "code": 404,
+                }),
+            )
+        });
+        let mut tracker = ReapTracker::default();
+        assert!(list_lvmvolumes(&client, &mut tracker).await.is_empty());
+
+        assert!(tracker.lvmvolume_crd_missing);
+        assert_eq!(
+            *requests.lock().unwrap(),
+            ["GET /apis/local.openebs.io/v1alpha1/lvmvolumes"]
+        );
+    }
+
+    #[test]
+    fn test_threshold_delays() {
+        let mut config = test_config();
+        config.unschedulable_pod_threshold_secs = 300;
+        config.class_thresholds = vec![
+            ClassThreshold::parse("fast=600").unwrap(),
+            ClassThreshold::parse("slow=300").unwrap(),
+        ];
+        let pod = pod_with_pvc("pod", "data", "Pending", Some("Unschedulable"), 3600);
+        let since = unschedulable_condition(&pod)
+            .and_then(|cond| cond.last_transition_time.clone())
+            .unwrap();
+
+        let now = since.0 + chrono::Duration::seconds(100);
+        assert_eq!(
+            threshold_delays(&pod, &config, now),
+            [Duration::from_secs(200), Duration::from_secs(500)]
+        );
+
+        let now = since.0 + chrono::Duration::seconds(400);
+        assert_eq!(
+            threshold_delays(&pod, &config, now),
+            [Duration::from_secs(200)]
+        );
+
+        let now = since.0 + chrono::Duration::seconds(600);
+        assert!(threshold_delays(&pod, &config, now).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_cycles() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.confirmation_cycles = 3;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let mut stuck = test_pvc("stuck", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        stuck.metadata.uid = Some("uid-stuck".to_string());
+        let stuck_pod = pod_with_pvc("stuck-0", "stuck", "Pending", Some("Unschedulable"), 600);
+        let mut tracker = ReapTracker::default();
+
+        let stuck_state = || state_with(&["node-1"], vec![stuck_pod.clone()], vec![stuck.clone()]);
+
+        // Targeted runs from the watches see the count but don't advance it
+        let mut targeted = stuck_state();
+        targeted.target = Some(Target::Nodes(["gone".to_string()].into()));
+        let result = targeted.reap(&client, &config, &mut tracker).await.unwrap();
+        assert!(matches!(
+            result.skipped[0].reason,
+            SkipReason::AwaitingConfirmation { seen: 0, .. }
+        ));
+
+        for seen in 1..=2 {
+            let result = stuck_state()
+                .reap(&client, &config, &mut tracker)
+                .await
+                .unwrap();
+            assert_eq!(result.would_delete_count, 0);
+            assert!(matches!(
+                &result.skipped[0].reason,
+                SkipReason::AwaitingConfirmation { seen: s, required: 3, .. } if *s == seen
+            ));
+        }
+
+        // A cycle where the node is back and the pod was only just rescheduled resets the count
+        let recovered = state_with(
+            &["node-1", "gone"],
+            vec![pod_with_pvc(
+                "stuck-0",
+                "stuck",
+                "Pending",
+                Some("Unschedulable"),
+                10,
+            )],
+            vec![stuck.clone()],
+        );
+        recovered
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        let result = stuck_state()
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        assert!(matches!(
+            result.skipped[0].reason,
+            SkipReason::AwaitingConfirmation { seen: 1, .. }
+        ));
+
+        stuck_state()
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        let result = stuck_state()
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 1);
+
+        // Entries for PVCs that no longer exist are dropped
+        state_with(&["node-1"], vec![], vec![])
+            .reap(&client, &config, &mut tracker)
+            .await
+            .unwrap();
+        assert!(tracker.confirmations.is_empty());
+    }
+
+    #[test]
+    fn test_take_deletion_token() {
+        let mut tracker = ReapTracker::default();
+        let start = chrono::Utc::now();
+        let take = |tracker: &mut ReapTracker, secs: i64| {
+            tracker.take_deletion_token(2, start + chrono::Duration::seconds(secs))
+        };
+
+        // A full bucket allows a minute's worth at once
+        assert!(take(&mut tracker, 0));
+        assert!(take(&mut tracker, 0));
+        assert!(!take(&mut tracker, 0));
+        // Refilled at two per minute
+        assert!(take(&mut tracker, 30));
+        assert!(!take(&mut tracker, 30));
+        // But never beyond its capacity
+        assert!(take(&mut tracker, 600));
+        assert!(take(&mut tracker, 600));
+        assert!(!take(&mut tracker, 600));
+    }
+
+    #[tokio::test]
+    async fn test_deletions_per_minute_across_cycles() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.deletions_per_minute = Some(2);
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let state = || {
+            state_with(
+                &["node-1"],
+                ["a", "b", "c"]
+                    .iter()
+                    .map(|name| {
+                        pod_with_pvc(
+                            &format!("{}-0", name),
+                            name,
+                            "Pending",
+                            Some("Unschedulable"),
+                            600,
+                        )
+                    })
+                    .collect(),
+                ["a", "b", "c"]
+                    .iter()
+                    .map(|name| test_pvc(name, "openebs-lvm", "local.csi.openebs.io", Some("gone")))
+                    .collect(),
+            )
+        };
+        let mut tracker = ReapTracker::default();
+
+        let result = state().reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!(result.would_delete_count, 2);
+        assert_eq!(result.deferred_count, 1);
+        assert_eq!(result.skipped[0].reason.variant(), "RateLimited");
+
+        // Dry-run used up the tokens too, so the preview matches a real run
+        let mut soon = state();
+        soon.now += chrono::Duration::seconds(30);
+        let result = soon.reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!(result.would_delete_count, 1);
+        assert_eq!(result.deferred_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_max_deletions_per_cycle_oldest_first() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.max_deletions_per_cycle = 2;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let stuck = |name: &str, age_secs: i64| {
+            let mut pvc = test_pvc(name, "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+            pvc.metadata.creation_timestamp = Some(Time(
+                chrono::Utc::now() - chrono::Duration::seconds(age_secs),
+            ));
+            pvc
+        };
+        // Listed newest first; the cap must still take the oldest two
+        let state = || {
+            state_with(
+                &["node-1"],
+                ["new", "old", "middle"]
+                    .iter()
+                    .map(|name| {
+                        pod_with_pvc(
+                            &format!("{}-0", name),
+                            name,
+                            "Pending",
+                            Some("Unschedulable"),
+                            600,
+                        )
+                    })
+                    .collect(),
+                vec![stuck("new", 100), stuck("old", 300), stuck("middle", 200)],
+            )
+        };
+
+        let mut tracker = ReapTracker::default();
+        let result = state().reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!(result.would_delete_count, 2);
+        assert_eq!(
+            result
+                .deleted
+                .iter()
+                .map(|pvc| pvc.name.as_str())
+                .collect::<Vec<_>>(),
+            ["old", "middle"]
+        );
+        assert_eq!(result.deferred_count, 1);
+        assert_eq!(result.skipped[0].name, "new");
+        assert_eq!(result.skipped[0].reason.variant(), "DeletionLimitReached");
+
+        // A watch-triggered run before the next cycle draws on the same budget
+        let mut targeted = state();
+        targeted.target = Some(Target::Nodes(["gone".to_string()].into()));
+        let result = targeted.reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!((result.would_delete_count, result.deferred_count), (0, 3));
+
+        let result = state().reap(&client, &config, &mut tracker).await.unwrap();
+        assert_eq!(result.would_delete_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_server_side_dry_run() {
+        let mut config = test_config();
+        config.dry_run = true;
+        let state = || {
+            state_with(
+                &["node-1"],
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![test_pvc(
+                    "data",
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("gone"),
+                )],
+            )
+        };
+
+        // Client mode never calls the delete endpoint
+        let (client, requests) = mock_api(|_, _| (200, serde_json::json!({})));
+        let result = state()
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 1);
+        assert!(
+            !requests
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|r| r.starts_with("DELETE"))
+        );
+
+        config.dry_run_mode = DryRunMode::Server;
+        let (client, requests) = mock_api(|_, _| (200, deleted_status()));
+        let result = state()
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 1);
+        assert!(
+            requests
+                .lock()
+                .unwrap()
+                .contains(&"DELETE /api/v1/namespaces/default/persistentvolumeclaims/data".into())
+        );
+
+        // A rejection from RBAC or an admission webhook is a failed delete, not a would-delete
+        let (client, _) = mock_api(|method, _| match method {
+            "DELETE" => (
+                403,
+                serde_json::json!({
+                    "kind": "Status",
+                    "apiVersion": "v1",
+                    "status": "Failure",
+                    "message": "forbidden",
+                    "reason": "Forbidden",
+                    "code": 403,
+                }),
+            ),
+            _ => (200, serde_json::json!({})),
+        });
+        let result = state()
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 0);
     }
 
-    fn test_config() -> ReaperConfig {
-        ReaperConfig {
-            storage_classes: vec!["openebs-lvm".to_string()],
-            storage_provisioner: "local.csi.openebs.io".to_string(),
-            reap_interval_secs: 60,
-            dry_run: false,
-            check_unschedulable_pods: true,
-            unschedulable_pod_threshold_secs: 120,
+    #[tokio::test]
+    async fn test_pvc_revalidated_before_delete() {
+        let config = test_config();
+        let evaluated = with_uid(
+            test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone")),
+            "uid-1",
+        );
+        let reap_with = |config: ReaperConfig,
+                         evaluated: PersistentVolumeClaim,
+                         fresh: Option<PersistentVolumeClaim>| {
+            async move {
+                let (client, requests) = match &fresh {
+                    Some(fresh) => mock_api(serving_pvc(fresh)),
+                    None => mock_api(|method, _| match method {
+                        "GET" => (
+                            404,
+                            serde_json::json!({
+                                "kind": "Status",
+                                "apiVersion": "v1",
+                                "status": "Failure",
+                                "reason": "NotFound",
+                                "code": 404,
+                            }),
+                        ),
+                        _ => (200, deleted_status()),
+                    }),
+                };
+                let state = state_with(
+                    &["node-1"],
+                    vec![pod_with_pvc(
+                        "data-0",
+                        "data",
+                        "Pending",
+                        Some("Unschedulable"),
+                        600,
+                    )],
+                    vec![evaluated],
+                );
+                let result = state
+                    .reap(&client, &config, &mut ReapTracker::default())
+                    .await
+                    .unwrap();
+                let requests = requests.lock().unwrap();
+                let deleted = requests.iter().any(|r| r.starts_with("DELETE"));
+                // The PvcReaped event only follows a delete that went through
+                let published = requests
+                    .iter()
+                    .any(|r| r.starts_with("POST") && r.contains("/events"));
+                assert_eq!(published, deleted);
+                (result, deleted)
+            }
+        };
+        let reap = |fresh| reap_with(config.clone(), evaluated.clone(), fresh);
+
+        let (result, deleted) = reap(Some(evaluated.clone())).await;
+        assert_eq!(result.deleted_count, 1);
+        assert!(deleted);
+
+        let protected = with_annotation(evaluated.clone(), DEFAULT_PROTECTION_ANNOTATION, "true");
+        let (result, deleted) = reap(Some(protected)).await;
+        assert_eq!((result.deleted_count, result.changed_count), (0, 1));
+        assert!(!deleted);
+
+        // The claim got bound elsewhere in the meantime
+        let moved = with_annotation(evaluated.clone(), SELECTED_NODE_ANNOTATION, "node-1");
+        let (result, deleted) = reap(Some(moved)).await;
+        assert_eq!(result.changed_count, 1);
+        assert!(!deleted);
+
+        let recreated = with_uid(evaluated.clone(), "uid-2");
+        let (result, deleted) = reap(Some(recreated)).await;
+        assert_eq!(result.replaced_count, 1);
+        assert!(!deleted);
+
+        let (result, deleted) = reap(None).await;
+        assert_eq!(result.already_gone_count, 1);
+        assert!(!deleted);
+
+        // The opt-in was withdrawn in the meantime
+        let opt_in_config = ReaperConfig {
+            require_opt_in: true,
+            ..config.clone()
+        };
+        let opted_in = with_annotation(evaluated.clone(), DEFAULT_OPT_IN_ANNOTATION, "true");
+        let (result, deleted) = reap_with(
+            opt_in_config.clone(),
+            opted_in.clone(),
+            Some(opted_in.clone()),
+        )
+        .await;
+        assert_eq!(result.deleted_count, 1);
+        assert!(deleted);
+        let (result, deleted) = reap_with(opt_in_config, opted_in, Some(evaluated.clone())).await;
+        assert_eq!((result.deleted_count, result.changed_count), (0, 1));
+        assert!(!deleted);
+
+        // So was the reap-now request the deletion was decided on
+        let requested = with_annotation(evaluated.clone(), REAP_NOW_ANNOTATION, "true");
+        let (result, deleted) =
+            reap_with(config.clone(), requested.clone(), Some(requested.clone())).await;
+        assert_eq!(result.deleted_operator_requested, 1);
+        assert!(deleted);
+        let (result, deleted) = reap_with(config.clone(), requested, Some(evaluated.clone())).await;
+        assert_eq!((result.deleted_count, result.changed_count), (0, 1));
+        assert!(!deleted);
+    }
+
+    /// Answers the re-fetch before a delete with `pvc`, and everything else like a successful
+    /// delete
+    fn serving_pvc(
+        pvc: &PersistentVolumeClaim,
+    ) -> impl Fn(&str, &str) -> (u16, serde_json::Value) + Send + 'static {
+        let pvc = serde_json::to_value(pvc).unwrap();
+        move |method, path| match method {
+            "GET" if path.contains("/persistentvolumeclaims/") => (200, pvc.clone()),
+            _ => (200, deleted_status()),
         }
     }
 
-    fn state_with(node_names: &[&str], pods: Vec<Pod>, pvcs: Vec<PersistentVolumeClaim>) -> State {
-        let nodes = node_names
-            .iter()
-            .map(|name| Node {
+    /// The `Status` the API server answers a successful delete with
+    fn deleted_status() -> serde_json::Value {
+        serde_json::json!({
+            "kind": "Status",
+            "apiVersion": "v1",
+            "status": "Success",
+            "code": 200,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_delete_pvc_dry_run_options() {
+        let (service, mut handle) = tower_test::mock::pair::<
+            http::Request<kube::client::Body>,
+            http::Response<kube::client::Body>,
+        >();
+        let server = tokio::spawn(async move {
+            let (request, send) = handle.next_request().await.unwrap();
+            send.send_response(
+                http::Response::builder()
+                    .status(200)
+                    .body(kube::client::Body::from(
+                        deleted_status().to_string().into_bytes(),
+                    ))
+                    .unwrap(),
+            );
+            request.into_body().collect_bytes().await.unwrap()
+        });
+        delete_pvc(
+            &Client::new(service, "default"),
+            "default",
+            "data",
+            None,
+            true,
+        )
+        .await
+        .unwrap();
+        let options: serde_json::Value = serde_json::from_slice(&server.await.unwrap()).unwrap();
+        assert_eq!(options["dryRun"], serde_json::json!(["All"]));
+    }
+
+    #[tokio::test]
+    async fn test_backup_failure_policy() {
+        let mut config = test_config();
+        config.backup_dir = Some(PathBuf::from("/nonexistent/pvc-reaper-backups"));
+        let state = || {
+            state_with(
+                &["node-1"],
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![test_pvc(
+                    "data",
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("gone"),
+                )],
+            )
+        };
+        let deletes = |requests: &std::sync::Mutex<Vec<String>>| {
+            requests
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.starts_with("DELETE"))
+                .count()
+        };
+
+        let (client, requests) = mock_api(serving_pvc(&state().pvcs[0]));
+        let result = state()
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.deleted_count, 0);
+        assert_eq!(deletes(&requests), 0);
+
+        config.backup_failure_policy = BackupFailurePolicy::BestEffort;
+        let (client, requests) = mock_api(serving_pvc(&state().pvcs[0]));
+        let result = state()
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.deleted_count, 1);
+        assert_eq!(deletes(&requests), 1);
+    }
+
+    #[tokio::test]
+    async fn test_too_few_ready_nodes() {
+        let mut config = test_config();
+        config.dry_run = true;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let state = |nodes: &[&str]| {
+            let mut state = state_with(
+                nodes,
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![test_pvc(
+                    "data",
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("gone"),
+                )],
+            );
+            // Only the first node is Ready
+            for node in state.nodes.iter_mut().skip(1) {
+                node.status = None;
+            }
+            state
+        };
+
+        let result = state(&["node-1", "node-2"])
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert!(!result.too_few_ready_nodes);
+        assert_eq!(result.would_delete_count, 1);
+
+        // An empty node list never satisfies the default floor of one
+        let result = state(&[])
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert!(result.too_few_ready_nodes);
+        assert_eq!(result.would_delete_count, 0);
+
+        config.min_ready_node_fraction = Some(0.75);
+        let held = state(&["node-1", "node-2"]);
+        assert_eq!(held.ready_node_shortfall(&config), Some((1, 2)));
+        let result = held
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert!(result.too_few_ready_nodes);
+        assert_eq!(result.would_delete_count, 0);
+        assert_eq!(result.skipped[0].reason.variant(), "TooFewReadyNodes");
+
+        config.min_ready_nodes = 0;
+        config.min_ready_node_fraction = None;
+        assert_eq!(state(&[]).ready_node_shortfall(&config), None);
+    }
+
+    #[tokio::test]
+    async fn test_empty_node_list_holds_missing_node_deletions() {
+        use k8s_openapi::api::storage::v1::VolumeAttachmentSpec;
+
+        let mut config = test_config();
+        config.dry_run = true;
+        config.min_ready_nodes = 0;
+        config.cleanup_volume_attachments = true;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let state = |nodes: &[&str]| {
+            let mut state = state_with(
+                nodes,
+                vec![pod_with_pvc(
+                    "data-0",
+                    "data",
+                    "Pending",
+                    Some("Unschedulable"),
+                    600,
+                )],
+                vec![test_pvc(
+                    "data",
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("gone"),
+                )],
+            );
+            state.volume_attachments = vec![VolumeAttachment {
                 metadata: ObjectMeta {
-                    name: Some((*name).to_string()),
+                    name: Some("csi-123".to_string()),
+                    creation_timestamp: Some(Time(state.now - chrono::Duration::seconds(3600))),
                     ..Default::default()
                 },
-                ..Default::default()
-            })
-            .collect::<Vec<_>>();
+                spec: VolumeAttachmentSpec {
+                    attacher: "local.csi.openebs.io".to_string(),
+                    node_name: "gone".to_string(),
+                    ..Default::default()
+                },
+                status: None,
+            }];
+            state
+        };
 
-        State {
-            node_names: node_names.iter().map(|s| s.to_string()).collect(),
-            nodes,
-            pods,
-            pvcs,
-            now: Utc::now(),
-        }
+        // The cleanup passes are held back along with the PVC deletions
+        let result = state(&[])
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 0);
+        assert_eq!(result.skipped[0].reason.variant(), "NodeListEmpty");
+        assert_eq!(result.volume_attachments_would_delete, 0);
+
+        let result = state(&["node-1"])
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert_eq!(result.would_delete_count, 1);
+        assert_eq!(result.volume_attachments_would_delete, 1);
     }
 
-    fn pod_with_pvc(
-        pod_name: &str,
-        pvc_name: &str,
-        phase: &str,
-        condition_reason: Option<&str>,
-        creation_offset_secs: i64,
-    ) -> Pod {
-        Pod {
-            metadata: ObjectMeta {
-                name: Some(pod_name.to_string()),
-                namespace: Some("default".to_string()),
-                creation_timestamp: Some(Time(
-                    chrono::Utc::now() - chrono::Duration::seconds(creation_offset_secs),
-                )),
-                ..Default::default()
-            },
-            spec: Some(k8s_openapi::api::core::v1::PodSpec {
-                volumes: Some(vec![Volume {
-                    name: "data".to_string(),
-                    persistent_volume_claim: Some(PersistentVolumeClaimVolumeSource {
-                        claim_name: pvc_name.to_string(),
-                        ..Default::default()
-                    }),
-                    ..Default::default()
-                }]),
-                ..Default::default()
-            }),
-            status: Some(PodStatus {
-                phase: Some(phase.to_string()),
-                conditions: condition_reason.map(|reason| {
-                    vec![PodCondition {
-                        type_: "PodScheduled".to_string(),
-                        status: "False".to_string(),
-                        reason: Some(reason.to_string()),
-                        ..Default::default()
-                    }]
+    #[tokio::test]
+    async fn test_truncated_list_fails() {
+        let (client, _) = mock_api(|_, _| {
+            (
+                200,
+                serde_json::json!({
+                    "apiVersion": "v1",
+                    "kind": "PersistentVolumeClaimList",
+                    "metadata": {"continue": "abc"},
+                    "items": [],
                 }),
-                ..Default::default()
-            }),
-        }
-    }
+            )
+        });
+        let error = list_namespaced::<PersistentVolumeClaim>(&client, &test_config(), "pvcs")
+            .await
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("partial result"));
 
-    #[test]
-    fn test_matches_storage_criteria() {
-        let pvc = test_pvc(
-            "test",
-            "openebs-lvm",
-            "local.csi.openebs.io",
-            Some("node-1"),
-        );
-        assert!(matches_storage_criteria(&pvc, &test_config()));
+        let (client, _) = mock_api(|_, _| {
+            (
+                200,
+                serde_json::json!({
+                    "apiVersion": "v1",
+                    "kind": "PersistentVolumeClaimList",
+                    "metadata": {"continue": ""},
+                    "items": [],
+                }),
+            )
+        });
+        let pvcs = list_namespaced::<PersistentVolumeClaim>(&client, &test_config(), "pvcs")
+            .await
+            .unwrap();
+        assert!(pvcs.is_empty());
     }
 
     #[test]
-    fn test_matches_storage_criteria_multiple_classes() {
-        let pvc = test_pvc(
-            "test",
-            "local-storage",
-            "local.csi.openebs.io",
-            Some("node-1"),
-        );
+    fn test_circuit_breaker_trips() {
         let mut config = test_config();
-        config.storage_classes = vec!["openebs-lvm".to_string(), "local-storage".to_string()];
-        assert!(matches_storage_criteria(&pvc, &config));
+        assert!(!circuit_breaker_trips(&config, 50, 50));
+
+        config.max_deletion_fraction = Some(0.2);
+        assert!(!circuit_breaker_trips(&config, 10, 50));
+        assert!(circuit_breaker_trips(&config, 11, 50));
+        // Up to the floor always passes, even at 100%
+        assert!(!circuit_breaker_trips(&config, 3, 3));
+        assert!(circuit_breaker_trips(&config, 4, 4));
+        config.max_deletion_fraction_floor = 0;
+        assert!(circuit_breaker_trips(&config, 1, 1));
+
+        assert_eq!(parse_fraction("0.2"), Ok(0.2));
+        assert!(parse_fraction("1.5").is_err());
+        assert!(parse_fraction("-0.1").is_err());
     }
 
-    #[test]
-    fn test_pod_unschedulable_long_enough_with_unschedulable_condition() {
-        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 600);
-        assert!(pod_exceeds_unschedulable_thresh(
-            &pod,
-            Duration::from_secs(120),
-            Utc::now()
-        ));
+    #[tokio::test]
+    async fn test_circuit_breaker_skips_all_deletions() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.max_deletion_fraction = Some(0.5);
+        config.max_deletion_fraction_floor = 2;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let state = |stuck: usize| {
+            let names: Vec<String> = (0..stuck).map(|i| format!("stuck-{}", i)).collect();
+            let mut pvcs: Vec<_> = names
+                .iter()
+                .map(|name| test_pvc(name, "openebs-lvm", "local.csi.openebs.io", Some("gone")))
+                .collect();
+            pvcs.extend((0..4).map(|i| {
+                test_pvc(
+                    &format!("healthy-{}", i),
+                    "openebs-lvm",
+                    "local.csi.openebs.io",
+                    Some("node-1"),
+                )
+            }));
+            let pods = names
+                .iter()
+                .map(|name| {
+                    pod_with_pvc(
+                        &format!("{}-pod", name),
+                        name,
+                        "Pending",
+                        Some("Unschedulable"),
+                        600,
+                    )
+                })
+                .collect();
+            state_with(&["node-1"], pods, pvcs)
+        };
+
+        // 4 of 8 is at the fraction, not above it
+        let result = state(4)
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert!(!result.circuit_breaker_open);
+        assert_eq!(result.would_delete_count, 4);
+
+        // 5 of 9 trips it, and no candidate is deleted
+        let result = state(5)
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        assert!(result.circuit_breaker_open);
+        assert_eq!(result.would_delete_count, 0);
+        assert_eq!(
+            result
+                .skipped
+                .iter()
+                .filter(|pvc| pvc.reason.variant() == "CircuitBreakerOpen")
+                .count(),
+            5
+        );
     }
 
-    #[test]
-    fn test_pod_unschedulable_not_long_enough() {
-        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 60);
-        assert!(!pod_exceeds_unschedulable_thresh(
-            &pod,
-            Duration::from_secs(120),
-            Utc::now()
-        ));
+    #[tokio::test]
+    async fn test_max_deletions_per_namespace_with_global_cap() {
+        let mut config = test_config();
+        config.dry_run = true;
+        config.max_deletions_per_namespace = 1;
+        config.max_deletions_per_cycle = 2;
+        let (client, _) = mock_api(|_, _| (200, serde_json::json!({})));
+        let stuck = |namespace: &str, name: &str, age_secs: i64| {
+            let mut pvc = test_pvc(name, "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+            pvc.metadata.namespace = Some(namespace.to_string());
+            pvc.metadata.creation_timestamp = Some(Time(
+                chrono::Utc::now() - chrono::Duration::seconds(age_secs),
+            ));
+            let mut pod = pod_with_pvc(
+                &format!("{}-0", name),
+                name,
+                "Pending",
+                Some("Unschedulable"),
+                600,
+            );
+            pod.metadata.namespace = Some(namespace.to_string());
+            (pvc, pod)
+        };
+        let (pvcs, pods): (Vec<_>, Vec<_>) = [
+            stuck("team-a", "a-1", 500),
+            stuck("team-a", "a-2", 400),
+            stuck("team-b", "b-1", 300),
+            stuck("team-c", "c-1", 200),
+        ]
+        .into_iter()
+        .unzip();
+        let state = state_with(&["node-1"], pods, pvcs);
+
+        let result = state
+            .reap(&client, &config, &mut ReapTracker::default())
+            .await
+            .unwrap();
+        // a-2 is held back by team-a's limit without using up the global budget, which b-1
+        // then takes; c-1 is past the global cap
+        assert_eq!(
+            result
+                .deleted
+                .iter()
+                .map(|pvc| pvc.name.as_str())
+                .collect::<Vec<_>>(),
+            ["a-1", "b-1"]
+        );
+        assert_eq!(result.deferred_count, 2);
+        assert_eq!(
+            result
+                .skipped
+                .iter()
+                .map(|skipped| (skipped.name.as_str(), skipped.reason.variant()))
+                .collect::<Vec<_>>(),
+            [
+                ("a-2", "NamespaceLimitReached"),
+                ("c-1", "DeletionLimitReached")
+            ]
+        );
     }
 
-    #[test]
-    fn test_deletion_reason_when_node_missing() {
-        let pvc = test_pvc(
-            "test",
-            "openebs-lvm",
-            "local.csi.openebs.io",
-            Some("missing-node"),
+    #[tokio::test]
+    async fn test_soft_delete() {
+        let mut config = test_config();
+        config.soft_delete_delay_secs = Some(3600);
+        let mut stuck = test_pvc("stuck", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        stuck.metadata.uid = Some("uid-stuck".to_string());
+        // What the API server returns for the claim, changed by each step as needed
+        let served =
+            std::sync::Arc::new(std::sync::Mutex::new(serde_json::to_value(&stuck).unwrap()));
+        let (client, requests) = mock_api({
+            let served = served.clone();
+            move |_, path| {
+                if path.contains("/persistentvolumeclaims/") {
+                    (200, served.lock().unwrap().clone())
+                } else {
+                    (200, serde_json::json!({}))
+                }
+            }
+        });
+        let serve = |pvc: &PersistentVolumeClaim| {
+            *served.lock().unwrap() = serde_json::to_value(pvc).unwrap();
+        };
+        let pvc_path = "/api/v1/namespaces/default/persistentvolumeclaims/stuck";
+        let stuck_pod = || pod_with_pvc("stuck-0", "stuck", "Pending", Some("Unschedulable"), 600);
+        let tracker = std::sync::Arc::new(tokio::sync::Mutex::new(ReapTracker::default()));
+        let run = |state: State| {
+            let (client, config, requests) = (client.clone(), config.clone(), requests.clone());
+            let tracker = tracker.clone();
+            async move {
+                requests.lock().unwrap().clear();
+                let result = state
+                    .reap(&client, &config, &mut *tracker.lock().await)
+                    .await
+                    .unwrap();
+                let calls: Vec<String> = requests
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .filter(|call| call.ends_with(pvc_path))
+                    .cloned()
+                    .collect();
+                (result, calls)
+            }
+        };
+
+        // First seen: annotated, not deleted
+        let (result, calls) = run(state_with(
+            &["node-1"],
+            vec![stuck_pod()],
+            vec![stuck.clone()],
+        ))
+        .await;
+        assert_eq!(calls, [format!("PATCH {}", pvc_path)]);
+        assert_eq!(result.deleted_count, 0);
+        assert_eq!(result.skipped[0].reason.variant(), "ScheduledForDeletion");
+
+        // Still in the future: left alone
+        let future = (chrono::Utc::now() + chrono::Duration::minutes(30)).to_rfc3339();
+        let pending = with_annotation(stuck.clone(), DELETE_AT_ANNOTATION, &future);
+        let (result, calls) = run(state_with(&["node-1"], vec![stuck_pod()], vec![pending])).await;
+        assert!(calls.is_empty());
+        assert_eq!(result.skipped[0].reason.variant(), "ScheduledForDeletion");
+
+        // Due, but the annotation was removed between the list and the delete: left alone
+        let past = (chrono::Utc::now() - chrono::Duration::minutes(1)).to_rfc3339();
+        let due = with_annotation(stuck.clone(), DELETE_AT_ANNOTATION, &past);
+        let (result, calls) = run(state_with(
+            &["node-1"],
+            vec![stuck_pod()],
+            vec![due.clone()],
+        ))
+        .await;
+        assert_eq!(calls, [format!("GET {}", pvc_path)]);
+        assert_eq!((result.deleted_count, result.changed_count), (0, 1));
+
+        // Due and still qualifying: deleted
+        serve(&due);
+        let (result, calls) = run(state_with(
+            &["node-1"],
+            vec![stuck_pod()],
+            vec![due.clone()],
+        ))
+        .await;
+        assert_eq!(
+            calls,
+            [format!("GET {}", pvc_path), format!("DELETE {}", pvc_path)]
         );
-        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 10);
+        assert_eq!(result.deleted_count, 1);
+        // The steps below stand for claims that were never deleted
+        *tracker.lock().await = ReapTracker::default();
 
-        let state = state_with(&[], vec![pod], vec![pvc.clone()]);
+        // Protected meanwhile: cancelled, the annotation is removed instead
+        let protected = with_annotation(due.clone(), DEFAULT_PROTECTION_ANNOTATION, "true");
+        let (result, calls) =
+            run(state_with(&["node-1"], vec![stuck_pod()], vec![protected])).await;
+        assert_eq!(calls, [format!("PATCH {}", pvc_path)]);
+        assert_eq!(result.deleted_count, 0);
 
-        let reason = state
-            .deletion_reason(&pvc, &test_config())
-            .expect("expected deletion reason");
+        // The node came back: cancelled too, and a later relapse starts a fresh countdown
+        let recovered_pod = pod_with_pvc("stuck-0", "stuck", "Pending", Some("Unschedulable"), 10);
+        let (_, calls) = run(state_with(
+            &["node-1", "gone"],
+            vec![recovered_pod.clone()],
+            vec![due],
+        ))
+        .await;
+        assert_eq!(calls, [format!("PATCH {}", pvc_path)]);
+        let (result, calls) = run(state_with(
+            &["node-1"],
+            vec![stuck_pod()],
+            vec![stuck.clone()],
+        ))
+        .await;
+        assert_eq!(calls, [format!("PATCH {}", pvc_path)]);
+        assert_eq!(result.skipped[0].reason.variant(), "ScheduledForDeletion");
 
-        match reason {
-            DeleteReason::MissingNode { node, pod } => {
-                assert_eq!(node, "missing-node");
-                assert_eq!(pod, "pending-pod");
-            }
-            _ => panic!("expected missing node reason"),
+        // The annotation removed by hand: cancelled, neither deleted nor scheduled again for as
+        // long as the claim keeps qualifying
+        for _ in 0..2 {
+            let (result, calls) = run(state_with(
+                &["node-1"],
+                vec![stuck_pod()],
+                vec![stuck.clone()],
+            ))
+            .await;
+            assert!(calls.is_empty());
+            assert_eq!(result.deleted_count, 0);
+            assert_eq!(result.skipped[0].reason.variant(), "SoftDeleteCancelled");
         }
+
+        // Once it stopped qualifying, qualifying again starts a new countdown
+        let (_, calls) = run(state_with(
+            &["node-1", "gone"],
+            vec![recovered_pod],
+            vec![stuck.clone()],
+        ))
+        .await;
+        assert!(calls.is_empty());
+        let (result, calls) = run(state_with(&["node-1"], vec![stuck_pod()], vec![stuck])).await;
+        assert_eq!(calls, [format!("PATCH {}", pvc_path)]);
+        assert_eq!(result.skipped[0].reason.variant(), "ScheduledForDeletion");
+
+        let patch = delete_at_patch(None);
+        assert_eq!(
+            patch["metadata"]["annotations"][DELETE_AT_ANNOTATION],
+            serde_json::Value::Null
+        );
     }
 
-    #[test]
-    fn test_deletion_reason_when_unschedulable_too_long() {
+    #[tokio::test]
+    async fn test_namespace_scoped_listing() {
+        let respond = |_: &str, path: &str| {
+            if path == "/api/v1/nodes" {
+                (
+                    403,
+                    serde_json::json!({
+                        "kind": "Status",
+                        "apiVersion": "v1",
+                        "status": "Failure",
+                        "reason": "Forbidden",
+                        "code": 403,
+                    }),
+                )
+            } else {
+                (
+                    200,
+                    serde_json::json!({"apiVersion": "v1", "kind": "List", "metadata": {}, "items": []}),
+                )
+            }
+        };
+
+        // Cluster-wide, a forbidden node list fails the cycle
+        let (client, _) = mock_api(respond);
+        assert!(State::new(&client, &test_config()).await.is_err());
+
+        let mut config = test_config();
+        config.namespaces = vec!["team-a".to_string(), "team-b".to_string()];
+        let (client, requests) = mock_api(respond);
+        let state = State::new(&client, &config).await.unwrap();
+        assert!(!state.nodes_listed);
+        let requests = requests.lock().unwrap();
+        for path in [
+            "GET /api/v1/namespaces/team-a/pods",
+            "GET /api/v1/namespaces/team-b/pods",
+            "GET /api/v1/namespaces/team-a/persistentvolumeclaims",
+            "GET /api/v1/namespaces/team-b/persistentvolumeclaims",
+        ] {
+            assert!(requests.iter().any(|r| r == path), "{} not requested", path);
+        }
+        assert!(!requests.iter().any(|r| r == "GET /api/v1/pods"));
+
+        // Unknown nodes are never treated as missing
         let pvc = test_pvc(
             "test",
             "openebs-lvm",
             "local.csi.openebs.io",
             Some("node-1"),
         );
-        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("Unschedulable"), 601);
-
-        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
-
-        let reason = state
-            .deletion_reason(&pvc, &test_config())
-            .expect("expected deletion reason");
-
-        match reason {
-            DeleteReason::UnschedulableTooLong { pod } => assert_eq!(pod, "pending-pod"),
-            _ => panic!("expected pending too long reason"),
-        }
+        assert_eq!(state.missing_node(&pvc, &config), None);
     }
 
     #[test]
-    fn test_deletion_reason_skips_when_pod_not_unschedulable() {
-        let pvc = test_pvc(
-            "test",
+    fn test_candidates() {
+        let config = test_config();
+        let stuck = test_pvc("stuck", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let healthy = test_pvc(
+            "healthy",
             "openebs-lvm",
             "local.csi.openebs.io",
             Some("node-1"),
         );
-        let pod = pod_with_pvc("pending-pod", "test", "Pending", Some("OtherReason"), 600);
+        let other = test_pvc("other", "standard", "ebs.csi.aws.com", Some("gone"));
+        let state = state_with(
+            &["node-1"],
+            vec![
+                pod_with_pvc("stuck-0", "stuck", "Pending", Some("Unschedulable"), 600),
+                pod_with_pvc("healthy-0", "healthy", "Pending", Some("Unschedulable"), 10),
+                pod_with_pvc("other-0", "other", "Pending", Some("Unschedulable"), 600),
+            ],
+            vec![stuck, healthy, other],
+        );
 
-        let state = state_with(&["node-1"], vec![pod], vec![pvc.clone()]);
+        let list = state.candidates(&config);
+        assert_eq!(
+            list.skipped
+                .iter()
+                .map(|skipped| (skipped.name.as_str(), skipped.reason.variant()))
+                .collect::<Vec<_>>(),
+            [("healthy", "BelowThreshold")]
+        );
+        let candidates = list.candidates;
+        assert_eq!(candidates.len(), 1);
+        let candidate = &candidates[0];
+        assert_eq!(candidate.name, "stuck");
+        assert_eq!(candidate.node.as_deref(), Some("gone"));
+        assert_eq!(candidate.pod.as_deref(), Some("stuck-0"));
+        assert_eq!(candidate.reason.variant(), "MissingNode");
+        assert!(candidate.pending_secs.is_some_and(|secs| secs >= 600));
+    }
+
+    #[test]
+    fn test_explanation() {
+        let config = test_config();
+        let pvc = test_pvc("data", "openebs-lvm", "local.csi.openebs.io", Some("gone"));
+        let state = state_with(
+            &["node-1"],
+            vec![pod_with_pvc(
+                "db-0",
+                "data",
+                "Pending",
+                Some("Unschedulable"),
+                600,
+            )],
+            vec![pvc.clone()],
+        );
+
+        let explanation = state.explanation(&pvc, &config);
+        assert!(explanation.is_candidate());
+        let outcome = |name: &str| {
+            explanation
+                .checks
+                .iter()
+                .find(|check| check.name == name)
+                .map(|check| check.passed)
+        };
+        assert_eq!(outcome("storage class"), Some(true));
+        assert_eq!(outcome("consuming pods"), Some(true));
+        assert_eq!(outcome("threshold"), Some(true));
+        assert_eq!(outcome("node missing"), Some(true));
+        assert_eq!(outcome("annotation selector"), None);
+
+        // A non-matching claim is explained but never evaluated
+        let other = test_pvc("data", "standard", "ebs.csi.aws.com", Some("node-1"));
+        let explanation = state.explanation(&other, &config);
+        assert!(!explanation.is_candidate());
+        assert_eq!(explanation.decision, None);
+        let failed: Vec<_> = explanation
+            .checks
+            .iter()
+            .filter(|check| !check.passed)
+            .map(|check| check.name)
+            .collect();
+        assert_eq!(failed, ["storage class", "provisioner", "node missing"]);
+    }
 
-        assert!(state.deletion_reason(&pvc, &test_config()).is_none());
+    #[test]
+    fn test_parse_namespaced_name() {
+        assert_eq!(
+            parse_namespaced_name("team-a/data-db-0"),
+            Ok(("team-a".to_string(), "data-db-0".to_string()))
+        );
+        assert!(parse_namespaced_name("data-db-0").is_err());
+        assert!(parse_namespaced_name("/data-db-0").is_err());
     }
 }
@@ -1,37 +1,379 @@
 use anyhow::{Context, Result};
 use clap::Parser;
+use futures::future::join_all;
 use kube::Client;
-use pvc_reaper::{reap, ReaperConfig};
-use std::time::Duration;
+use metrics_exporter_prometheus::PrometheusHandle;
+use pvc_reaper::{
+    Command, LogFormat, OutputFormat, ReaperConfig,
+    audit::{self, HistoryFilter},
+    candidates, cli,
+    cluster::{self, Cluster},
+    explain_pvc, server, telemetry, watch,
+};
+use server::Status;
+use std::{io::IsTerminal, sync::Arc, time::Duration};
+use tokio::sync::watch as channel;
+#[cfg(feature = "otlp")]
+use tracing::Level;
 use tracing::{error, info};
+#[cfg(feature = "otlp")]
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::{
+    EnvFilter, Layer, fmt::writer::BoxMakeWriter, layer::SubscriberExt, util::SubscriberInitExt,
+};
+
+/// Exit code of `explain` when the PVC would not be deleted; errors exit with 1
+const NOT_CANDIDATE_EXIT_CODE: i32 = 2;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info")),
-        )
-        .init();
-
     let config = ReaperConfig::parse();
 
+    // Subcommands and machine-readable output use stdout, so their logs go to stderr
+    let writer = match (&config.command, config.output) {
+        (Some(Command::List | Command::Explain { .. } | Command::History { .. }), _)
+        | (_, OutputFormat::Json | OutputFormat::Yaml) => BoxMakeWriter::new(std::io::stderr),
+        _ => BoxMakeWriter::new(std::io::stdout),
+    };
+    let fmt_layer = match config.log_format {
+        LogFormat::Text => tracing_subscriber::fmt::layer().with_writer(writer).boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .with_writer(writer)
+            .boxed(),
+    }
+    .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")));
+
+    #[cfg(feature = "otlp")]
+    let tracer_provider = config
+        .otlp_endpoint
+        .as_deref()
+        .map(pvc_reaper::otel::tracer_provider)
+        .transpose()?;
+    #[cfg(feature = "otlp")]
+    let otel_layer = tracer_provider.as_ref().map(|provider| {
+        pvc_reaper::otel::layer(provider)
+            .with_filter(Targets::new().with_target("pvc_reaper", Level::DEBUG))
+    });
+    #[cfg(not(feature = "otlp"))]
+    let otel_layer: Option<tracing_subscriber::layer::Identity> = None;
+    #[cfg(not(feature = "otlp"))]
+    if config.otlp_endpoint.is_some() {
+        anyhow::bail!("--otlp-endpoint requires pvc-reaper to be built with the `otlp` feature");
+    }
+    // Prompting without a terminal would hang forever, e.g. in a Deployment
+    if config.interactive && !std::io::stdin().is_terminal() {
+        anyhow::bail!("--interactive requires stdin to be a terminal");
+    }
+    if config.require_namespace_opt_in && config.namespace_annotation.is_empty() {
+        anyhow::bail!("--require-namespace-opt-in needs a --namespace-annotation to opt in with");
+    }
+    if config.interactive && config.contexts.len() > 1 {
+        anyhow::bail!("--interactive works on one cluster; pass a single --contexts entry");
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt_layer)
+        .with(otel_layer)
+        .init();
+
     info!("Starting pvc-reaper");
-    info!("Storage class names: {}", config.storage_classes.join(","));
-    info!("Storage provisioner: {}", config.storage_provisioner);
+    info!(
+        "Storage class patterns: {}",
+        config
+            .storage_class_patterns()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+    info!(
+        "Storage provisioners: {}",
+        config.storage_provisioner.join(",")
+    );
     info!("Reap interval: {}s", config.reap_interval_secs);
     info!("Dry run: {}", config.dry_run);
-    info!("Check unschedulable pods: {}", config.check_unschedulable_pods);
+    if config.dry_run {
+        info!("Dry run mode: {:?}", config.dry_run_mode);
+    }
+    info!(
+        "Check unschedulable pods: {}",
+        config.check_unschedulable_pods
+    );
+    if let Some(name) = &config.pause_configmap_name {
+        info!(
+            "Pause ConfigMap: {}/{} ({:?})",
+            config.pause_configmap_namespace, name, config.pause_mode
+        );
+    }
+
+    // History only reads the record store, so it needs neither metrics nor a cluster
+    if let Some(Command::History {
+        since,
+        until,
+        namespace,
+        storage_class,
+    }) = &config.command
+    {
+        let filter = HistoryFilter {
+            since: *since,
+            until: *until,
+            namespace: namespace.clone(),
+            storage_class: storage_class.clone(),
+        };
+        return history(&config, &filter);
+    }
+
+    let metrics = telemetry::install_recorder()?;
+
+    let clusters = cluster::connect(&config).await?;
+
+    let outcome = if config.command == Some(Command::List) {
+        list(single_cluster(&clusters)?, &config).await
+    } else if let Some(Command::Explain {
+        pvc: (namespace, name),
+    }) = &config.command
+    {
+        match explain(single_cluster(&clusters)?, &config, namespace, name).await {
+            Ok(false) => std::process::exit(NOT_CANDIDATE_EXIT_CODE),
+            outcome => outcome.map(|_| ()),
+        }
+    } else if config.once || config.pushgateway_url.is_some() {
+        run_once(&clusters, &config, &metrics).await
+    } else {
+        serve(&clusters, &config, metrics).await
+    };
+
+    #[cfg(feature = "otlp")]
+    if let Some(provider) = tracer_provider
+        && let Err(e) = provider.shutdown()
+    {
+        error!("Failed to flush OTLP traces: {}", e);
+    }
+
+    outcome
+}
+
+/// `list` and `explain` look at one cluster, so at most one `--contexts` entry may be given
+fn single_cluster(clusters: &[Cluster]) -> Result<&Client> {
+    match clusters {
+        [cluster] => Ok(&cluster.client),
+        _ => anyhow::bail!("list and explain work on one cluster; pass a single --contexts entry"),
+    }
+}
+
+/// Run the reaping loop alongside the HTTP server until a shutdown signal arrives
+async fn serve(
+    clusters: &[Cluster],
+    config: &ReaperConfig,
+    metrics: PrometheusHandle,
+) -> Result<()> {
+    let status = Arc::new(Status::new(config));
+    let (shutdown_tx, shutdown_rx) = channel::channel(false);
+    // With several contexts, readiness probes the first one's API server
+    let server = tokio::spawn(server::serve(
+        config.metrics_addr,
+        metrics,
+        status.clone(),
+        clusters[0].client.clone(),
+        Duration::from_secs(config.reap_interval_secs),
+        config.reconcile_token.clone(),
+        shutdown_rx,
+    ));
+
+    let node_watch = async {
+        if config.watch_nodes {
+            join_all(clusters.iter().map(|cluster| {
+                cluster.scoped(watch::node_deletions(
+                    &cluster.client,
+                    config,
+                    &cluster.tracker,
+                ))
+            }))
+            .await;
+        }
+        // The periodic loop carries on alone if the watch ever ends
+        std::future::pending::<()>().await
+    };
+    let pod_watch = async {
+        if config.watch_pods {
+            join_all(clusters.iter().map(|cluster| {
+                cluster.scoped(watch::unschedulable_pods(
+                    &cluster.client,
+                    config,
+                    &cluster.tracker,
+                ))
+            }))
+            .await;
+        }
+        std::future::pending::<()>().await
+    };
+
+    let outcome = tokio::select! {
+        result = run(clusters, config, &status) => result,
+        _ = node_watch => unreachable!("the node watch never completes"),
+        _ = pod_watch => unreachable!("the pod watch never completes"),
+        _ = shutdown_signal() => {
+            info!("Shutdown signal received");
+            Ok(())
+        }
+    };
+
+    let _ = shutdown_tx.send(true);
+    if let Err(e) = server.await.context("HTTP server task panicked")? {
+        error!("HTTP server error: {:#}", e);
+    }
+
+    outcome
+}
+
+/// Print the current deletion candidates; never deletes anything
+async fn list(client: &Client, config: &ReaperConfig) -> Result<()> {
+    let list = candidates(client, config).await?;
+    match cli::machine_readable(&list, config.output)? {
+        Some(output) => print!("{}", output),
+        None => print!("{}", cli::candidate_table(&list.candidates)),
+    }
+    Ok(())
+}
+
+/// Print every check for one PVC and whether it would be deleted right now
+async fn explain(
+    client: &Client,
+    config: &ReaperConfig,
+    namespace: &str,
+    name: &str,
+) -> Result<bool> {
+    let explanation = explain_pvc(client, config, namespace, name).await?;
+    match cli::machine_readable(&explanation, config.output)? {
+        Some(output) => print!("{}", output),
+        None => print!("{}", cli::explanation_text(&explanation)),
+    }
+    Ok(explanation.is_candidate())
+}
+
+/// Print past deletions from the audit log
+fn history(config: &ReaperConfig, filter: &HistoryFilter) -> Result<()> {
+    let Some(path) = &config.audit_log_path else {
+        anyhow::bail!(
+            "No deletion record store configured; set --audit-log-path / AUDIT_LOG_PATH to the audit log to read"
+        );
+    };
+    let records = audit::read_history(path, filter)?;
+    match cli::machine_readable(&records, config.output)? {
+        Some(output) => print!("{}", output),
+        None => print!("{}", cli::history_table(&records)),
+    }
+    Ok(())
+}
+
+/// Run a single cycle, push its metrics to the Pushgateway if one is configured and report the
+/// outcome
+async fn run_once(
+    clusters: &[Cluster],
+    config: &ReaperConfig,
+    metrics: &PrometheusHandle,
+) -> Result<()> {
+    let result = cluster::reap_all(clusters, config).await;
+    // The process exits right after this, taking any notification still in flight with it
+    cluster::flush_notifications(clusters).await;
+    match &result {
+        Ok(cycle) => match cli::machine_readable(cycle, config.output)? {
+            Some(output) => print!("{}", output),
+            None => info!(
+                "Single cycle complete: {}",
+                serde_json::to_string(cycle).unwrap_or_default()
+            ),
+        },
+        Err(e) => error!("Single cycle failed: {:#}", e),
+    }
+    // Every cluster is still reaped when some fail, but the run as a whole didn't succeed
+    let result = result.and_then(|cycle| match cycle.failed_clusters.as_slice() {
+        [] => Ok(()),
+        failed => Err(anyhow::anyhow!(
+            "Reaping failed in {} of {} clusters: {}",
+            failed.len(),
+            clusters.len(),
+            failed.join(", ")
+        )),
+    });
+
+    let Some(gateway) = &config.pushgateway_url else {
+        return result;
+    };
+    if let Err(e) = telemetry::push_to_gateway(
+        metrics,
+        gateway,
+        &config.pushgateway_job,
+        config.pushgateway_instance.as_deref(),
+    )
+    .await
+    {
+        error!("Failed to push metrics to Pushgateway: {:#}", e);
+        result?;
+        anyhow::bail!("Failed to push metrics to Pushgateway");
+    }
 
-    let client = Client::try_default()
-        .await
-        .context("Failed to create Kubernetes client")?;
+    info!("Pushed metrics to Pushgateway {}", gateway);
+    result
+}
 
+/// Reap every interval, or sooner on `POST /reconcile`; only returns once
+/// `--max-consecutive-failures` is reached
+async fn run(clusters: &[Cluster], config: &ReaperConfig, status: &Status) -> Result<()> {
     loop {
-        if let Err(e) = reap(&client, &config).await {
-            error!("Reaping error: {:#}", e);
+        let cycle = cluster::reap_all(clusters, config).await;
+        match cycle {
+            Ok(result) => status.record_success(&result),
+            Err(e) => {
+                let failures = status.record_failure(&e);
+                if config.max_consecutive_failures > 0 {
+                    error!(
+                        "Reaping error (attempt {}/{}): {:#}",
+                        failures, config.max_consecutive_failures, e
+                    );
+                } else {
+                    error!("Reaping error (attempt {}): {:#}", failures, e);
+                }
+
+                if status.failures_exhausted() {
+                    return Err(e.context(format!(
+                        "Giving up after {} consecutive reconcile failures",
+                        failures
+                    )));
+                }
+            }
         }
 
-        tokio::time::sleep(Duration::from_secs(config.reap_interval_secs)).await;
+        tokio::select! {
+            _ = tokio::time::sleep(Duration::from_secs(config.reap_interval_secs)) => {}
+            _ = status.reconcile_requested() => {}
+        }
+    }
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("Failed to install SIGTERM handler: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
     }
 }
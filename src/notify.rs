@@ -0,0 +1,290 @@
+//! Webhook notifications sent after each deletion, and per-cycle Slack summaries.
+
+use crate::{DeleteReason, DeletedPvc, ReaperConfig, get_selected_node};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use kube::ResourceExt;
+use reqwest::Url;
+use serde::Serialize;
+use std::{sync::LazyLock, time::Duration};
+use tokio::task::JoinSet;
+use tracing::{debug, warn};
+
+const WEBHOOK_ATTEMPTS: u32 = 3;
+const WEBHOOK_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Shared across all notifications so connections are pooled
+static HTTP_CLIENT: LazyLock<reqwest::Client> = LazyLock::new(reqwest::Client::new);
+
+/// JSON body POSTed to the notification webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeletionNotification {
+    pub namespace: String,
+    pub name: String,
+    /// `DeleteReason` variant, e.g. `MissingNode`
+    pub reason: String,
+    pub description: String,
+    pub node: Option<String>,
+    pub pod: Option<String>,
+    pub dry_run: bool,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl DeletionNotification {
+    pub(crate) fn new(
+        pvc: &PersistentVolumeClaim,
+        reason: &DeleteReason,
+        config: &ReaperConfig,
+    ) -> Self {
+        Self {
+            namespace: pvc.namespace().unwrap_or_default(),
+            name: pvc.name_any(),
+            reason: reason.variant().to_string(),
+            description: reason.describe(),
+            node: get_selected_node(pvc, config).map(str::to_string),
+            pod: reason.pod().map(str::to_string),
+            dry_run: config.dry_run,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Slack incoming-webhook message body
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SlackMessage {
+    pub text: String,
+}
+
+impl SlackMessage {
+    /// Summarize a cycle's deletions, or `None` when nothing was deleted
+    pub(crate) fn summary(deleted: &[DeletedPvc]) -> Option<Self> {
+        let first = deleted.first()?;
+        let heading = if first.dry_run {
+            format!(
+                "pvc-reaper would delete {} PVC(s) (dry-run):",
+                deleted.len()
+            )
+        } else {
+            format!("pvc-reaper deleted {} PVC(s):", deleted.len())
+        };
+
+        let lines = deleted.iter().map(|pvc| {
+            format!(
+                "• `{}/{}` on node `{}`: {}",
+                pvc.namespace,
+                pvc.name,
+                pvc.node.as_deref().unwrap_or("none"),
+                pvc.reason.describe()
+            )
+        });
+
+        Some(Self {
+            text: std::iter::once(heading)
+                .chain(lines)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        })
+    }
+}
+
+/// Post a summary of `deleted` to Slack in the background; does nothing if the list is empty
+pub(crate) fn spawn_slack_summary(tasks: &mut JoinSet<()>, url: Url, deleted: &[DeletedPvc]) {
+    let Some(message) = SlackMessage::summary(deleted) else {
+        return;
+    };
+
+    reap_finished(tasks);
+    tasks.spawn(async move {
+        if let Err(e) = post_with_retry(&url, &message).await {
+            warn!("Failed to send Slack summary: {:#}", e);
+        }
+    });
+}
+
+/// POST `payload` to `url` in the background, retrying with exponential backoff.
+/// Failures are logged and never surface to the reconcile loop.
+pub(crate) fn spawn_webhook(tasks: &mut JoinSet<()>, url: Url, payload: DeletionNotification) {
+    reap_finished(tasks);
+    tasks.spawn(async move {
+        if let Err(e) = post_with_retry(&url, &payload).await {
+            warn!(
+                namespace = %payload.namespace,
+                pvc = %payload.name,
+                "Failed to send deletion webhook: {:#}",
+                e
+            );
+        }
+    });
+}
+
+/// Wait for every notification in `tasks`, retries included
+pub(crate) async fn drain(tasks: &mut JoinSet<()>) {
+    while let Some(joined) = tasks.join_next().await {
+        if let Err(e) = joined {
+            warn!("Notification task failed: {}", e);
+        }
+    }
+}
+
+/// Forget notifications that are already done, so the long-running loop doesn't pile them up
+fn reap_finished(tasks: &mut JoinSet<()>) {
+    while let Some(joined) = tasks.try_join_next() {
+        if let Err(e) = joined {
+            warn!("Notification task failed: {}", e);
+        }
+    }
+}
+
+async fn post_with_retry(url: &Url, payload: &impl Serialize) -> Result<()> {
+    let mut backoff = WEBHOOK_INITIAL_BACKOFF;
+    let mut attempt = 1;
+
+    loop {
+        match post(url, payload).await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt < WEBHOOK_ATTEMPTS => {
+                debug!(
+                    "Webhook attempt {}/{} failed, retrying in {:?}: {:#}",
+                    attempt, WEBHOOK_ATTEMPTS, backoff, e
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+async fn post(url: &Url, payload: &impl Serialize) -> Result<()> {
+    HTTP_CLIENT
+        .post(url.clone())
+        .timeout(WEBHOOK_TIMEOUT)
+        .json(payload)
+        .send()
+        .await
+        .context("Webhook request failed")?
+        .error_for_status()
+        .context("Webhook returned an error status")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_deletion_notification_payload() {
+        let payload = DeletionNotification {
+            namespace: "default".to_string(),
+            name: "data-db-0".to_string(),
+            reason: "MissingNode".to_string(),
+            description: "pod 'db-0' references missing node 'node-1'".to_string(),
+            node: Some("node-1".to_string()),
+            pod: Some("db-0".to_string()),
+            dry_run: true,
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+        };
+
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({
+                "namespace": "default",
+                "name": "data-db-0",
+                "reason": "MissingNode",
+                "description": "pod 'db-0' references missing node 'node-1'",
+                "node": "node-1",
+                "pod": "db-0",
+                "dry_run": true,
+                "timestamp": "2025-01-02T03:04:05Z",
+            })
+        );
+    }
+
+    fn deleted_pvc(name: &str, node: Option<&str>, dry_run: bool) -> DeletedPvc {
+        DeletedPvc {
+            namespace: "default".to_string(),
+            name: name.to_string(),
+            uid: None,
+            storage_class: Some("openebs-lvm".to_string()),
+            node: node.map(str::to_string),
+            size: None,
+            size_bytes: 0,
+            reason: DeleteReason::MissingNode {
+                node: "node-1".to_string(),
+                pod: "db-0".to_string(),
+                missing_secs: 300,
+            },
+            dry_run,
+        }
+    }
+
+    #[test]
+    fn test_slack_summary() {
+        assert_eq!(SlackMessage::summary(&[]), None);
+
+        let message = SlackMessage::summary(&[
+            deleted_pvc("data-db-0", Some("node-1"), false),
+            deleted_pvc("data-db-1", None, false),
+        ])
+        .unwrap();
+        assert_eq!(
+            message.text,
+            "pvc-reaper deleted 2 PVC(s):\n\
+             • `default/data-db-0` on node `node-1`: pod 'db-0' references node 'node-1', missing for 300s\n\
+             • `default/data-db-1` on node `none`: pod 'db-0' references node 'node-1', missing for 300s"
+        );
+
+        let message = SlackMessage::summary(&[deleted_pvc("data-db-0", None, true)]).unwrap();
+        assert!(
+            message
+                .text
+                .starts_with("pvc-reaper would delete 1 PVC(s) (dry-run):")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_drain_waits_for_webhooks() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = Url::parse(&format!("http://{}/hook", listener.local_addr().unwrap())).unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            // Answer slower than a one-shot run takes to finish its cycle
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            let mut request = Vec::new();
+            let mut chunk = [0; 4096];
+            while !request.ends_with(b"}") {
+                let read = stream.read(&mut chunk).await.unwrap();
+                request.extend_from_slice(&chunk[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+
+        let mut tasks = JoinSet::new();
+        let payload = DeletionNotification {
+            namespace: "default".to_string(),
+            name: "data-db-0".to_string(),
+            reason: "MissingNode".to_string(),
+            description: "pod 'db-0' references missing node 'node-1'".to_string(),
+            node: Some("node-1".to_string()),
+            pod: Some("db-0".to_string()),
+            dry_run: false,
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 2, 3, 4, 5).unwrap(),
+        };
+        spawn_webhook(&mut tasks, url, payload);
+        drain(&mut tasks).await;
+
+        assert!(tasks.is_empty());
+        assert!(server.is_finished());
+        assert!(server.await.unwrap().contains("data-db-0"));
+    }
+}
@@ -0,0 +1,333 @@
+//! HTTP server exposing the reaper's Prometheus metrics, health probes and status.
+
+use crate::{ReapResult, ReaperConfig};
+use anyhow::{Context, Result};
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    routing::{get, post},
+};
+use chrono::{DateTime, Utc};
+use kube::Client;
+use metrics_exporter_prometheus::PrometheusHandle;
+use serde::Serialize;
+use std::{
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tokio::{
+    net::TcpListener,
+    sync::{Notify, watch},
+};
+use tracing::{info, warn};
+
+/// Number of reap intervals without a successful cycle after which the reaper reports unready
+const READY_INTERVALS: u32 = 3;
+
+/// Reconcile status shared between the reaping loop and the HTTP server
+#[derive(Debug)]
+pub struct Status {
+    snapshot: RwLock<StatusSnapshot>,
+    max_consecutive_failures: u32,
+    /// Holds at most one pending `POST /reconcile`, so triggers during a cycle coalesce
+    reconcile: Notify,
+}
+
+/// JSON body served at `/status`
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusSnapshot {
+    pub last_success: Option<DateTime<Utc>>,
+    pub last_result: Option<ReapResult>,
+    pub last_error: Option<CycleError>,
+    /// Failed cycles since the last success
+    pub consecutive_failures: u32,
+    pub storage_classes: Vec<String>,
+    pub storage_provisioner: Vec<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleError {
+    pub message: String,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl Status {
+    pub fn new(config: &ReaperConfig) -> Self {
+        Self {
+            snapshot: RwLock::new(StatusSnapshot {
+                last_success: None,
+                last_result: None,
+                last_error: None,
+                consecutive_failures: 0,
+                storage_classes: config
+                    .storage_class_patterns()
+                    .map(ToString::to_string)
+                    .collect(),
+                storage_provisioner: config.storage_provisioner.clone(),
+                dry_run: config.dry_run,
+            }),
+            max_consecutive_failures: config.max_consecutive_failures,
+            reconcile: Notify::new(),
+        }
+    }
+
+    /// Ask the reaping loop to start a cycle now; repeated requests before it does collapse
+    /// into one
+    pub fn request_reconcile(&self) {
+        self.reconcile.notify_one();
+    }
+
+    /// Wait until a reconcile is requested
+    pub async fn reconcile_requested(&self) {
+        self.reconcile.notified().await;
+    }
+
+    pub fn record_success(&self, result: &ReapResult) {
+        let mut snapshot = self.snapshot.write().unwrap();
+        snapshot.last_success = Some(Utc::now());
+        snapshot.last_result = Some(result.clone());
+        snapshot.consecutive_failures = 0;
+    }
+
+    /// Record a failed cycle, returning the number of consecutive failures so far
+    pub fn record_failure(&self, error: &anyhow::Error) -> u32 {
+        let mut snapshot = self.snapshot.write().unwrap();
+        snapshot.last_error = Some(CycleError {
+            message: format!("{:#}", error),
+            timestamp: Utc::now(),
+        });
+        snapshot.consecutive_failures += 1;
+        snapshot.consecutive_failures
+    }
+
+    /// Whether `--max-consecutive-failures` has been reached and the reaper is giving up
+    pub fn failures_exhausted(&self) -> bool {
+        self.max_consecutive_failures > 0
+            && self.snapshot.read().unwrap().consecutive_failures >= self.max_consecutive_failures
+    }
+
+    pub fn snapshot(&self) -> StatusSnapshot {
+        self.snapshot.read().unwrap().clone()
+    }
+
+    /// Whether a cycle succeeded within `max_age` of `now`
+    fn succeeded_within(&self, max_age: Duration, now: DateTime<Utc>) -> bool {
+        self.snapshot
+            .read()
+            .unwrap()
+            .last_success
+            .is_some_and(|last| {
+                now.signed_duration_since(last)
+                    .to_std()
+                    .map_or(true, |age| age <= max_age)
+            })
+    }
+}
+
+#[derive(Clone)]
+struct AppState {
+    metrics: PrometheusHandle,
+    status: Arc<Status>,
+    client: Client,
+    stale_after: Duration,
+    reconcile_token: Option<Arc<str>>,
+}
+
+/// Serve `/metrics`, `/healthz`, `/readyz`, `/status` and `/reconcile` on `addr` until `shutdown`
+/// flips to true
+pub async fn serve(
+    addr: SocketAddr,
+    metrics: PrometheusHandle,
+    status: Arc<Status>,
+    client: Client,
+    reap_interval: Duration,
+    reconcile_token: Option<String>,
+    mut shutdown: watch::Receiver<bool>,
+) -> Result<()> {
+    let state = AppState {
+        metrics,
+        status,
+        client,
+        stale_after: reap_interval * READY_INTERVALS,
+        reconcile_token: reconcile_token.map(Arc::from),
+    };
+
+    let app = Router::new()
+        .route("/metrics", get(render_metrics))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/status", get(render_status))
+        .route("/reconcile", post(reconcile))
+        .with_state(state);
+
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind HTTP server to {}", addr))?;
+    info!("HTTP server listening on {}", addr);
+
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move {
+            let _ = shutdown.wait_for(|stop| *stop).await;
+        })
+        .await
+        .context("HTTP server failed")
+}
+
+async fn render_metrics(State(state): State<AppState>) -> String {
+    state.metrics.render()
+}
+
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+async fn readyz(State(state): State<AppState>) -> (StatusCode, &'static str) {
+    if state.status.failures_exhausted() {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "too many consecutive reconcile failures",
+        );
+    }
+
+    if !state.status.succeeded_within(state.stale_after, Utc::now()) {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no successful reconcile recently",
+        );
+    }
+
+    if let Err(e) = state.client.apiserver_version().await {
+        warn!("Readiness check could not reach the API server: {}", e);
+        return (StatusCode::SERVICE_UNAVAILABLE, "API server unreachable");
+    }
+
+    (StatusCode::OK, "ok")
+}
+
+async fn render_status(State(state): State<AppState>) -> Json<StatusSnapshot> {
+    Json(state.status.snapshot())
+}
+
+async fn reconcile(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> (StatusCode, &'static str) {
+    if !authorized(&headers, state.reconcile_token.as_deref()) {
+        warn!("Rejected unauthorized reconcile request");
+        return (StatusCode::UNAUTHORIZED, "missing or invalid bearer token");
+    }
+
+    info!("On-demand reconcile requested");
+    state.status.request_reconcile();
+    (StatusCode::ACCEPTED, "reconcile requested")
+}
+
+/// Whether `headers` carry `Authorization: Bearer <token>`; always true without a token
+fn authorized(headers: &HeaderMap, token: Option<&str>) -> bool {
+    let Some(token) = token else {
+        return true;
+    };
+    headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|given| given == token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+
+    fn status() -> Status {
+        Status::new(&ReaperConfig::parse_from(["pvc-reaper"]))
+    }
+
+    #[test]
+    fn test_not_ready_before_first_success() {
+        let status = status();
+        assert!(!status.succeeded_within(Duration::from_secs(180), Utc::now()));
+    }
+
+    #[test]
+    fn test_readiness_goes_stale_and_recovers() {
+        let status = status();
+        status.record_success(&ReapResult::default());
+
+        let later = Utc::now() + chrono::Duration::seconds(181);
+        assert!(!status.succeeded_within(Duration::from_secs(180), later));
+
+        status.record_success(&ReapResult::default());
+        assert!(status.succeeded_within(Duration::from_secs(180), Utc::now()));
+    }
+
+    #[test]
+    fn test_status_snapshot_records_result_and_error() {
+        let status = status();
+        status.record_success(&ReapResult {
+            deleted_count: 2,
+            ..Default::default()
+        });
+        status.record_failure(&anyhow::anyhow!("Failed to list nodes"));
+
+        let json = serde_json::to_value(status.snapshot()).unwrap();
+        assert_eq!(json["last_result"]["deleted_count"], 2);
+        assert_eq!(json["last_error"]["message"], "Failed to list nodes");
+        assert_eq!(json["storage_classes"], serde_json::json!(["openebs-lvm"]));
+        assert_eq!(json["dry_run"], false);
+    }
+
+    #[test]
+    fn test_consecutive_failures_exhaust_and_reset() {
+        let status = Status::new(&ReaperConfig::parse_from([
+            "pvc-reaper",
+            "--max-consecutive-failures",
+            "2",
+        ]));
+        let error = anyhow::anyhow!("Failed to list nodes");
+
+        assert_eq!(status.record_failure(&error), 1);
+        assert!(!status.failures_exhausted());
+        status.record_success(&ReapResult::default());
+        assert_eq!(status.snapshot().consecutive_failures, 0);
+
+        assert_eq!(status.record_failure(&error), 1);
+        assert_eq!(status.record_failure(&error), 2);
+        assert!(status.failures_exhausted());
+    }
+
+    #[test]
+    fn test_reconcile_requires_matching_bearer_token() {
+        let mut headers = HeaderMap::new();
+        assert!(authorized(&headers, None));
+        assert!(!authorized(&headers, Some("secret")));
+
+        headers.insert(AUTHORIZATION, "Bearer wrong".parse().unwrap());
+        assert!(!authorized(&headers, Some("secret")));
+        headers.insert(AUTHORIZATION, "secret".parse().unwrap());
+        assert!(!authorized(&headers, Some("secret")));
+        headers.insert(AUTHORIZATION, "Bearer secret".parse().unwrap());
+        assert!(authorized(&headers, Some("secret")));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_requests_coalesce() {
+        let status = status();
+        status.request_reconcile();
+        status.request_reconcile();
+        status.request_reconcile();
+
+        tokio::time::timeout(Duration::from_millis(50), status.reconcile_requested())
+            .await
+            .expect("a pending request wakes the loop");
+        assert!(
+            tokio::time::timeout(Duration::from_millis(50), status.reconcile_requested())
+                .await
+                .is_err(),
+            "further requests made before the wake-up are coalesced"
+        );
+    }
+}
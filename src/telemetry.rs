@@ -0,0 +1,496 @@
+//! Prometheus metrics recorded by the reaper.
+//!
+//! Metrics go through the `metrics` facade, so recording is a no-op until a recorder is
+//! installed with [`install_recorder`].
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use metrics::{
+    Counter, Gauge, Histogram, Key, KeyName, Label, Metadata, Recorder, SharedString, Unit,
+    counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram,
+};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use reqwest::Url;
+use std::{collections::HashSet, sync::Arc, time::Duration};
+
+pub const PVCS_DELETED: &str = "pvc_reaper_pvcs_deleted_total";
+pub const RECLAIMED_BYTES: &str = "pvc_reaper_reclaimed_bytes_total";
+pub const PVCS_SKIPPED: &str = "pvc_reaper_pvcs_skipped_total";
+pub const DELETE_ERRORS: &str = "pvc_reaper_delete_errors_total";
+pub const CIRCUIT_BREAKER_TRIPS: &str = "pvc_reaper_circuit_breaker_trips_total";
+pub const READY_NODE_FLOOR_TRIPS: &str = "pvc_reaper_ready_node_floor_trips_total";
+pub const READY_NODES: &str = "pvc_reaper_ready_nodes";
+pub const API_ERRORS: &str = "pvc_reaper_api_errors_total";
+pub const RECONCILE_CYCLES: &str = "pvc_reaper_reconcile_cycles_total";
+pub const LAST_SUCCESS_TIMESTAMP: &str = "pvc_reaper_last_success_timestamp_seconds";
+pub const RECONCILE_DURATION: &str = "pvc_reaper_reconcile_duration_seconds";
+pub const LIST_STATE_DURATION: &str = "pvc_reaper_list_state_duration_seconds";
+pub const DELETE_DURATION: &str = "pvc_reaper_delete_duration_seconds";
+pub const MATCHING_PVCS: &str = "pvc_reaper_matching_pvcs";
+pub const PENDING_CONSUMER_PVCS: &str = "pvc_reaper_pending_consumer_pvcs";
+pub const CANDIDATE_PVCS: &str = "pvc_reaper_candidate_pvcs";
+pub const KILL_SWITCH: &str = "pvc_reaper_kill_switch_engaged";
+
+const PUSHGATEWAY_TIMEOUT: Duration = Duration::from_secs(10);
+
+tokio::task_local! {
+    /// Kubeconfig context of the cluster the current task is reaping, with `--contexts`
+    static CLUSTER: Arc<str>;
+}
+
+/// Run `future` with every metric it records labelled `cluster="<context>"`
+pub async fn with_cluster<F: Future>(context: &str, future: F) -> F::Output {
+    CLUSTER.scope(Arc::from(context), future).await
+}
+
+/// Recorder adding the task's `cluster` label, if any, to every metric it registers
+struct ClusterLabel<R>(R);
+
+impl<R> ClusterLabel<R> {
+    fn key(key: &Key) -> Key {
+        match CLUSTER.try_with(|cluster| cluster.to_string()) {
+            Ok(cluster) => key.with_extra_labels(vec![Label::new("cluster", cluster)]),
+            Err(_) => key.clone(),
+        }
+    }
+}
+
+impl<R: Recorder> Recorder for ClusterLabel<R> {
+    fn describe_counter(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.0.describe_counter(key, unit, description);
+    }
+
+    fn describe_gauge(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.0.describe_gauge(key, unit, description);
+    }
+
+    fn describe_histogram(&self, key: KeyName, unit: Option<Unit>, description: SharedString) {
+        self.0.describe_histogram(key, unit, description);
+    }
+
+    fn register_counter(&self, key: &Key, metadata: &Metadata<'_>) -> Counter {
+        self.0.register_counter(&Self::key(key), metadata)
+    }
+
+    fn register_gauge(&self, key: &Key, metadata: &Metadata<'_>) -> Gauge {
+        self.0.register_gauge(&Self::key(key), metadata)
+    }
+
+    fn register_histogram(&self, key: &Key, metadata: &Metadata<'_>) -> Histogram {
+        self.0.register_histogram(&Self::key(key), metadata)
+    }
+}
+
+/// Label value that namespaces beyond `--metrics-max-namespaces` collapse into
+pub const OTHER_NAMESPACE: &str = "other";
+
+/// Labels attached to per-PVC counters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PvcLabels {
+    pub namespace: String,
+    pub storage_class: String,
+}
+
+/// Hands out namespace label values, admitting at most `max` distinct namespaces for the
+/// lifetime of the process so metric cardinality stays bounded
+#[derive(Debug, Default)]
+pub(crate) struct NamespaceLabels {
+    admitted: HashSet<String>,
+}
+
+impl NamespaceLabels {
+    pub(crate) fn label(&mut self, namespace: &str, max: usize) -> String {
+        if self.admitted.contains(namespace) {
+            return namespace.to_string();
+        }
+        if self.admitted.len() < max {
+            self.admitted.insert(namespace.to_string());
+            return namespace.to_string();
+        }
+        OTHER_NAMESPACE.to_string()
+    }
+}
+
+/// Histogram buckets (seconds) for API call and cycle durations
+const DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0,
+];
+
+/// Install the global Prometheus recorder and describe the reaper's metrics
+pub fn install_recorder() -> Result<PrometheusHandle> {
+    let recorder = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Suffix("_duration_seconds".to_string()),
+            DURATION_BUCKETS,
+        )
+        .context("Failed to configure histogram buckets")?
+        .build_recorder();
+    let handle = recorder.handle();
+    metrics::set_global_recorder(ClusterLabel(recorder)).map_err(|_| {
+        anyhow::anyhow!("Failed to install Prometheus recorder: one is already set")
+    })?;
+
+    describe_counter!(
+        PVCS_DELETED,
+        "PVCs deleted, labelled by namespace, storage_class and dry_run"
+    );
+    describe_counter!(
+        RECLAIMED_BYTES,
+        metrics::Unit::Bytes,
+        "Requested storage of deleted PVCs, labelled by dry_run"
+    );
+    describe_counter!(
+        PVCS_SKIPPED,
+        "Matching PVCs that were not deleted, labelled by namespace and storage_class"
+    );
+    describe_counter!(DELETE_ERRORS, "Failed PVC delete calls");
+    describe_counter!(
+        CIRCUIT_BREAKER_TRIPS,
+        "Cycles whose deletions were all skipped by --max-deletion-fraction"
+    );
+    describe_counter!(
+        READY_NODE_FLOOR_TRIPS,
+        "Cycles whose deletions were all skipped for too few Ready nodes"
+    );
+    describe_counter!(
+        API_ERRORS,
+        "Failed Kubernetes API calls, labelled by resource and verb"
+    );
+    describe_counter!(
+        RECONCILE_CYCLES,
+        "Reconcile cycles, labelled by result (success/failure)"
+    );
+    describe_gauge!(
+        LAST_SUCCESS_TIMESTAMP,
+        "Unix timestamp of the last successful reconcile cycle"
+    );
+    describe_histogram!(
+        RECONCILE_DURATION,
+        metrics::Unit::Seconds,
+        "Duration of a full reconcile cycle"
+    );
+    describe_histogram!(
+        LIST_STATE_DURATION,
+        metrics::Unit::Seconds,
+        "Time spent listing nodes, pods and PVCs"
+    );
+    describe_histogram!(
+        DELETE_DURATION,
+        metrics::Unit::Seconds,
+        "Latency of individual PVC delete calls"
+    );
+
+    describe_gauge!(
+        MATCHING_PVCS,
+        "PVCs matching the storage class and provisioner filters in the last cycle"
+    );
+    describe_gauge!(
+        PENDING_CONSUMER_PVCS,
+        "Matching PVCs with a Pending consuming pod in the last cycle"
+    );
+    describe_gauge!(
+        READY_NODES,
+        "Nodes whose Ready condition was True in the last cycle"
+    );
+    describe_gauge!(
+        KILL_SWITCH,
+        "1 while the kill switch ConfigMap keeps the reaper from deleting, 0 otherwise"
+    );
+    describe_gauge!(
+        CANDIDATE_PVCS,
+        "Matching PVCs that produced a deletion reason in the last cycle"
+    );
+
+    Ok(handle)
+}
+
+/// Replace the Pushgateway group for `job` (and `instance`, if set) with the current metrics
+pub async fn push_to_gateway(
+    handle: &PrometheusHandle,
+    gateway: &Url,
+    job: &str,
+    instance: Option<&str>,
+) -> Result<()> {
+    let url = pushgateway_group_url(gateway, job, instance)?;
+    reqwest::Client::new()
+        .put(url.clone())
+        .timeout(PUSHGATEWAY_TIMEOUT)
+        .header(reqwest::header::CONTENT_TYPE, "text/plain; version=0.0.4")
+        .body(handle.render())
+        .send()
+        .await
+        .with_context(|| format!("Failed to push metrics to {}", url))?
+        .error_for_status()
+        .context("Pushgateway rejected metrics")?;
+    Ok(())
+}
+
+/// Build `<gateway>/metrics/job/<job>[/instance/<instance>]`, escaping the label values
+fn pushgateway_group_url(gateway: &Url, job: &str, instance: Option<&str>) -> Result<Url> {
+    let mut url = gateway.clone();
+    {
+        let mut segments = url
+            .path_segments_mut()
+            .map_err(|_| anyhow::anyhow!("Invalid Pushgateway URL: {}", gateway))?;
+        segments.pop_if_empty().extend(["metrics", "job", job]);
+        if let Some(instance) = instance {
+            segments.extend(["instance", instance]);
+        }
+    }
+    Ok(url)
+}
+
+pub(crate) fn pvc_deleted(labels: &PvcLabels, dry_run: bool) {
+    counter!(
+        PVCS_DELETED,
+        "namespace" => labels.namespace.clone(),
+        "storage_class" => labels.storage_class.clone(),
+        "dry_run" => dry_run.to_string()
+    )
+    .increment(1);
+}
+
+pub(crate) fn reclaimed_bytes(bytes: u64, dry_run: bool) {
+    counter!(RECLAIMED_BYTES, "dry_run" => dry_run.to_string()).increment(bytes);
+}
+
+pub(crate) fn pvc_skipped(labels: &PvcLabels) {
+    counter!(
+        PVCS_SKIPPED,
+        "namespace" => labels.namespace.clone(),
+        "storage_class" => labels.storage_class.clone()
+    )
+    .increment(1);
+}
+
+pub(crate) fn delete_error() {
+    counter!(DELETE_ERRORS).increment(1);
+}
+
+pub(crate) fn circuit_breaker_tripped() {
+    counter!(CIRCUIT_BREAKER_TRIPS).increment(1);
+}
+
+pub(crate) fn ready_node_floor_tripped() {
+    counter!(READY_NODE_FLOOR_TRIPS).increment(1);
+}
+
+pub(crate) fn api_error(resource: &'static str, verb: &'static str) {
+    counter!(API_ERRORS, "resource" => resource, "verb" => verb).increment(1);
+}
+
+pub(crate) fn cycle_completed(success: bool) {
+    let result = if success { "success" } else { "failure" };
+    counter!(RECONCILE_CYCLES, "result" => result).increment(1);
+
+    if success {
+        gauge!(LAST_SUCCESS_TIMESTAMP).set(Utc::now().timestamp() as f64);
+    }
+}
+
+pub(crate) fn reconcile_duration(elapsed: Duration) {
+    histogram!(RECONCILE_DURATION).record(elapsed.as_secs_f64());
+}
+
+pub(crate) fn list_state_duration(elapsed: Duration) {
+    histogram!(LIST_STATE_DURATION).record(elapsed.as_secs_f64());
+}
+
+pub(crate) fn delete_duration(elapsed: Duration) {
+    histogram!(DELETE_DURATION).record(elapsed.as_secs_f64());
+}
+
+pub(crate) fn ready_nodes(ready: usize) {
+    gauge!(READY_NODES).set(ready as f64);
+}
+
+pub(crate) fn kill_switch(engaged: bool) {
+    gauge!(KILL_SWITCH).set(if engaged { 1.0 } else { 0.0 });
+}
+
+pub(crate) fn candidate_gauges(matching: usize, pending_consumer: usize, candidates: usize) {
+    gauge!(MATCHING_PVCS).set(matching as f64);
+    gauge!(PENDING_CONSUMER_PVCS).set(pending_consumer as f64);
+    gauge!(CANDIDATE_PVCS).set(candidates as f64);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    #[test]
+    fn test_duration_histograms_recorded() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            reconcile_duration(Duration::from_millis(1500));
+            list_state_duration(Duration::from_millis(250));
+            delete_duration(Duration::from_millis(40));
+        });
+
+        let snapshot = snapshotter.snapshot().into_hashmap();
+        let histogram = |name: &str| {
+            snapshot
+                .iter()
+                .find(|(key, _)| key.key().name() == name)
+                .map(|(_, (_, _, value))| value)
+        };
+
+        assert_eq!(
+            histogram(RECONCILE_DURATION),
+            Some(&DebugValue::Histogram(vec![1.5.into()]))
+        );
+        assert_eq!(
+            histogram(LIST_STATE_DURATION),
+            Some(&DebugValue::Histogram(vec![0.25.into()]))
+        );
+        assert_eq!(
+            histogram(DELETE_DURATION),
+            Some(&DebugValue::Histogram(vec![0.04.into()]))
+        );
+    }
+
+    #[test]
+    fn test_api_errors_labelled_by_resource_and_verb() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            api_error("pods", "list");
+            api_error("persistentvolumeclaims", "delete");
+            api_error("persistentvolumeclaims", "delete");
+        });
+
+        let snapshot = snapshotter.snapshot().into_hashmap();
+        let count = |resource: &str, verb: &str| {
+            snapshot
+                .iter()
+                .find(|(key, _)| {
+                    let labels: Vec<_> = key.key().labels().map(|l| (l.key(), l.value())).collect();
+                    key.key().name() == API_ERRORS
+                        && labels.contains(&("resource", resource))
+                        && labels.contains(&("verb", verb))
+                })
+                .map(|(_, (_, _, value))| value)
+        };
+
+        assert_eq!(count("pods", "list"), Some(&DebugValue::Counter(1)));
+        assert_eq!(
+            count("persistentvolumeclaims", "delete"),
+            Some(&DebugValue::Counter(2))
+        );
+        assert_eq!(count("nodes", "list"), None);
+    }
+
+    #[test]
+    fn test_pushgateway_group_url() {
+        let gateway: Url = "http://pushgateway:9091".parse().unwrap();
+        assert_eq!(
+            pushgateway_group_url(&gateway, "pvc-reaper", None)
+                .unwrap()
+                .as_str(),
+            "http://pushgateway:9091/metrics/job/pvc-reaper"
+        );
+
+        let gateway: Url = "http://pushgateway:9091/prefix/".parse().unwrap();
+        assert_eq!(
+            pushgateway_group_url(&gateway, "pvc-reaper", Some("cluster a"))
+                .unwrap()
+                .as_str(),
+            "http://pushgateway:9091/prefix/metrics/job/pvc-reaper/instance/cluster%20a"
+        );
+    }
+
+    #[test]
+    fn test_namespace_labels_overflow_to_other() {
+        let mut labels = NamespaceLabels::default();
+
+        assert_eq!(labels.label("team-a", 2), "team-a");
+        assert_eq!(labels.label("team-b", 2), "team-b");
+        assert_eq!(labels.label("team-c", 2), OTHER_NAMESPACE);
+        // Namespaces admitted earlier keep their own label
+        assert_eq!(labels.label("team-a", 2), "team-a");
+        assert_eq!(labels.label("team-d", 0), OTHER_NAMESPACE);
+    }
+
+    #[test]
+    fn test_deletion_counters_labelled() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let labels = PvcLabels {
+            namespace: "team-a".to_string(),
+            storage_class: "openebs-lvm".to_string(),
+        };
+
+        metrics::with_local_recorder(&recorder, || {
+            pvc_deleted(&labels, false);
+            pvc_skipped(&labels);
+            pvc_skipped(&labels);
+        });
+
+        let snapshot = snapshotter.snapshot().into_hashmap();
+        let labelled = |name: &str| {
+            snapshot
+                .iter()
+                .find(|(key, _)| key.key().name() == name)
+                .map(|(key, (_, _, value))| {
+                    let mut labels: Vec<_> = key
+                        .key()
+                        .labels()
+                        .map(|l| format!("{}={}", l.key(), l.value()))
+                        .collect();
+                    labels.sort();
+                    (labels, value)
+                })
+        };
+
+        assert_eq!(
+            labelled(PVCS_DELETED),
+            Some((
+                vec![
+                    "dry_run=false".to_string(),
+                    "namespace=team-a".to_string(),
+                    "storage_class=openebs-lvm".to_string()
+                ],
+                &DebugValue::Counter(1)
+            ))
+        );
+        assert_eq!(
+            labelled(PVCS_SKIPPED),
+            Some((
+                vec![
+                    "namespace=team-a".to_string(),
+                    "storage_class=openebs-lvm".to_string()
+                ],
+                &DebugValue::Counter(2)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cluster_label_added_inside_context_scope() {
+        let recorder = ClusterLabel(DebuggingRecorder::new());
+        let snapshotter = recorder.0.snapshotter();
+
+        metrics::with_local_recorder(&recorder, || {
+            delete_error();
+            CLUSTER.sync_scope(Arc::from("edge-1"), delete_error);
+        });
+
+        let mut clusters: Vec<_> = snapshotter
+            .snapshot()
+            .into_vec()
+            .into_iter()
+            .map(|(key, _, _, _)| {
+                key.key()
+                    .labels()
+                    .find(|label| label.key() == "cluster")
+                    .map(|label| label.value().to_string())
+            })
+            .collect();
+        clusters.sort();
+        assert_eq!(clusters, vec![None, Some("edge-1".to_string())]);
+    }
+}
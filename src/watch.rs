@@ -0,0 +1,138 @@
+//! Node and pod watches that trigger targeted reaps between periodic cycles.
+
+use crate::{
+    ReapTracker, ReaperConfig, get_pod_pvc_names, pod_is_pending, pod_is_unschedulable,
+    reap_claims, reap_nodes, threshold_delays,
+};
+use chrono::Utc;
+use futures::{
+    FutureExt, StreamExt,
+    stream::{self, FuturesUnordered},
+};
+use k8s_openapi::api::core::v1::{Node, Pod};
+use kube::{
+    Api, Client, ResourceExt,
+    runtime::{WatchStreamExt, watcher},
+};
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+/// Slack added to threshold timers so the pod is safely past the threshold when evaluated
+const THRESHOLD_SLACK: Duration = Duration::from_secs(1);
+
+/// Watch Node deletions and reap the PVCs pinned to each deleted node right away. Watch errors
+/// are retried with backoff, so this only returns if the stream ends.
+pub async fn node_deletions(client: &Client, config: &ReaperConfig, tracker: &Mutex<ReapTracker>) {
+    let nodes = Api::<Node>::all(client.clone());
+    let mut events = watcher(nodes, watcher::Config::default())
+        .default_backoff()
+        .boxed();
+
+    while let Some(event) = events.next().await {
+        let node = match event {
+            Ok(watcher::Event::Delete(node)) => node.name_any(),
+            Ok(_) => continue,
+            Err(e) => {
+                warn!("Node watch error, retrying: {}", e);
+                continue;
+            }
+        };
+
+        info!(node = %node, "Node deleted, evaluating its PVCs");
+        let mut tracker = tracker.lock().await;
+        if let Err(e) =
+            reap_nodes(client, config, &mut tracker, HashSet::from([node.clone()])).await
+        {
+            error!(node = %node, "Targeted reap after node deletion failed: {:#}", e);
+        }
+    }
+
+    warn!("Node watch ended");
+}
+
+/// Watch pods and evaluate the claims of each pod that turns unschedulable: once right away and
+/// again as it crosses each unschedulable threshold. Watch errors are retried with backoff, so
+/// this only returns if the stream ends.
+pub async fn unschedulable_pods(
+    client: &Client,
+    config: &ReaperConfig,
+    tracker: &Mutex<ReapTracker>,
+) {
+    let apis = if config.namespaces.is_empty() {
+        vec![Api::<Pod>::all(client.clone())]
+    } else {
+        config
+            .namespaces
+            .iter()
+            .map(|namespace| Api::<Pod>::namespaced(client.clone(), namespace))
+            .collect()
+    };
+    let mut events = stream::select_all(apis.into_iter().map(|pods| {
+        watcher(pods, watcher::Config::default())
+            .default_backoff()
+            .boxed()
+    }));
+    // Pods (by UID) already handled during their current unschedulable window
+    let mut unschedulable = HashSet::new();
+    let mut timers = FuturesUnordered::new();
+
+    loop {
+        let claims = tokio::select! {
+            Some(event) = events.next() => {
+                let (pod, initial) = match event {
+                    Ok(watcher::Event::Apply(pod)) => (pod, false),
+                    Ok(watcher::Event::InitApply(pod)) => (pod, true),
+                    Ok(watcher::Event::Delete(pod)) => {
+                        unschedulable.remove(&pod.uid().unwrap_or_default());
+                        continue;
+                    }
+                    Ok(_) => continue,
+                    Err(e) => {
+                        warn!("Pod watch error, retrying: {}", e);
+                        continue;
+                    }
+                };
+
+                let uid = pod.uid().unwrap_or_default();
+                if !(pod_is_pending(&pod) && pod_is_unschedulable(&pod)) {
+                    unschedulable.remove(&uid);
+                    continue;
+                }
+                let namespace = pod.namespace().unwrap_or_default();
+                let claims: HashSet<(String, String)> = get_pod_pvc_names(&pod)
+                    .into_iter()
+                    .map(|claim| (namespace.clone(), claim))
+                    .collect();
+                if claims.is_empty() || !unschedulable.insert(uid) {
+                    continue;
+                }
+
+                for delay in threshold_delays(&pod, config, Utc::now()) {
+                    let claims = claims.clone();
+                    timers.push(tokio::time::sleep(delay + THRESHOLD_SLACK).map(move |_| claims));
+                }
+                // The periodic loop already covers pods that were unschedulable before startup
+                if initial {
+                    continue;
+                }
+                info!(
+                    namespace = %namespace,
+                    pod = %pod.name_any(),
+                    "Pod turned unschedulable, evaluating its PVCs"
+                );
+                claims
+            }
+            Some(claims) = timers.next() => claims,
+            else => break,
+        };
+
+        let mut tracker = tracker.lock().await;
+        if let Err(e) = reap_claims(client, config, &mut tracker, claims).await {
+            error!("Targeted reap of unschedulable pod's PVCs failed: {:#}", e);
+        }
+    }
+
+    warn!("Pod watch ended");
+}